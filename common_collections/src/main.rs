@@ -52,6 +52,7 @@
   map, the existing value should remain the way it is; if the key doesn’t exist, insert it and a value for it.
 - By default, HashMap uses a hashing function called SipHash that can provide resistance to denial-of-service (DoS) attacks involving hash tables
 */
+use fundamentals_core::{Align, Table};
 use std::collections::HashMap;
 
 enum SpreadsheetCell {
@@ -136,7 +137,8 @@ fn main() {
     let s3 = String::from("toe");
 
     let s = s1 + "-" + &s2 + "-" + &s3;
-    let s = format!("{s1}-{s2}-{s3}");
+    let s1 = String::from("tic"); // s1 was moved by the + above
+    let s = format!("{s1}-{s2}-{s3}"); // format! only borrows its arguments
 
     let s1 = String::from("hello");
     // let h = s1[0]; error
@@ -156,8 +158,13 @@ fn main() {
     scores.insert(String::from("Yellow"), 50);
     let team_name = String::from("Blue");
     let score = scores.get(&team_name).copied().unwrap_or(0);
+    // Iterating over a hash map gives the pairs in an arbitrary order.
+    let table = Table::new()
+        .column("team", 8, Align::Left)
+        .column("score", 5, Align::Right);
+    println!("{}", table.header());
     for (key, value) in &scores {
-        println!("{key}: {value}");
+        println!("{}", table.row(&[key, value]));
     }
 
     let field_name = String::from("Favorite color");
@@ -0,0 +1,71 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 05:44:16
+/// @Description: Benchmark of the Fibonacci Algorithms
+/*
+- Run with: cargo bench --bench algorithms [-- MAX_N]. MAX_N defaults to 10^7.
+- Every algorithm computes F(n) for n = 10^3, 10^4, ... up to MAX_N. The iterative algorithm is O(n^2) in limb operations and is only run up to 10^6, beyond
+  that a single run takes minutes.
+- Each measurement is the best of a few runs, which filters out noise from the rest of the machine better than the average does. Results of the different
+  algorithms are compared against each other, so the benchmark doubles as a check on the large inputs the unit tests do not reach.
+*/
+use fibonacci_series::algorithm::algorithms;
+use fundamentals_core::{Align, Table};
+use std::env;
+use std::time::{Duration, Instant};
+
+const ITERATIVE_LIMIT: u64 = 1_000_000;
+
+fn best_of<F: FnMut()>(runs: u32, mut f: F) -> Duration {
+    (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            f();
+            start.elapsed()
+        })
+        .min()
+        .unwrap_or_default()
+}
+
+fn main() {
+    // cargo bench passes --bench to the binary, anything numeric is the limit.
+    let max_n = env::args()
+        .skip(1)
+        .find_map(|arg| arg.parse::<u64>().ok())
+        .unwrap_or(10_000_000);
+    let algorithms = algorithms();
+
+    let table = Table::new()
+        .column("n", 10, Align::Right)
+        .column("algorithm", 14, Align::Right)
+        .column("time", 14, Align::Right);
+    println!("{}", table.header());
+    let mut n = 1_000;
+    while n <= max_n {
+        let mut expected = None;
+        for algorithm in &algorithms {
+            if algorithm.name() == "iterative" && n > ITERATIVE_LIMIT {
+                continue;
+            }
+            let runs = if n >= 1_000_000 { 1 } else { 5 };
+            let mut result = None;
+            let elapsed = best_of(runs, || result = Some(algorithm.fibonacci(n)));
+            println!(
+                "{}",
+                table.row(&[&n, &algorithm.name(), &format!("{:.3?}", elapsed)])
+            );
+
+            let result = result.expect("at least one run");
+            match &expected {
+                None => expected = Some(result),
+                Some(expected) => assert_eq!(
+                    &result,
+                    expected,
+                    "{} disagrees at n = {}",
+                    algorithm.name(),
+                    n
+                ),
+            }
+        }
+        n *= 10;
+    }
+}
@@ -0,0 +1,157 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 05:44:16
+/// @Description: Fibonacci Algorithms
+/*
+- Every algorithm implements the FibonacciAlgorithm trait, so the CLI and the benchmarks can pick one by name and treat them all the same way.
+- Iterative adds the last two numbers n times. That is n big additions, O(n) steps but O(n^2) limb operations overall because the numbers grow.
+- FastDoubling walks the bits of n from the top and uses the identities
+      F(2k)     = F(k) * (2*F(k+1) - F(k))
+      F(2k + 1) = F(k)^2 + F(k+1)^2
+  to go from (F(k), F(k+1)) to (F(2k), F(2k+1)), and one addition more when the bit is set. That is O(log n) steps.
+- Matrix raises [[1, 1], [1, 0]] to the n-th power by repeated squaring, the power is [[F(n+1), F(n)], [F(n), F(n-1)]]. Powers of this matrix are symmetric,
+  so only three of the four entries are stored and multiplied. It is O(log n) as well but does more multiplications per step than fast doubling.
+*/
+use crate::BigUint;
+
+pub trait FibonacciAlgorithm {
+    fn name(&self) -> &'static str;
+    fn fibonacci(&self, n: u64) -> BigUint;
+}
+
+pub struct Iterative;
+pub struct FastDoubling;
+pub struct Matrix;
+
+pub fn algorithms() -> Vec<Box<dyn FibonacciAlgorithm>> {
+    vec![
+        Box::new(Iterative),
+        Box::new(FastDoubling),
+        Box::new(Matrix),
+    ]
+}
+
+pub fn algorithm_by_name(name: &str) -> Option<Box<dyn FibonacciAlgorithm>> {
+    algorithms()
+        .into_iter()
+        .find(|algorithm| algorithm.name() == name)
+}
+
+impl FibonacciAlgorithm for Iterative {
+    fn name(&self) -> &'static str {
+        "iterative"
+    }
+
+    // The addition is done in place into the older of the two numbers, so the loop does not allocate apart from growing by a limb now and then.
+    fn fibonacci(&self, n: u64) -> BigUint {
+        let mut current = BigUint::zero();
+        let mut next = BigUint::one();
+        for _ in 0..n {
+            current += &next;
+            std::mem::swap(&mut current, &mut next);
+        }
+        current
+    }
+}
+
+impl FibonacciAlgorithm for FastDoubling {
+    fn name(&self) -> &'static str {
+        "fast-doubling"
+    }
+
+    fn fibonacci(&self, n: u64) -> BigUint {
+        let mut a = BigUint::zero(); // F(k)
+        let mut b = BigUint::one(); // F(k + 1)
+        for bit in (0..u64::BITS - n.leading_zeros()).rev() {
+            let twice_b_minus_a = &(&b + &b) - &a;
+            let even = &a * &twice_b_minus_a;
+            let odd = &a.square() + &b.square();
+            if n >> bit & 1 == 1 {
+                b = &even + &odd;
+                a = odd;
+            } else {
+                a = even;
+                b = odd;
+            }
+        }
+        a
+    }
+}
+
+// The symmetric matrix [[next, current], [current, previous]].
+struct FibonacciMatrix {
+    next: BigUint,
+    current: BigUint,
+    previous: BigUint,
+}
+
+impl FibonacciMatrix {
+    fn multiply(&self, other: &FibonacciMatrix) -> FibonacciMatrix {
+        let shared = &self.current * &other.current;
+        FibonacciMatrix {
+            next: &(&self.next * &other.next) + &shared,
+            current: &(&self.next * &other.current) + &(&self.current * &other.previous),
+            previous: &shared + &(&self.previous * &other.previous),
+        }
+    }
+}
+
+impl FibonacciAlgorithm for Matrix {
+    fn name(&self) -> &'static str {
+        "matrix"
+    }
+
+    fn fibonacci(&self, n: u64) -> BigUint {
+        let mut result = FibonacciMatrix {
+            next: BigUint::one(),
+            current: BigUint::zero(),
+            previous: BigUint::one(),
+        };
+        let mut base = FibonacciMatrix {
+            next: BigUint::one(),
+            current: BigUint::one(),
+            previous: BigUint::zero(),
+        };
+        let mut exponent = n;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result.multiply(&base);
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = base.multiply(&base);
+            }
+        }
+        result.current
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn algorithms_agree() {
+        let algorithms = algorithms();
+        for n in (0..200).chain([1000, 4095, 4096, 10_007]) {
+            let expected = Iterative.fibonacci(n);
+            for algorithm in &algorithms {
+                assert_eq!(
+                    algorithm.fibonacci(n),
+                    expected,
+                    "{} at n = {}",
+                    algorithm.name(),
+                    n
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn lookup_by_name() {
+        assert_eq!(
+            algorithm_by_name("matrix").map(|algorithm| algorithm.name()),
+            Some("matrix")
+        );
+        assert!(algorithm_by_name("recursive").is_none());
+    }
+}
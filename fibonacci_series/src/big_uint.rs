@@ -0,0 +1,450 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 05:42:49
+/// @Description: Arbitrary Precision Unsigned Integer
+/*
+- BigUint stores a non-negative integer of any size as a vector of 64 bit limbs, least significant limb first. The vector never ends with a zero limb, so zero
+  is the empty vector and two equal numbers always have equal vectors, which lets Eq and Ord be simple comparisons.
+- Addition works limb by limb with a carry, the same way as on paper but in base 2^64. Subtraction does the same with a borrow and panics when the result would
+  be negative, like the built-in unsigned integers do in debug builds. checked_sub is the non-panicking version.
+- Multiplication is schoolbook for short numbers. Above KARATSUBA_THRESHOLD limbs it uses Karatsuba's trick: splitting both numbers in halves, a = a1*B + a0
+  and b = b1*B + b0, the product needs only three half-size products, a0*b0, a1*b1 and (a0 + a1)*(b0 + b1), instead of four. Applied recursively this brings
+  the cost down from n^2 to about n^1.585 limb operations, which is what makes the O(log n) Fibonacci algorithms fast for huge n.
+- Printing in decimal repeatedly divides by 10^19, the largest power of ten that fits in a limb, and collects the remainders as groups of 19 digits.
+*/
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Sub};
+use std::str::FromStr;
+
+const DECIMAL_CHUNK: u64 = 10_000_000_000_000_000_000;
+const DECIMAL_CHUNK_DIGITS: usize = 19;
+const KARATSUBA_THRESHOLD: usize = 32;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigUint {
+    limbs: Vec<u64>,
+}
+
+impl BigUint {
+    pub fn zero() -> BigUint {
+        BigUint { limbs: Vec::new() }
+    }
+
+    pub fn one() -> BigUint {
+        BigUint::from(1u64)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    pub fn bits(&self) -> u64 {
+        match self.limbs.last() {
+            Some(top) => self.limbs.len() as u64 * 64 - top.leading_zeros() as u64,
+            None => 0,
+        }
+    }
+
+    pub fn to_u64(&self) -> Option<u64> {
+        match self.limbs.len() {
+            0 => Some(0),
+            1 => Some(self.limbs[0]),
+            _ => None,
+        }
+    }
+
+    // Bit i counted from the least significant bit.
+    pub fn bit(&self, i: u64) -> bool {
+        let limb = (i / 64) as usize;
+        limb < self.limbs.len() && self.limbs[limb] >> (i % 64) & 1 == 1
+    }
+
+    pub fn rem_u64(&self, modulus: u64) -> u64 {
+        assert!(
+            modulus > 0,
+            "attempt to calculate the remainder with a divisor of zero"
+        );
+        self.limbs.iter().rev().fold(0u128, |remainder, limb| {
+            ((remainder << 64) | *limb as u128) % modulus as u128
+        }) as u64
+    }
+
+    pub fn checked_sub(&self, other: &BigUint) -> Option<BigUint> {
+        if *self < *other {
+            return None;
+        }
+        let mut difference = self.limbs.clone();
+        sub_in_place(&mut difference, &other.limbs);
+        Some(BigUint::from_limbs(difference))
+    }
+
+    pub fn square(&self) -> BigUint {
+        self * self
+    }
+
+    fn from_limbs(limbs: Vec<u64>) -> BigUint {
+        let mut number = BigUint { limbs };
+        number.normalize();
+        number
+    }
+
+    fn normalize(&mut self) {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+    }
+
+    // Divides in place by a single limb and returns the remainder.
+    fn div_rem_small(&mut self, divisor: u64) -> u64 {
+        let mut remainder = 0u128;
+        for limb in self.limbs.iter_mut().rev() {
+            let current = (remainder << 64) | *limb as u128;
+            *limb = (current / divisor as u128) as u64;
+            remainder = current % divisor as u128;
+        }
+        self.normalize();
+        remainder as u64
+    }
+
+    fn mul_small_add(&mut self, factor: u64, addend: u64) {
+        let mut carry = addend as u128;
+        for limb in self.limbs.iter_mut() {
+            let product = *limb as u128 * factor as u128 + carry;
+            *limb = product as u64;
+            carry = product >> 64;
+        }
+        if carry > 0 {
+            self.limbs.push(carry as u64);
+        }
+        self.normalize();
+    }
+}
+
+impl From<u64> for BigUint {
+    fn from(value: u64) -> BigUint {
+        let mut number = BigUint { limbs: vec![value] };
+        number.normalize();
+        number
+    }
+}
+
+impl AddAssign<&BigUint> for BigUint {
+    fn add_assign(&mut self, other: &BigUint) {
+        if self.limbs.len() < other.limbs.len() {
+            self.limbs.resize(other.limbs.len(), 0);
+        }
+        let mut carry = 0u64;
+        for (limb, addend) in self.limbs.iter_mut().zip(&other.limbs) {
+            let sum = *limb as u128 + *addend as u128 + carry as u128;
+            *limb = sum as u64;
+            carry = (sum >> 64) as u64;
+        }
+        for limb in self.limbs[other.limbs.len()..].iter_mut() {
+            if carry == 0 {
+                break;
+            }
+            let (sum, overflow) = limb.overflowing_add(carry);
+            *limb = sum;
+            carry = overflow as u64;
+        }
+        if carry > 0 {
+            self.limbs.push(carry);
+        }
+    }
+}
+
+impl Add<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn add(self, other: &BigUint) -> BigUint {
+        let mut sum = self.clone();
+        sum += other;
+        sum
+    }
+}
+
+impl Sub<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn sub(self, other: &BigUint) -> BigUint {
+        self.checked_sub(other)
+            .expect("attempt to subtract with overflow")
+    }
+}
+
+impl Mul<&BigUint> for &BigUint {
+    type Output = BigUint;
+
+    fn mul(self, other: &BigUint) -> BigUint {
+        if self.is_zero() || other.is_zero() {
+            return BigUint::zero();
+        }
+        BigUint::from_limbs(mul_limbs(&self.limbs, &other.limbs))
+    }
+}
+
+//------------------------------------------------------------Limb Arithmetic-------------------------------------------------------
+
+// Adds src into dst starting at limb offset and carries as far as needed. dst must be long enough to hold the result.
+fn add_into(dst: &mut [u64], src: &[u64], offset: usize) {
+    let mut carry = 0u64;
+    for (limb, addend) in dst[offset..].iter_mut().zip(src) {
+        let sum = *limb as u128 + *addend as u128 + carry as u128;
+        *limb = sum as u64;
+        carry = (sum >> 64) as u64;
+    }
+    for limb in dst[offset + src.len()..].iter_mut() {
+        if carry == 0 {
+            break;
+        }
+        let (sum, overflow) = limb.overflowing_add(carry);
+        *limb = sum;
+        carry = overflow as u64;
+    }
+    debug_assert_eq!(carry, 0, "add_into overflowed its destination");
+}
+
+// dst -= src, the caller makes sure dst is the larger number.
+fn sub_in_place(dst: &mut [u64], src: &[u64]) {
+    let mut borrow = false;
+    for (i, limb) in dst.iter_mut().enumerate() {
+        let subtrahend = src.get(i).copied().unwrap_or(0);
+        if i >= src.len() && !borrow {
+            break;
+        }
+        let (difference, borrow_a) = limb.overflowing_sub(subtrahend);
+        let (difference, borrow_b) = difference.overflowing_sub(borrow as u64);
+        *limb = difference;
+        borrow = borrow_a || borrow_b;
+    }
+    debug_assert!(!borrow, "sub_in_place went below zero");
+}
+
+fn add_limbs(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut sum = Vec::with_capacity(long.len() + 1);
+    sum.extend_from_slice(long);
+    sum.push(0);
+    add_into(&mut sum, short, 0);
+    sum
+}
+
+fn schoolbook_mul(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let mut product = vec![0u64; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        if x == 0 {
+            continue;
+        }
+        let mut carry = 0u128;
+        for (j, &y) in b.iter().enumerate() {
+            let current = x as u128 * y as u128 + product[i + j] as u128 + carry;
+            product[i + j] = current as u64;
+            carry = current >> 64;
+        }
+        product[i + b.len()] = carry as u64;
+    }
+    product
+}
+
+// The result has exactly a.len() + b.len() limbs and may end with zeros.
+fn mul_limbs(a: &[u64], b: &[u64]) -> Vec<u64> {
+    let (a, b) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    if b.len() < KARATSUBA_THRESHOLD {
+        return schoolbook_mul(a, b);
+    }
+
+    let half = a.len() / 2;
+    let (a0, a1) = a.split_at(half);
+    let mut product = vec![0u64; a.len() + b.len()];
+
+    // b fits in the lower half of a, split only a: a*b = a0*b + a1*b*B^half.
+    if b.len() <= half {
+        add_into(&mut product, &mul_limbs(a0, b), 0);
+        add_into(&mut product, &mul_limbs(a1, b), half);
+        return product;
+    }
+
+    let (b0, b1) = b.split_at(half);
+    let low = mul_limbs(a0, b0);
+    let high = mul_limbs(a1, b1);
+    let mut middle = mul_limbs(&add_limbs(a0, a1), &add_limbs(b0, b1));
+    sub_in_place(&mut middle, &low);
+    sub_in_place(&mut middle, &high);
+    while middle.last() == Some(&0) {
+        middle.pop();
+    }
+
+    add_into(&mut product, &low, 0);
+    add_into(&mut product, &middle, half);
+    add_into(&mut product, &high, 2 * half);
+    product
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &BigUint) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &BigUint) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_zero() {
+            return f.pad_integral(true, "", "0");
+        }
+        let mut rest = self.clone();
+        let mut chunks = Vec::new();
+        while !rest.is_zero() {
+            chunks.push(rest.div_rem_small(DECIMAL_CHUNK));
+        }
+        let mut digits = String::with_capacity(chunks.len() * DECIMAL_CHUNK_DIGITS);
+        digits.push_str(&chunks.pop().unwrap_or(0).to_string());
+        for chunk in chunks.iter().rev() {
+            digits.push_str(&format!("{:019}", chunk));
+        }
+        f.pad_integral(true, "", &digits)
+    }
+}
+
+impl fmt::LowerHex for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut digits = format!("{:x}", self.limbs.last().copied().unwrap_or(0));
+        for limb in self.limbs.iter().rev().skip(1) {
+            digits.push_str(&format!("{:016x}", limb));
+        }
+        f.pad_integral(true, "0x", &digits)
+    }
+}
+
+impl fmt::UpperHex for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut digits = format!("{:X}", self.limbs.last().copied().unwrap_or(0));
+        for limb in self.limbs.iter().rev().skip(1) {
+            digits.push_str(&format!("{:016X}", limb));
+        }
+        f.pad_integral(true, "0x", &digits)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseBigUintError;
+
+impl fmt::Display for ParseBigUintError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid digit found in string")
+    }
+}
+
+impl std::error::Error for ParseBigUintError {}
+
+impl FromStr for BigUint {
+    type Err = ParseBigUintError;
+
+    fn from_str(s: &str) -> Result<BigUint, ParseBigUintError> {
+        if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ParseBigUintError);
+        }
+        let mut number = BigUint::zero();
+        let first = s.len() % DECIMAL_CHUNK_DIGITS;
+        let (head, tail) = s.split_at(first);
+        if !head.is_empty() {
+            number.mul_small_add(1, head.parse().map_err(|_| ParseBigUintError)?);
+        }
+        for start in (0..tail.len()).step_by(DECIMAL_CHUNK_DIGITS) {
+            let chunk = &tail[start..start + DECIMAL_CHUNK_DIGITS];
+            number.mul_small_add(DECIMAL_CHUNK, chunk.parse().map_err(|_| ParseBigUintError)?);
+        }
+        Ok(number)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_with_carry_across_limbs() {
+        let max = BigUint::from(u64::MAX);
+        let sum = &max + &BigUint::one();
+        assert_eq!(sum.limbs, vec![0, 1]);
+        assert_eq!(sum.bits(), 65);
+        assert_eq!(sum.to_string(), "18446744073709551616");
+        assert_eq!(format!("{:x}", sum), "10000000000000000");
+        assert_eq!(format!("{:#X}", sum), "0x10000000000000000");
+    }
+
+    #[test]
+    fn decimal_round_trip() {
+        for text in [
+            "0",
+            "7",
+            "10000000000000000000",
+            "123456789012345678901234567890123456789",
+        ] {
+            assert_eq!(text.parse::<BigUint>().unwrap().to_string(), text);
+        }
+        assert_eq!("00042".parse::<BigUint>().unwrap(), BigUint::from(42));
+        assert!("12a".parse::<BigUint>().is_err());
+        assert!("".parse::<BigUint>().is_err());
+    }
+
+    #[test]
+    fn sub_and_mul() {
+        let a: BigUint = "340282366920938463463374607431768211456".parse().unwrap();
+        let b: BigUint = "18446744073709551617".parse().unwrap();
+        assert_eq!(
+            (&a - &b).to_string(),
+            "340282366920938463444927863358058659839"
+        );
+        assert_eq!(b.checked_sub(&a), None);
+        assert_eq!((&a - &a), BigUint::zero());
+        assert_eq!(
+            (&a * &b).to_string(),
+            "6277101735386680764176071790128604879565730051895802724352"
+        );
+        assert_eq!(&a * &BigUint::zero(), BigUint::zero());
+    }
+
+    // Karatsuba against schoolbook on numbers big enough to recurse several times, with balanced and unbalanced sizes.
+    #[test]
+    fn karatsuba_matches_schoolbook() {
+        let mut seed = 0x2545F4914F6CDD1Du64;
+        let mut random_limbs = |len: usize| -> Vec<u64> {
+            (0..len)
+                .map(|_| {
+                    seed ^= seed << 13;
+                    seed ^= seed >> 7;
+                    seed ^= seed << 17;
+                    seed
+                })
+                .collect()
+        };
+        for (len_a, len_b) in [(40, 40), (257, 300), (500, 70), (129, 33)] {
+            let a = random_limbs(len_a);
+            let b = random_limbs(len_b);
+            assert_eq!(mul_limbs(&a, &b), schoolbook_mul(&a, &b));
+        }
+        let all_ones = vec![u64::MAX; 100];
+        assert_eq!(
+            mul_limbs(&all_ones, &all_ones),
+            schoolbook_mul(&all_ones, &all_ones)
+        );
+    }
+
+    #[test]
+    fn ordering() {
+        let small: BigUint = "18446744073709551615".parse().unwrap();
+        let large: BigUint = "18446744073709551616".parse().unwrap();
+        assert!(small < large);
+        assert!(BigUint::zero() < small);
+        assert_eq!(format!("{:>5}", BigUint::from(42)), "   42");
+    }
+}
@@ -0,0 +1,60 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 05:42:49
+/// @Description: Fibonacci Numbers Library
+/*
+- The Fibonacci sequence starts with F(0) = 0 and F(1) = 1, every later number is the sum of the previous two: 0, 1, 1, 2, 3, 5, 8, 13, ...
+- The numbers grow exponentially, F(n) has about 0.694 * n bits. F(93) is the last one that fits in a u64 and F(46) the last one in an i32, so exact values for
+  large n need an integer type without a fixed width. BigUint in the big_uint module is that type.
+- fibonacci(n) uses the fast doubling algorithm. The other algorithms are in the algorithm module behind the FibonacciAlgorithm trait.
+- F(n) mod m for huge n and the Pisano periods are in the modular module.
+- Fibonacci is one linear recurrence among many (Lucas, Pell, Tribonacci, ...). LinearRecurrence in the recurrence module handles all of them.
+- When the numbers have to fit in a machine integer, the Fibonacci iterator in the sequence module yields them lazily and stops before overflowing. Zeckendorf
+  representations and the Fibonacci code built on it are in the zeckendorf module.
+*/
+pub mod algorithm;
+mod big_uint;
+pub mod modular;
+pub mod recurrence;
+pub mod sequence;
+pub mod zeckendorf;
+
+pub use algorithm::{FastDoubling, FibonacciAlgorithm, Iterative, Matrix};
+pub use big_uint::{BigUint, ParseBigUintError};
+pub use modular::{fib_mod, fib_mod_big, pisano_period};
+pub use recurrence::{LinearRecurrence, RecurrenceError, TermError};
+pub use sequence::{Fibonacci, FibonacciInteger};
+pub use zeckendorf::{
+    fibonacci_decode, fibonacci_encode, from_zeckendorf_bits, zeckendorf, zeckendorf_bits,
+    FibonacciCodeError, ZeckendorfError,
+};
+
+pub fn fibonacci(n: u64) -> BigUint {
+    FastDoubling.fibonacci(n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_numbers() {
+        let expected = [
+            0u64, 1, 1, 2, 3, 5, 8, 13, 21, 34, 55, 89, 144, 233, 377, 610,
+        ];
+        for (n, value) in expected.iter().enumerate() {
+            assert_eq!(fibonacci(n as u64), BigUint::from(*value));
+        }
+    }
+
+    #[test]
+    fn beyond_machine_integers() {
+        assert_eq!(fibonacci(47).to_string(), "2971215073");
+        assert_eq!(fibonacci(93).to_u64(), Some(12200160415121876738));
+        assert_eq!(fibonacci(94).to_u64(), None);
+        assert_eq!(
+            fibonacci(300).to_string(),
+            "222232244629420445529739893461909967206666939096499764990979600"
+        );
+        assert_eq!(format!("{:x}", fibonacci(100)), "1333db76a7c594bfc3");
+    }
+}
@@ -1,20 +1,53 @@
 /// @Author: Mitul
 /// @Date:   2024-11-15 20:05:04
 /// @Description: Nth Fibonacci Number
+use fibonacci_series::algorithm::{algorithm_by_name, algorithms};
+use fundamentals_core::{exit_with_usage, Args};
+
+fn usage() -> String {
+    let names: Vec<&str> = algorithms()
+        .iter()
+        .map(|algorithm| algorithm.name())
+        .collect();
+    format!(
+        "Usage: fibonacci_series <N> [--hex] [--algorithm NAME]\nAlgorithms: {} (default fast-doubling)",
+        names.join(", ")
+    )
+}
+
+fn fail(message: &dyn std::fmt::Display) -> ! {
+    exit_with_usage(message, &usage())
+}
+
 fn main() {
-    let n = 15;
-    let mut i = 2;
-    let mut first = 0;
-    let mut second = 1;
-    let mut number;
-    let fibonacci_number = loop {
-        number = first + second;
-        first = second;
-        second = number;
-        i += 1;
-        if i > n {
-            break number;
+    let mut n = None;
+    let mut hex = false;
+    let mut algorithm_name = String::from("fast-doubling");
+    let mut args = Args::from_env();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--hex" => hex = true,
+            "--algorithm" => {
+                algorithm_name = args.value("--algorithm").unwrap_or_else(|err| fail(&err))
+            }
+            "-h" | "--help" => {
+                println!("{}", usage());
+                return;
+            }
+            _ => match arg.parse::<u64>() {
+                Ok(value) if n.is_none() => n = Some(value),
+                _ => fail(&format!("unexpected argument '{}'", arg)),
+            },
         }
-    };
-    println!("{}th Fibonacci Number is {:?}", n, fibonacci_number);
+    }
+    let n = n.unwrap_or(15);
+    let algorithm = algorithm_by_name(&algorithm_name)
+        .unwrap_or_else(|| fail(&format!("unknown algorithm '{}'", algorithm_name)));
+
+    let fibonacci_number = algorithm.fibonacci(n);
+    if hex {
+        println!("F({}) = {:#x}", n, fibonacci_number);
+    } else {
+        println!("F({}) = {}", n, fibonacci_number);
+    }
 }
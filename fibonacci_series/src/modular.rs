@@ -0,0 +1,240 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 05:45:02
+/// @Description: Fibonacci Numbers Modulo m and Pisano Periods
+/*
+- fib_mod(n, m) is F(n) mod m without ever computing F(n). It runs the fast doubling identities with every intermediate value reduced mod m, so all numbers
+  stay below m and the work is O(log n) word operations. n can be a u128, or a BigUint of any size with fib_mod_big.
+- The sequence F(n) mod m is periodic, the length of the period is the Pisano period π(m). For example mod 3 the sequence is 0, 1, 1, 2, 0, 2, 2, 1 and then
+  repeats, so π(3) = 8.
+- Looking for the period by walking the sequence takes up to 6m steps. pisano_period(m) uses the factorization of m instead:
+    -> π(m) is the least common multiple of π(p^k) over the prime powers p^k in m.
+    -> π(p^k) = p^(k-1) * π(p). This is Wall's conjecture, it has been checked for every prime below 10^14.
+    -> π(2) = 3 and π(5) = 20. For other primes π(p) divides p - 1 when p ends in 1 or 9, and 2(p + 1) otherwise, so only the divisors of that number have to
+       be tried, smallest first, until (F(d), F(d+1)) mod p is (0, 1) again.
+    -> The divisors are u128, 2(p + 1) is past u64::MAX for primes above 2^63. Only a period that does not fit in a u64 makes pisano_period panic.
+- Factorization is trial division, which is quick for every m up to 10^9 and anything up to about 10^14.
+*/
+use crate::BigUint;
+
+// (F(k), F(k+1)) mod m where k is given by its bits from the most significant one down.
+fn fib_pair_mod<I: Iterator<Item = bool>>(bits: I, m: u64) -> (u64, u64) {
+    assert!(m > 0, "modulus must be positive");
+    let m = m as u128;
+    let mut a = 0u128; // F(k) mod m
+    let mut b = 1 % m; // F(k + 1) mod m
+    for bit in bits {
+        let even = a * ((2 * b + m - a) % m) % m;
+        let odd = (a * a % m + b * b % m) % m;
+        if bit {
+            a = odd;
+            b = (even + odd) % m;
+        } else {
+            a = even;
+            b = odd;
+        }
+    }
+    (a as u64, b as u64)
+}
+
+pub fn fib_mod(n: u128, m: u64) -> u64 {
+    let bits = (0..u128::BITS - n.leading_zeros())
+        .rev()
+        .map(|i| n >> i & 1 == 1);
+    fib_pair_mod(bits, m).0
+}
+
+pub fn fib_mod_big(n: &BigUint, m: u64) -> u64 {
+    let bits = (0..n.bits()).rev().map(|i| n.bit(i));
+    fib_pair_mod(bits, m).0
+}
+
+// Prime factors with their exponents, smallest prime first.
+fn factorize(mut n: u64) -> Vec<(u64, u32)> {
+    let mut factors = Vec::new();
+    let mut p = 2;
+    while p <= n / p {
+        if n.is_multiple_of(p) {
+            let mut exponent = 0;
+            while n.is_multiple_of(p) {
+                n /= p;
+                exponent += 1;
+            }
+            factors.push((p, exponent));
+        }
+        p += if p == 2 { 1 } else { 2 };
+    }
+    if n > 1 {
+        factors.push((n, 1));
+    }
+    factors
+}
+
+// The divisors of the number with these prime factors, smallest first.
+fn divisors(factors: &[(u64, u32)]) -> Vec<u128> {
+    let mut divisors = vec![1];
+    for &(p, exponent) in factors {
+        let count = divisors.len();
+        let mut power = 1;
+        for _ in 0..exponent {
+            power *= p as u128;
+            for i in 0..count {
+                divisors.push(divisors[i] * power);
+            }
+        }
+    }
+    divisors.sort_unstable();
+    divisors
+}
+
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+// u128 because 2(p + 1) does not fit in a u64 for primes above 2^63, it is factorized as p + 1 with one more 2.
+fn prime_pisano_period(p: u64) -> u128 {
+    match p {
+        2 => 3,
+        5 => 20,
+        _ => {
+            let bound = if p % 10 == 1 || p % 10 == 9 {
+                factorize(p - 1)
+            } else {
+                // p is odd, so p + 1 is even and its first factor is 2.
+                let mut factors = factorize(p + 1);
+                factors[0].1 += 1;
+                factors
+            };
+            divisors(&bound)
+                .into_iter()
+                .find(|&d| fib_pair_mod_u128(d, p) == (0, 1))
+                .expect("the period always divides the bound")
+        }
+    }
+}
+
+fn fib_pair_mod_u128(n: u128, m: u64) -> (u64, u64) {
+    fib_pair_mod(
+        (0..u128::BITS - n.leading_zeros())
+            .rev()
+            .map(|i| n >> i & 1 == 1),
+        m,
+    )
+}
+
+pub fn pisano_period(m: u64) -> u64 {
+    assert!(m > 0, "modulus must be positive");
+    let period = factorize(m)
+        .into_iter()
+        .fold(1u128, |period, (p, exponent)| {
+            let prime_power_period = prime_pisano_period(p) * (p as u128).pow(exponent - 1);
+            period / gcd(period, prime_power_period) * prime_power_period
+        });
+    // π(m) <= 6m, so the period only leaves the u64 range for moduli close to u64::MAX.
+    u64::try_from(period).expect("Pisano period does not fit in a u64")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fibonacci;
+
+    fn naive_fib_mod(n: u64, m: u64) -> u64 {
+        let m = m as u128;
+        let (mut a, mut b) = (0, 1 % m);
+        for _ in 0..n {
+            (a, b) = (b, (a + b) % m);
+        }
+        a as u64
+    }
+
+    fn naive_pisano_period(m: u64) -> u64 {
+        if m == 1 {
+            return 1;
+        }
+        let (mut a, mut b) = (0, 1);
+        let mut period = 0;
+        loop {
+            (a, b) = (b, (a + b) % m);
+            period += 1;
+            if (a, b) == (0, 1) {
+                return period;
+            }
+        }
+    }
+
+    #[test]
+    fn fib_mod_matches_naive_loop() {
+        for m in 1..60 {
+            for n in 0..300 {
+                assert_eq!(
+                    fib_mod(n as u128, m),
+                    naive_fib_mod(n, m),
+                    "n = {}, m = {}",
+                    n,
+                    m
+                );
+            }
+        }
+        for m in [1_000_000_007, u64::MAX, u64::MAX - 58] {
+            for n in [0, 1, 2, 93, 1000, 12345] {
+                assert_eq!(
+                    fib_mod(n as u128, m),
+                    naive_fib_mod(n, m),
+                    "n = {}, m = {}",
+                    n,
+                    m
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn fib_mod_big_matches_exact_value() {
+        let modulus = 1_000_000_007;
+        for n in [0u64, 1, 2, 100, 1000, 65_537] {
+            let exact = fibonacci(n);
+            assert_eq!(
+                fib_mod_big(&BigUint::from(n), modulus),
+                exact.rem_u64(modulus)
+            );
+        }
+        // n = 2^64 + 5 only fits in a BigUint, and F(n) mod 10 is periodic with period 60.
+        let n: BigUint = "18446744073709551621".parse().unwrap();
+        assert_eq!(fib_mod_big(&n, 10), naive_fib_mod(n.rem_u64(60), 10));
+        assert_eq!(fib_mod(18446744073709551621, 10), fib_mod_big(&n, 10));
+    }
+
+    #[test]
+    fn pisano_period_matches_naive_search() {
+        for m in 1..2000 {
+            assert_eq!(pisano_period(m), naive_pisano_period(m), "m = {}", m);
+        }
+    }
+
+    #[test]
+    fn pisano_period_for_large_moduli() {
+        assert_eq!(pisano_period(10), 60);
+        assert_eq!(pisano_period(1_000_000_000), 1_500_000_000);
+        assert_eq!(pisano_period(1_000_000_007), 2_000_000_016);
+        let period = pisano_period(999_999_937);
+        assert_eq!(fib_mod(period as u128, 999_999_937), 0);
+        assert_eq!(fib_mod(period as u128 + 1, 999_999_937), 1);
+    }
+
+    #[test]
+    fn factorize_and_divisors() {
+        assert_eq!(factorize(360), vec![(2, 3), (3, 2), (5, 1)]);
+        assert_eq!(factorize(999_999_937), vec![(999_999_937, 1)]);
+        assert_eq!(divisors(&factorize(12)), vec![1, 2, 3, 4, 6, 12]);
+    }
+
+    #[test]
+    fn prime_periods_above_2_to_the_63() {
+        // 2(p + 1) is past u64::MAX and it is the period itself.
+        let p = 18_446_744_073_709_551_337;
+        assert_eq!(prime_pisano_period(p), 2 * (p as u128 + 1));
+    }
+}
@@ -0,0 +1,486 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 05:46:25
+/// @Description: Linear Recurrence Sequences
+/*
+- A linear recurrence of order k defines every term from the k terms before it:
+      a(n) = c1 * a(n-1) + c2 * a(n-2) + ... + ck * a(n-k)
+  and is fixed by the coefficients c1..ck together with the first k terms a(0)..a(k-1). Fibonacci is c = [1, 1] starting from [0, 1].
+- Terms are i128 and every operation is checked, so iteration stops and nth returns TermError::Overflow once a value no longer fits instead of wrapping
+  around.
+- nth(n) raises the k x k companion matrix to the n-th power by repeated squaring, which is O(k^3 log n) instead of the O(k n) of iterating. The first row of
+  the companion matrix holds the coefficients and the ones below the diagonal shift the window of the last k terms by one.
+    -> The powers of the matrix can outgrow i128 while the terms stay small, like a(n) = 3a(n-1) - 2a(n-2) from [1, 1], which is 1 forever. nth then steps
+       the window term by term instead, for n up to 2^20. Further away it returns TermError::TooFar, which is not a claim that the term does not fit:
+       the powers of x modulo the characteristic polynomial grow just as fast, only exact big integer arithmetic would tell. An all-zero start is zero
+       for every n.
+- identify(prefix) finds the shortest recurrence that produces a given prefix with the Berlekamp–Massey algorithm. The algorithm needs a field, so it runs
+  modulo the prime 2^61 - 1 and the coefficients it finds are mapped back to signed integers. The candidate is then checked against the prefix in exact integer
+  arithmetic. A recurrence of order L is only pinned down by at least 2L terms, so a shorter prefix gives None rather than a guess.
+*/
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt;
+
+const FIELD_PRIME: u64 = (1 << 61) - 1;
+const MAX_STEPS: u64 = 1 << 20;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RecurrenceError {
+    Empty,
+    LengthMismatch { coefficients: usize, initial: usize },
+}
+
+impl fmt::Display for RecurrenceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RecurrenceError::Empty => write!(f, "a recurrence needs at least one coefficient"),
+            RecurrenceError::LengthMismatch {
+                coefficients,
+                initial,
+            } => write!(
+                f,
+                "{} coefficients need {} initial terms, got {}",
+                coefficients, coefficients, initial
+            ),
+        }
+    }
+}
+
+impl Error for RecurrenceError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TermError {
+    // The term, or one of the terms before it, does not fit in an i128.
+    Overflow,
+    // The powers of the companion matrix do not fit and the term is too far away to step to. It may still fit.
+    TooFar,
+}
+
+impl fmt::Display for TermError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TermError::Overflow => write!(f, "the term does not fit in an i128"),
+            TermError::TooFar => write!(
+                f,
+                "the term cannot be computed, the matrix powers do not fit and it is more than {} steps away",
+                MAX_STEPS
+            ),
+        }
+    }
+}
+
+impl Error for TermError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinearRecurrence {
+    coefficients: Vec<i64>,
+    initial: Vec<i128>,
+}
+
+impl LinearRecurrence {
+    pub fn new(
+        coefficients: Vec<i64>,
+        initial: Vec<i128>,
+    ) -> Result<LinearRecurrence, RecurrenceError> {
+        if coefficients.is_empty() {
+            return Err(RecurrenceError::Empty);
+        }
+        if coefficients.len() != initial.len() {
+            return Err(RecurrenceError::LengthMismatch {
+                coefficients: coefficients.len(),
+                initial: initial.len(),
+            });
+        }
+        Ok(LinearRecurrence {
+            coefficients,
+            initial,
+        })
+    }
+
+    pub fn fibonacci() -> LinearRecurrence {
+        LinearRecurrence::new(vec![1, 1], vec![0, 1]).unwrap()
+    }
+
+    pub fn lucas() -> LinearRecurrence {
+        LinearRecurrence::new(vec![1, 1], vec![2, 1]).unwrap()
+    }
+
+    pub fn pell() -> LinearRecurrence {
+        LinearRecurrence::new(vec![2, 1], vec![0, 1]).unwrap()
+    }
+
+    pub fn tribonacci() -> LinearRecurrence {
+        LinearRecurrence::new(vec![1, 1, 1], vec![0, 0, 1]).unwrap()
+    }
+
+    pub fn padovan() -> LinearRecurrence {
+        LinearRecurrence::new(vec![0, 1, 1], vec![1, 1, 1]).unwrap()
+    }
+
+    pub fn order(&self) -> usize {
+        self.coefficients.len()
+    }
+
+    pub fn coefficients(&self) -> &[i64] {
+        &self.coefficients
+    }
+
+    pub fn initial_terms(&self) -> &[i128] {
+        &self.initial
+    }
+
+    pub fn iter(&self) -> Terms<'_> {
+        Terms {
+            recurrence: self,
+            window: self.initial.iter().copied().collect(),
+            exhausted: false,
+        }
+    }
+
+    pub fn nth(&self, n: u64) -> Result<i128, TermError> {
+        let k = self.order();
+        if n < k as u64 {
+            return Ok(self.initial[n as usize]);
+        }
+        if self.initial.iter().all(|&term| term == 0) {
+            return Ok(0);
+        }
+        // M^(n-k+1) moves the window [a(k-1), ..., a(0)] to [a(n), ..., a(n-k+1)].
+        let term = matrix_power(&self.companion_matrix(), n - k as u64 + 1).and_then(|power| {
+            let mut term = 0i128;
+            for (entry, value) in power[0].iter().zip(self.initial.iter().rev()) {
+                term = term.checked_add(entry.checked_mul(*value)?)?;
+            }
+            Some(term)
+        });
+        match term {
+            Some(term) => Ok(term),
+            None if n <= MAX_STEPS => self.iter().nth(n as usize).ok_or(TermError::Overflow),
+            None => Err(TermError::TooFar),
+        }
+    }
+
+    fn companion_matrix(&self) -> Matrix {
+        let k = self.order();
+        let mut matrix = vec![vec![0i128; k]; k];
+        for (j, coefficient) in self.coefficients.iter().enumerate() {
+            matrix[0][j] = *coefficient as i128;
+        }
+        for i in 1..k {
+            matrix[i][i - 1] = 1;
+        }
+        matrix
+    }
+
+    pub fn identify(prefix: &[i128]) -> Option<LinearRecurrence> {
+        if prefix.is_empty() {
+            return None;
+        }
+        let field_values: Vec<u64> = prefix.iter().map(|value| to_field(*value)).collect();
+        let connection = berlekamp_massey(&field_values);
+        let order = connection.len() - 1;
+        if order == 0 {
+            // Only an all-zero prefix has an empty recurrence, describe it as a(n) = 0 * a(n-1).
+            return LinearRecurrence::new(vec![0], vec![prefix[0]]).ok();
+        }
+        if prefix.len() < 2 * order {
+            return None;
+        }
+
+        let coefficients = connection[1..]
+            .iter()
+            .map(|c| i64::try_from(from_field((FIELD_PRIME - c) % FIELD_PRIME)).ok())
+            .collect::<Option<Vec<i64>>>()?;
+        let recurrence = LinearRecurrence::new(coefficients, prefix[..order].to_vec()).ok()?;
+        let generated: Vec<i128> = recurrence.iter().take(prefix.len()).collect();
+        if generated == prefix {
+            Some(recurrence)
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for LinearRecurrence {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut terms = String::new();
+        for (i, coefficient) in self.coefficients.iter().enumerate() {
+            if *coefficient == 0 {
+                continue;
+            }
+            let sign = match (terms.is_empty(), *coefficient < 0) {
+                (_, true) => " -",
+                (true, false) => "",
+                (false, false) => " +",
+            };
+            let magnitude = match coefficient.unsigned_abs() {
+                1 => String::new(),
+                magnitude => format!("{}*", magnitude),
+            };
+            terms.push_str(&format!("{} {}a(n-{})", sign, magnitude, i + 1));
+        }
+        if terms.is_empty() {
+            terms.push_str(" 0");
+        }
+        write!(f, "a(n) ={}, starting with {:?}", terms, self.initial)
+    }
+}
+
+pub struct Terms<'a> {
+    recurrence: &'a LinearRecurrence,
+    window: VecDeque<i128>,
+    exhausted: bool,
+}
+
+impl Iterator for Terms<'_> {
+    type Item = i128;
+
+    // The window holds the next k terms, oldest first. Once the term after them does not fit, the window is drained and iteration ends.
+    fn next(&mut self) -> Option<i128> {
+        if !self.exhausted {
+            let mut next = Some(0i128);
+            for (coefficient, value) in self
+                .recurrence
+                .coefficients
+                .iter()
+                .zip(self.window.iter().rev())
+            {
+                next =
+                    next.and_then(|sum| sum.checked_add(value.checked_mul(*coefficient as i128)?));
+            }
+            match next {
+                Some(next) => self.window.push_back(next),
+                None => self.exhausted = true,
+            }
+        }
+        self.window.pop_front()
+    }
+}
+
+type Matrix = Vec<Vec<i128>>;
+
+fn matrix_multiply(a: &Matrix, b: &Matrix) -> Option<Matrix> {
+    let k = a.len();
+    let mut product = vec![vec![0i128; k]; k];
+    for i in 0..k {
+        for j in 0..k {
+            let mut sum = 0i128;
+            for m in 0..k {
+                sum = sum.checked_add(a[i][m].checked_mul(b[m][j])?)?;
+            }
+            product[i][j] = sum;
+        }
+    }
+    Some(product)
+}
+
+fn matrix_power(base: &Matrix, mut exponent: u64) -> Option<Matrix> {
+    let k = base.len();
+    let mut result: Matrix = (0..k)
+        .map(|i| (0..k).map(|j| (i == j) as i128).collect())
+        .collect();
+    let mut base = base.clone();
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = matrix_multiply(&result, &base)?;
+        }
+        exponent >>= 1;
+        if exponent > 0 {
+            base = matrix_multiply(&base, &base)?;
+        }
+    }
+    Some(result)
+}
+
+//------------------------------------------------------------Berlekamp–Massey------------------------------------------------------
+
+fn to_field(value: i128) -> u64 {
+    value.rem_euclid(FIELD_PRIME as i128) as u64
+}
+
+// Maps back to the representative closest to zero, so small negative coefficients come back negative.
+fn from_field(value: u64) -> i128 {
+    if value > FIELD_PRIME / 2 {
+        value as i128 - FIELD_PRIME as i128
+    } else {
+        value as i128
+    }
+}
+
+fn mul_mod(a: u64, b: u64) -> u64 {
+    (a as u128 * b as u128 % FIELD_PRIME as u128) as u64
+}
+
+fn pow_mod(mut base: u64, mut exponent: u64) -> u64 {
+    let mut result = 1;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul_mod(result, base);
+        }
+        base = mul_mod(base, base);
+        exponent >>= 1;
+    }
+    result
+}
+
+// Returns the connection polynomial C with C[0] = 1 and sum C[i] * s[n-i] = 0 for every n >= L, where L = C.len() - 1.
+fn berlekamp_massey(sequence: &[u64]) -> Vec<u64> {
+    let mut current = vec![1u64];
+    let mut previous = vec![1u64];
+    let mut length = 0;
+    let mut shift = 1;
+    let mut previous_discrepancy = 1u64;
+
+    for n in 0..sequence.len() {
+        let mut discrepancy = sequence[n];
+        for i in 1..=length {
+            discrepancy = (discrepancy + mul_mod(current[i], sequence[n - i])) % FIELD_PRIME;
+        }
+        if discrepancy == 0 {
+            shift += 1;
+            continue;
+        }
+
+        let factor = mul_mod(discrepancy, pow_mod(previous_discrepancy, FIELD_PRIME - 2));
+        let before = current.clone();
+        if current.len() < previous.len() + shift {
+            current.resize(previous.len() + shift, 0);
+        }
+        for (i, value) in previous.iter().enumerate() {
+            let reduced = mul_mod(factor, *value);
+            current[i + shift] = (current[i + shift] + FIELD_PRIME - reduced) % FIELD_PRIME;
+        }
+
+        if 2 * length <= n {
+            length = n + 1 - length;
+            previous = before;
+            previous_discrepancy = discrepancy;
+            shift = 1;
+        } else {
+            shift += 1;
+        }
+    }
+    current.resize(length + 1, 0);
+    current
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn first(recurrence: &LinearRecurrence, count: usize) -> Vec<i128> {
+        recurrence.iter().take(count).collect()
+    }
+
+    #[test]
+    fn known_sequences() {
+        assert_eq!(
+            first(&LinearRecurrence::lucas(), 8),
+            vec![2, 1, 3, 4, 7, 11, 18, 29]
+        );
+        assert_eq!(
+            first(&LinearRecurrence::pell(), 8),
+            vec![0, 1, 2, 5, 12, 29, 70, 169]
+        );
+        assert_eq!(
+            first(&LinearRecurrence::tribonacci(), 8),
+            vec![0, 0, 1, 1, 2, 4, 7, 13]
+        );
+        assert_eq!(
+            first(&LinearRecurrence::padovan(), 10),
+            vec![1, 1, 1, 2, 2, 3, 4, 5, 7, 9]
+        );
+    }
+
+    #[test]
+    fn nth_matches_iteration() {
+        let custom = LinearRecurrence::new(vec![3, -2], vec![1, 4]).unwrap();
+        for recurrence in [
+            LinearRecurrence::fibonacci(),
+            LinearRecurrence::tribonacci(),
+            LinearRecurrence::padovan(),
+            custom,
+        ] {
+            for (n, term) in recurrence.iter().enumerate() {
+                assert_eq!(
+                    recurrence.nth(n as u64),
+                    Ok(term),
+                    "{} at n = {}",
+                    recurrence,
+                    n
+                );
+            }
+        }
+        assert_eq!(
+            LinearRecurrence::fibonacci().nth(184),
+            Ok(127127879743834334146972278486287885163)
+        );
+        assert_eq!(
+            LinearRecurrence::fibonacci().nth(185),
+            Err(TermError::Overflow)
+        );
+    }
+
+    #[test]
+    fn iteration_stops_before_overflow() {
+        let terms = first(&LinearRecurrence::fibonacci(), 1000);
+        assert_eq!(terms.len(), 185);
+        assert_eq!(terms.last(), Some(&127127879743834334146972278486287885163));
+
+        // A sequence that never grows can be evaluated for huge n.
+        let periodic = LinearRecurrence::new(vec![0, 0, 1], vec![5, -1, 7]).unwrap();
+        assert_eq!(periodic.nth(1_000_000_000_000_000_001), Ok(7));
+    }
+
+    #[test]
+    fn nth_of_small_terms_with_huge_matrix_powers() {
+        let zero = LinearRecurrence::new(vec![1, 1], vec![0, 0]).unwrap();
+        assert_eq!(zero.nth(200), Ok(0));
+        assert_eq!(zero.nth(u64::MAX), Ok(0));
+
+        let constant = LinearRecurrence::new(vec![3, -2], vec![1, 1]).unwrap();
+        assert_eq!(constant.nth(500), Ok(1));
+        assert_eq!(constant.nth(MAX_STEPS), Ok(1));
+        // Too far to step to is not reported as too big.
+        assert_eq!(constant.nth(1 << 21), Err(TermError::TooFar));
+    }
+
+    #[test]
+    fn identify_recovers_the_recurrence() {
+        for recurrence in [
+            LinearRecurrence::fibonacci(),
+            LinearRecurrence::lucas(),
+            LinearRecurrence::pell(),
+            LinearRecurrence::tribonacci(),
+            LinearRecurrence::padovan(),
+            LinearRecurrence::new(vec![3, -2, 0, 5], vec![1, -4, 0, 9]).unwrap(),
+        ] {
+            let prefix = first(&recurrence, 12);
+            assert_eq!(LinearRecurrence::identify(&prefix), Some(recurrence));
+        }
+        assert_eq!(LinearRecurrence::identify(&[1, 2, 3]), None);
+        assert_eq!(
+            LinearRecurrence::identify(&[1, 2, 3, 4]),
+            LinearRecurrence::new(vec![2, -1], vec![1, 2]).ok()
+        );
+        assert_eq!(LinearRecurrence::identify(&[]), None);
+        assert_eq!(
+            LinearRecurrence::identify(&[0, 0, 0]).map(|r| r.coefficients().to_vec()),
+            Some(vec![0])
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            LinearRecurrence::pell().to_string(),
+            "a(n) = 2*a(n-1) + a(n-2), starting with [0, 1]"
+        );
+        assert_eq!(
+            LinearRecurrence::new(vec![0, -1, 1], vec![1, 1, 1])
+                .unwrap()
+                .to_string(),
+            "a(n) = - a(n-2) + a(n-3), starting with [1, 1, 1]"
+        );
+    }
+}
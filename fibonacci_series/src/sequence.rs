@@ -0,0 +1,102 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 05:47:15
+/// @Description: Lazy Fibonacci Iterator over Machine Integers
+/*
+- Fibonacci::<T>::new() yields F(0), F(1), F(2), ... as values of the unsigned integer type T, from u8 up to u128.
+- Every addition is checked. When the next number would not fit in T the iterator yields the numbers it still holds and then ends, so it never wraps around
+  and never panics. With u8 it ends after F(13) = 233, with u64 after F(93) and with u128 after F(186).
+- The FibonacciInteger trait is the small set of operations the iterator needs. It is implemented for the unsigned integer types with a macro, the same code
+  would be repeated for each width otherwise.
+*/
+use std::fmt::Debug;
+use std::iter::FusedIterator;
+
+pub trait FibonacciInteger: Copy + Debug + PartialOrd {
+    const ZERO: Self;
+    const ONE: Self;
+    fn checked_add(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! fibonacci_integer {
+    ($($int:ty),+) => {
+        $(impl FibonacciInteger for $int {
+            const ZERO: $int = 0;
+            const ONE: $int = 1;
+
+            fn checked_add(self, other: $int) -> Option<$int> {
+                <$int>::checked_add(self, other)
+            }
+        })+
+    };
+}
+
+fibonacci_integer!(u8, u16, u32, u64, u128, usize);
+
+#[derive(Debug, Clone)]
+pub struct Fibonacci<T: FibonacciInteger> {
+    current: Option<T>,
+    next: Option<T>,
+}
+
+impl<T: FibonacciInteger> Fibonacci<T> {
+    pub fn new() -> Fibonacci<T> {
+        Fibonacci {
+            current: Some(T::ZERO),
+            next: Some(T::ONE),
+        }
+    }
+}
+
+impl<T: FibonacciInteger> Default for Fibonacci<T> {
+    fn default() -> Fibonacci<T> {
+        Fibonacci::new()
+    }
+}
+
+impl<T: FibonacciInteger> Iterator for Fibonacci<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let current = self.current?;
+        let after = match self.next {
+            Some(next) => current.checked_add(next),
+            None => None,
+        };
+        self.current = self.next;
+        self.next = after;
+        Some(current)
+    }
+}
+
+impl<T: FibonacciInteger> FusedIterator for Fibonacci<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fibonacci;
+
+    #[test]
+    fn stops_at_the_last_number_that_fits() {
+        assert_eq!(Fibonacci::<u8>::new().count(), 14);
+        assert_eq!(Fibonacci::<u8>::new().last(), Some(233));
+        assert_eq!(Fibonacci::<u16>::new().last(), Some(46368));
+        assert_eq!(Fibonacci::<u32>::new().count(), 48);
+        assert_eq!(Fibonacci::<u64>::new().last(), Some(12200160415121876738));
+        assert_eq!(Fibonacci::<u128>::new().count(), 187);
+    }
+
+    #[test]
+    fn matches_big_integer_values() {
+        for (n, value) in Fibonacci::<u128>::new().enumerate() {
+            assert_eq!(value.to_string(), fibonacci(n as u64).to_string());
+        }
+    }
+
+    #[test]
+    fn fused_after_the_end() {
+        let mut numbers = Fibonacci::<u8>::new();
+        numbers.by_ref().for_each(drop);
+        assert_eq!(numbers.next(), None);
+        assert_eq!(numbers.next(), None);
+    }
+}
@@ -0,0 +1,248 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 05:47:15
+/// @Description: Zeckendorf Representation and Fibonacci Coding
+/*
+- Zeckendorf's theorem: every positive integer is a sum of distinct Fibonacci numbers, no two of them consecutive, in exactly one way. 100 = 89 + 8 + 3.
+  Taking the largest Fibonacci number that fits and repeating with the rest always finds that sum.
+- As bits, position i stands for F(i + 2), so the bits are 1, 2, 3, 5, 8, ... from the least significant end and there are never two 1 bits next to each other.
+- The Fibonacci code of a positive integer is its Zeckendorf bits, least significant first, with one more 1 appended. Because "11" cannot appear inside a
+  Zeckendorf representation, it marks the end of every code word and a stream of codes can be split without knowing their lengths. Small numbers get short
+  codes: 1 is "11", 2 is "011", 3 is "0011", 4 is "1011".
+- Codes are packed into bytes from the most significant bit down. The last byte is padded with 0 bits, which the decoder ignores. Zero has no code, every value
+  in the stream has to be at least 1.
+*/
+use crate::sequence::Fibonacci;
+use std::error::Error;
+use std::fmt;
+
+// F(2), F(3), ... F(93), every Fibonacci number usable in a Zeckendorf representation of a u64.
+fn zeckendorf_table() -> Vec<u64> {
+    Fibonacci::<u64>::new().skip(2).collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ZeckendorfError {
+    ConsecutiveOnes { position: usize },
+    Overflow,
+}
+
+impl fmt::Display for ZeckendorfError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ZeckendorfError::ConsecutiveOnes { position } => {
+                write!(
+                    f,
+                    "bits {} and {} are both set, which Zeckendorf form does not allow",
+                    position - 1,
+                    position
+                )
+            }
+            ZeckendorfError::Overflow => write!(f, "value does not fit in a u64"),
+        }
+    }
+}
+
+impl Error for ZeckendorfError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FibonacciCodeError {
+    Zero { index: usize },
+    Truncated,
+    Overflow { index: usize },
+}
+
+impl fmt::Display for FibonacciCodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FibonacciCodeError::Zero { index } => write!(
+                f,
+                "value {} is 0, only positive integers have a Fibonacci code",
+                index
+            ),
+            FibonacciCodeError::Truncated => {
+                write!(f, "the stream ends in the middle of a code word")
+            }
+            FibonacciCodeError::Overflow { index } => {
+                write!(f, "code word {} does not fit in a u64", index)
+            }
+        }
+    }
+}
+
+impl Error for FibonacciCodeError {}
+
+// The Fibonacci numbers that sum to n, largest first. Empty for 0.
+pub fn zeckendorf(n: u64) -> Vec<u64> {
+    let mut rest = n;
+    let mut terms = Vec::new();
+    for number in zeckendorf_table().into_iter().rev() {
+        if number <= rest {
+            terms.push(number);
+            rest -= number;
+        }
+    }
+    terms
+}
+
+// Least significant bit first, without trailing zeros.
+pub fn zeckendorf_bits(n: u64) -> Vec<bool> {
+    let table = zeckendorf_table();
+    let mut bits = vec![false; table.len()];
+    let mut rest = n;
+    for (i, number) in table.iter().enumerate().rev() {
+        if *number <= rest {
+            bits[i] = true;
+            rest -= number;
+        }
+    }
+    while bits.last() == Some(&false) {
+        bits.pop();
+    }
+    bits
+}
+
+pub fn from_zeckendorf_bits(bits: &[bool]) -> Result<u64, ZeckendorfError> {
+    let table = zeckendorf_table();
+    let mut value = 0u64;
+    for (i, bit) in bits.iter().enumerate() {
+        if !bit {
+            continue;
+        }
+        if i > 0 && bits[i - 1] {
+            return Err(ZeckendorfError::ConsecutiveOnes { position: i });
+        }
+        let number = table.get(i).ok_or(ZeckendorfError::Overflow)?;
+        value = value
+            .checked_add(*number)
+            .ok_or(ZeckendorfError::Overflow)?;
+    }
+    Ok(value)
+}
+
+struct BitWriter {
+    bytes: Vec<u8>,
+    used: u32,
+}
+
+impl BitWriter {
+    fn push(&mut self, bit: bool) {
+        if self.used.is_multiple_of(8) {
+            self.bytes.push(0);
+        }
+        if bit {
+            *self.bytes.last_mut().unwrap() |= 0x80 >> (self.used % 8);
+        }
+        self.used += 1;
+    }
+}
+
+pub fn fibonacci_encode(values: &[u64]) -> Result<Vec<u8>, FibonacciCodeError> {
+    let mut writer = BitWriter {
+        bytes: Vec::new(),
+        used: 0,
+    };
+    for (index, value) in values.iter().enumerate() {
+        if *value == 0 {
+            return Err(FibonacciCodeError::Zero { index });
+        }
+        for bit in zeckendorf_bits(*value) {
+            writer.push(bit);
+        }
+        writer.push(true);
+    }
+    Ok(writer.bytes)
+}
+
+pub fn fibonacci_decode(bytes: &[u8]) -> Result<Vec<u64>, FibonacciCodeError> {
+    let mut values = Vec::new();
+    let mut word = Vec::new();
+    let bits = bytes
+        .iter()
+        .flat_map(|byte| (0..8).map(move |i| byte & (0x80 >> i) != 0));
+    for bit in bits {
+        if bit && word.last() == Some(&true) {
+            let index = values.len();
+            let value =
+                from_zeckendorf_bits(&word).map_err(|_| FibonacciCodeError::Overflow { index })?;
+            values.push(value);
+            word.clear();
+        } else {
+            word.push(bit);
+        }
+    }
+    // Whatever follows the last code word has to be the zero padding of the final byte.
+    if word.iter().any(|bit| *bit) {
+        return Err(FibonacciCodeError::Truncated);
+    }
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bit_string(bits: &[bool]) -> String {
+        bits.iter()
+            .map(|bit| if *bit { '1' } else { '0' })
+            .collect()
+    }
+
+    #[test]
+    fn representation_of_small_numbers() {
+        assert_eq!(zeckendorf(0), Vec::<u64>::new());
+        assert_eq!(zeckendorf(100), vec![89, 8, 3]);
+        assert_eq!(
+            zeckendorf(u64::MAX)
+                .iter()
+                .map(|n| *n as u128)
+                .sum::<u128>(),
+            u64::MAX as u128
+        );
+        assert_eq!(bit_string(&zeckendorf_bits(4)), "101");
+        assert_eq!(bit_string(&zeckendorf_bits(12)), "10101");
+    }
+
+    #[test]
+    fn bits_round_trip_without_consecutive_ones() {
+        for n in (0..5000).chain([u64::MAX, u64::MAX - 1, 12200160415121876738]) {
+            let bits = zeckendorf_bits(n);
+            assert!(!bits.windows(2).any(|pair| pair[0] && pair[1]), "n = {}", n);
+            assert_eq!(from_zeckendorf_bits(&bits), Ok(n));
+        }
+        assert_eq!(
+            from_zeckendorf_bits(&[true, true]),
+            Err(ZeckendorfError::ConsecutiveOnes { position: 1 })
+        );
+        let mut too_long = vec![false; 100];
+        too_long[99] = true;
+        assert_eq!(
+            from_zeckendorf_bits(&too_long),
+            Err(ZeckendorfError::Overflow)
+        );
+    }
+
+    #[test]
+    fn code_words() {
+        // 1 -> 11, 2 -> 011, 3 -> 0011, 4 -> 1011, packed into 11011001 11011000
+        assert_eq!(
+            fibonacci_encode(&[1, 2, 3, 4]).unwrap(),
+            vec![0b1101_1001, 0b1101_1000]
+        );
+        assert_eq!(
+            fibonacci_encode(&[3, 0]),
+            Err(FibonacciCodeError::Zero { index: 1 })
+        );
+    }
+
+    #[test]
+    fn stream_round_trip() {
+        let values: Vec<u64> = (1..2000).chain([u64::MAX, 1, 7]).collect();
+        let bytes = fibonacci_encode(&values).unwrap();
+        assert_eq!(fibonacci_decode(&bytes), Ok(values));
+        assert_eq!(fibonacci_decode(&[]), Ok(vec![]));
+        assert_eq!(
+            fibonacci_decode(&[0b0101_0000]),
+            Err(FibonacciCodeError::Truncated)
+        );
+    }
+}
@@ -0,0 +1,138 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 06:15:24
+/// @Description: Command Line Arguments
+/*
+- Args wraps the arguments without the program name and is an iterator over them, so a parser is a while let loop with a match on the flag. Inside the
+  loop the flag asks for what follows it:
+    -> value(flag) takes the next argument, whatever it looks like, so a negative number works as a value.
+    -> number(flag) takes the next argument and parses it into any FromStr type.
+    -> optional() takes the next argument only if it is not a flag, for values that may be left out, like the file after --batch.
+- Every mistake is a UsageError with a message naming the flag. The binaries print it with their usage text and exit with code 2 through exit_with_usage.
+*/
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::iter::{Peekable, Skip};
+use std::process;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UsageError(pub String);
+
+impl fmt::Display for UsageError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Error for UsageError {}
+
+// For binaries that collect their errors as plain messages.
+impl From<UsageError> for String {
+    fn from(err: UsageError) -> String {
+        err.0
+    }
+}
+
+pub struct Args<I: Iterator<Item = String>> {
+    args: Peekable<I>,
+}
+
+impl Args<Skip<env::Args>> {
+    pub fn from_env() -> Args<Skip<env::Args>> {
+        Args::new(env::args().skip(1))
+    }
+}
+
+impl<I: Iterator<Item = String>> Args<I> {
+    pub fn new<A: IntoIterator<IntoIter = I>>(args: A) -> Args<I> {
+        Args {
+            args: args.into_iter().peekable(),
+        }
+    }
+
+    pub fn value(&mut self, flag: &str) -> Result<String, UsageError> {
+        self.args
+            .next()
+            .ok_or_else(|| UsageError(format!("{} needs a value", flag)))
+    }
+
+    pub fn number<T: FromStr>(&mut self, flag: &str) -> Result<T, UsageError> {
+        let value = self.value(flag)?;
+        parse_number(&value, flag)
+    }
+
+    pub fn optional(&mut self) -> Option<String> {
+        match self.args.peek() {
+            Some(next) if !next.starts_with("--") => self.args.next(),
+            _ => None,
+        }
+    }
+}
+
+impl<I: Iterator<Item = String>> Iterator for Args<I> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        self.args.next()
+    }
+}
+
+pub fn parse_number<T: FromStr>(value: &str, flag: &str) -> Result<T, UsageError> {
+    value
+        .parse()
+        .map_err(|_| UsageError(format!("{} expects a number, got '{}'", flag, value)))
+}
+
+pub fn exit_with_usage(message: &dyn fmt::Display, usage: &str) -> ! {
+    eprintln!("error: {}\n{}", message, usage);
+    process::exit(2);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Args<std::vec::IntoIter<String>> {
+        Args::new(
+            line.split_whitespace()
+                .map(String::from)
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    #[test]
+    fn values_and_numbers_follow_their_flag() {
+        let mut args = args("--name ferris --start -40 --step 0.5 --games");
+        assert_eq!(args.next().as_deref(), Some("--name"));
+        assert_eq!(args.value("--name").unwrap(), "ferris");
+        args.next();
+        assert_eq!(args.number::<i32>("--start"), Ok(-40));
+        args.next();
+        assert_eq!(args.number::<f64>("--step"), Ok(0.5));
+        args.next();
+        assert_eq!(
+            args.number::<u32>("--games"),
+            Err(UsageError("--games needs a value".to_string()))
+        );
+    }
+
+    #[test]
+    fn bad_numbers_name_the_flag() {
+        let err = args("many").number::<u32>("--games").unwrap_err();
+        assert_eq!(err.to_string(), "--games expects a number, got 'many'");
+        assert_eq!(String::from(err), "--games expects a number, got 'many'");
+    }
+
+    #[test]
+    fn optional_values_stop_at_the_next_flag() {
+        let mut args = args("--batch --to K --csv log.csv");
+        args.next();
+        assert_eq!(args.optional(), None);
+        assert_eq!(args.next().as_deref(), Some("--to"));
+        args.next();
+        args.next();
+        assert_eq!(args.optional().as_deref(), Some("log.csv"));
+        assert_eq!(args.optional(), None);
+    }
+}
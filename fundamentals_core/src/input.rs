@@ -0,0 +1,54 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 06:15:24
+/// @Description: Reading Input Files
+/*
+- open_or_stdin() gives a buffered reader for a file, or for stdin when no file was named on the command line. The caller reads both the same way.
+- data_lines() reads a file of one value per line. Surrounding whitespace is trimmed, blank lines and lines starting with # are skipped, and every line
+  comes with its number, counted from 1 like an editor does, for the error messages.
+*/
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+
+pub fn open_or_stdin(path: Option<&Path>) -> io::Result<Box<dyn BufRead>> {
+    Ok(match path {
+        Some(path) => Box::new(BufReader::new(File::open(path)?)),
+        None => Box::new(io::stdin().lock()),
+    })
+}
+
+pub fn data_lines<R: BufRead>(input: R) -> impl Iterator<Item = io::Result<(usize, String)>> {
+    input
+        .lines()
+        .enumerate()
+        .filter_map(|(index, line)| match line {
+            Ok(line) => {
+                let line = line.trim();
+                (!line.is_empty() && !line.starts_with('#'))
+                    .then(|| Ok((index + 1, line.to_string())))
+            }
+            Err(err) => Some(Err(err)),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skips_blank_lines_and_comments() {
+        let input = "# temperatures\n132F\n\n   \n  0C  \n#-10K\n";
+        let lines: Vec<(usize, String)> = data_lines(input.as_bytes())
+            .collect::<io::Result<_>>()
+            .unwrap();
+        assert_eq!(lines, [(2, "132F".to_string()), (5, "0C".to_string())]);
+    }
+
+    #[test]
+    fn missing_files_are_errors() {
+        let err = open_or_stdin(Some(Path::new("no/such/file.txt")))
+            .err()
+            .unwrap();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    }
+}
@@ -0,0 +1,17 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 06:15:24
+/// @Description: Shared Library of the Workspace
+/*
+- The code every binary of the workspace needs and used to carry a copy of:
+    -> args reads command line flags: the value after a flag, numbers, values that may be left out, and the usage error that ends the program.
+    -> input opens a file or stdin and reads data files one meaningful line at a time, with the line numbers for error messages.
+    -> table prints aligned columns, one row at a time so long running programs can print as they go.
+- It only depends on std, like the crates that use it. They name it in their Cargo.toml as fundamentals_core.workspace = true.
+*/
+pub mod args;
+pub mod input;
+pub mod table;
+
+pub use args::{exit_with_usage, parse_number, Args, UsageError};
+pub use input::{data_lines, open_or_stdin};
+pub use table::{Align, Table};
@@ -0,0 +1,92 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 06:15:24
+/// @Description: Aligned Columns
+/*
+- A Table is a list of columns, each with a header, a width and an alignment. header() and row() return one line each, columns are separated by a space.
+- The rows are not kept, a program prints every row when it has it. A benchmark that takes minutes shows its results as they come in.
+- A column is as wide as its width or its header, whichever is longer. A cell that is longer still pushes the rest of its row to the right and does not
+  get cut.
+- Cells are anything that implements Display. Numbers with a precision or durations with {:?} are formatted by the caller first.
+*/
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Align {
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone)]
+struct Column {
+    header: String,
+    width: usize,
+    align: Align,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Table {
+    columns: Vec<Column>,
+}
+
+impl Table {
+    pub fn new() -> Table {
+        Table::default()
+    }
+
+    pub fn column(mut self, header: &str, width: usize, align: Align) -> Table {
+        self.columns.push(Column {
+            header: header.to_string(),
+            width: width.max(header.chars().count()),
+            align,
+        });
+        self
+    }
+
+    pub fn header(&self) -> String {
+        let headers: Vec<&dyn Display> = self
+            .columns
+            .iter()
+            .map(|column| &column.header as &dyn Display)
+            .collect();
+        self.row(&headers)
+    }
+
+    // Cells past the last column are left out, missing cells are left blank.
+    pub fn row(&self, cells: &[&dyn Display]) -> String {
+        let cells: Vec<String> = self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(index, column)| {
+                let cell = cells
+                    .get(index)
+                    .map(|cell| cell.to_string())
+                    .unwrap_or_default();
+                match column.align {
+                    Align::Left => format!("{:<width$}", cell, width = column.width),
+                    Align::Right => format!("{:>width$}", cell, width = column.width),
+                }
+            })
+            .collect();
+        cells.join(" ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aligns_cells_under_their_headers() {
+        let table = Table::new()
+            .column("name", 6, Align::Left)
+            .column("time", 8, Align::Right)
+            .column("allocations", 3, Align::Right);
+        assert_eq!(table.header(), "name       time allocations");
+        assert_eq!(
+            table.row(&[&"box", &format!("{:.1}ms", 136.04), &1_000_001]),
+            "box     136.0ms     1000001"
+        );
+        assert_eq!(table.row(&[&"vec"]), format!("{:<27}", "vec"));
+    }
+}
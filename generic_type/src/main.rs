@@ -10,7 +10,7 @@
 - Rust performs monomorphization of the code using generics at compile time. Monomorphization is the process of turning generic code into specific code by filling in
   the concrete types that are used when compiled.
 */
-fn largest<T>(list: &[T]) -> &T {
+fn largest<T: PartialOrd>(list: &[T]) -> &T {
     let mut largest = &list[0];
 
     for item in list {
@@ -43,8 +43,8 @@ struct PointCart<X1, Y1> {
 }
 
 impl<X1, Y1> PointCart<X1, Y1> {
-    fn mixup<X2, Y2>(self, other: Point<X2, Y2>) -> Point<X1, Y2> {
-        Point {
+    fn mixup<X2, Y2>(self, other: PointCart<X2, Y2>) -> PointCart<X1, Y2> {
+        PointCart {
             x: self.x,
             y: other.y,
         }
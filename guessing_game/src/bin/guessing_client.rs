@@ -0,0 +1,125 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 05:58:34
+/// @Description: Guessing Game Client
+/*
+- guessing_client [ADDRESS] [--name NAME] joins a server started with guessing_game --serve ADDRESS (default: 127.0.0.1:7878).
+- Every line typed is sent as a guess, quit leaves the game. The messages of the server are printed as they arrive.
+*/
+use fundamentals_core::{exit_with_usage, Args};
+use guessing_game::multiplayer::{Client, Hint, Message, Request};
+use std::env;
+use std::io::{self, BufRead};
+use std::process;
+use std::thread;
+
+const USAGE: &str = "Usage: guessing_client [ADDRESS] [--name NAME]";
+
+fn describe(message: &Message, me: &str) -> String {
+    match message {
+        Message::Welcome(name) => format!("Welcome, {}!", name),
+        Message::Round {
+            round,
+            low,
+            high,
+            attempts,
+        } => format!(
+            "Round {}: guess the number between {} and {}, you have {} attempts.",
+            round, low, high, attempts
+        ),
+        Message::Joined(name) => format!("{} joined.", name),
+        Message::Left(name) => format!("{} left.", name),
+        Message::Hint {
+            player,
+            guess,
+            hint,
+        } => {
+            let who = if player == me { "You" } else { player };
+            let hint = match hint {
+                Hint::TooSmall => "too small",
+                Hint::TooBig => "too big",
+            };
+            format!("{} guessed {}: {}.", who, guess, hint)
+        }
+        Message::Out(name) if name == me => "You are out of attempts for this round.".to_string(),
+        Message::Out(name) => format!("{} is out of attempts.", name),
+        Message::Winner {
+            player,
+            attempts,
+            secret,
+        } => format!(
+            "{} won in {} attempts! The number was {}.",
+            player, attempts, secret
+        ),
+        Message::NoWinner(secret) => format!("Nobody found it, the number was {}.", secret),
+        Message::Error(text) => format!("error: {}", text),
+        Message::Bye => "Bye!".to_string(),
+    }
+}
+
+fn main() {
+    let mut address = None;
+    let mut name = env::var("USER").ok();
+    let mut args = Args::from_env();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--name" => {
+                name = Some(
+                    args.value("--name")
+                        .unwrap_or_else(|err| exit_with_usage(&err, USAGE)),
+                )
+            }
+            _ if address.is_none() && !arg.starts_with("--") => address = Some(arg),
+            _ => exit_with_usage(&format!("unexpected argument '{}'", arg), USAGE),
+        }
+    }
+    let address = address.unwrap_or_else(|| "127.0.0.1:7878".to_string());
+    let name = name.unwrap_or_else(|| "player".to_string());
+
+    let mut client = Client::connect(address.as_str(), &name).unwrap_or_else(|err| {
+        eprintln!("error: cannot join {}: {}", address, err);
+        process::exit(1);
+    });
+    let mut sender = client.sender().unwrap_or_else(|err| {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    });
+
+    println!("{}", describe(&Message::Welcome(name.clone()), &name));
+    let printer = thread::spawn(move || loop {
+        match client.receive() {
+            Ok(Some(message)) => {
+                println!("{}", describe(&message, client.name()));
+                if message == Message::Bye {
+                    break;
+                }
+            }
+            Ok(None) => {
+                println!("The server closed the connection.");
+                break;
+            }
+            Err(err) => {
+                eprintln!("error: {}", err);
+                break;
+            }
+        }
+    });
+
+    for line in io::stdin().lock().lines() {
+        let line = match line {
+            Ok(line) => line,
+            Err(_) => break,
+        };
+        let request = match line.trim() {
+            "" => continue,
+            "quit" => Request::Quit,
+            guess => Request::Guess(guess.to_string()),
+        };
+        let quit = request == Request::Quit;
+        if sender.send(&request).is_err() || quit {
+            break;
+        }
+    }
+    // Leaving at the end of the input still says goodbye properly.
+    let _ = sender.send(&Request::Quit);
+    let _ = printer.join();
+}
@@ -0,0 +1,106 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 05:49:34
+/// @Description: Input and Output of a Front End
+use crate::game::State;
+use std::collections::VecDeque;
+use std::io::{self, BufRead, Write};
+use std::time::{Duration, Instant};
+
+// The state of a game for a full-screen console, the same for every variant. board is what the variant shows of what the player found out so far.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub title: String,
+    pub attempts_left: u32,
+    pub max_attempts: u32,
+    pub state: State,
+    pub board: Vec<String>,
+}
+
+pub trait Console {
+    // None at the end of the input.
+    fn read_line(&mut self) -> io::Result<Option<String>>;
+    fn write_line(&mut self, line: &str) -> io::Result<()>;
+    // The line asking for the next guess. Line consoles print it like any other line, a full-screen console has a prompt of its own.
+    fn prompt(&mut self, line: &str) -> io::Result<()> {
+        self.write_line(line)
+    }
+
+    // How long the game has been running. Replays override it with the recorded times so they print the same summary as the original game.
+    fn elapsed(&self, started: Instant) -> Duration {
+        started.elapsed()
+    }
+
+    // Called with the current state of the game before every guess and once more when it is over. Line consoles only print the messages, a full-screen
+    // console draws the game from it.
+    fn show(&mut self, _frame: &Frame) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+pub struct Terminal;
+
+impl Console for Terminal {
+    fn read_line(&mut self) -> io::Result<Option<String>> {
+        let mut line = String::new();
+        match io::stdin().lock().read_line(&mut line)? {
+            0 => Ok(None),
+            _ => Ok(Some(line)),
+        }
+    }
+
+    fn write_line(&mut self, line: &str) -> io::Result<()> {
+        let mut stdout = io::stdout().lock();
+        writeln!(stdout, "{}", line)?;
+        stdout.flush()
+    }
+}
+
+// Feeds prepared input lines and records the output, for tests and replays.
+#[derive(Debug, Default)]
+pub struct Scripted {
+    input: VecDeque<(Option<Duration>, String)>,
+    last_time: Option<Duration>,
+    pub output: Vec<String>,
+}
+
+impl Scripted {
+    pub fn new<I, S>(input: I) -> Scripted
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Scripted {
+            input: input.into_iter().map(|line| (None, line.into())).collect(),
+            ..Scripted::default()
+        }
+    }
+
+    // Every line comes with the time since the start of the game at which it was typed.
+    pub fn timed<I: IntoIterator<Item = (Duration, String)>>(input: I) -> Scripted {
+        Scripted {
+            input: input
+                .into_iter()
+                .map(|(time, line)| (Some(time), line))
+                .collect(),
+            ..Scripted::default()
+        }
+    }
+}
+
+impl Console for Scripted {
+    fn read_line(&mut self) -> io::Result<Option<String>> {
+        Ok(self.input.pop_front().map(|(time, line)| {
+            self.last_time = time.or(self.last_time);
+            line
+        }))
+    }
+
+    fn write_line(&mut self, line: &str) -> io::Result<()> {
+        self.output.push(line.to_string());
+        Ok(())
+    }
+
+    fn elapsed(&self, started: Instant) -> Duration {
+        self.last_time.unwrap_or_else(|| started.elapsed())
+    }
+}
@@ -0,0 +1,162 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 05:47:53
+/// @Description: Difficulty Levels
+/*
+- A difficulty decides the range the secret number is picked from and how many guesses the player gets before losing.
+- The presets leave a few spare guesses over what halving the range each time would need: log2(50) ~ 5.6, log2(100) ~ 6.6, log2(1000) ~ 9.97. Hard leaves none.
+- Custom takes the range and the number of attempts from the command line: --difficulty custom --range 1-500 --attempts 12.
+*/
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+    Custom {
+        low: u32,
+        high: u32,
+        max_attempts: u32,
+    },
+}
+
+impl Difficulty {
+    pub fn range(&self) -> RangeInclusive<u32> {
+        match self {
+            Difficulty::Easy => 1..=50,
+            Difficulty::Normal => 1..=100,
+            Difficulty::Hard => 1..=1000,
+            Difficulty::Custom { low, high, .. } => *low..=*high,
+        }
+    }
+
+    pub fn max_attempts(&self) -> u32 {
+        match self {
+            Difficulty::Easy => 10,
+            Difficulty::Normal => 8,
+            Difficulty::Hard => 10,
+            Difficulty::Custom { max_attempts, .. } => *max_attempts,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Difficulty::Easy => "easy",
+            Difficulty::Normal => "normal",
+            Difficulty::Hard => "hard",
+            Difficulty::Custom { .. } => "custom",
+        }
+    }
+
+    // Identifies the difficulty on the leaderboard. Custom games only compete with games of the same range and attempts.
+    pub fn key(&self) -> String {
+        match self {
+            Difficulty::Custom {
+                low,
+                high,
+                max_attempts,
+            } => format!("custom {}-{}/{}", low, high, max_attempts),
+            preset => preset.name().to_string(),
+        }
+    }
+
+    pub fn from_key(key: &str) -> Result<Difficulty, String> {
+        match key.strip_prefix("custom ") {
+            Some(custom) => {
+                let (range, attempts) = custom
+                    .split_once('/')
+                    .ok_or_else(|| format!("'{}' is not a custom difficulty", key))?;
+                let attempts = attempts
+                    .parse()
+                    .map_err(|_| format!("'{}' is not a number of attempts", attempts))?;
+                Difficulty::custom(range, attempts)
+            }
+            None => key.parse(),
+        }
+    }
+
+    pub fn custom(range: &str, max_attempts: u32) -> Result<Difficulty, String> {
+        let (low, high) = range
+            .split_once('-')
+            .ok_or_else(|| format!("range '{}' should look like 1-500", range))?;
+        let low: u32 = low
+            .trim()
+            .parse()
+            .map_err(|_| format!("'{}' is not a number", low))?;
+        let high: u32 = high
+            .trim()
+            .parse()
+            .map_err(|_| format!("'{}' is not a number", high))?;
+        if low >= high {
+            return Err(format!(
+                "range {}-{} is empty or has a single number",
+                low, high
+            ));
+        }
+        if max_attempts == 0 {
+            return Err("at least one attempt is needed".to_string());
+        }
+        Ok(Difficulty::Custom {
+            low,
+            high,
+            max_attempts,
+        })
+    }
+}
+
+impl fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let range = self.range();
+        write!(
+            f,
+            "{} ({} to {}, {} attempts)",
+            self.name(),
+            range.start(),
+            range.end(),
+            self.max_attempts()
+        )
+    }
+}
+
+// Only the presets can be parsed from a name, custom needs its range and attempts as well.
+impl FromStr for Difficulty {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Difficulty, String> {
+        match s.to_lowercase().as_str() {
+            "easy" => Ok(Difficulty::Easy),
+            "normal" => Ok(Difficulty::Normal),
+            "hard" => Ok(Difficulty::Hard),
+            other => Err(format!(
+                "unknown difficulty '{}', pick easy, normal, hard or custom",
+                other
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn presets_and_custom() {
+        assert_eq!("Hard".parse(), Ok(Difficulty::Hard));
+        assert!("extreme".parse::<Difficulty>().is_err());
+        assert_eq!(Difficulty::default().range(), 1..=100);
+
+        let custom = Difficulty::custom("5-500", 12).unwrap();
+        assert_eq!(custom.range(), 5..=500);
+        assert_eq!(custom.max_attempts(), 12);
+        assert!(Difficulty::custom("500-5", 12).is_err());
+        assert!(Difficulty::custom("1-10", 0).is_err());
+        assert!(Difficulty::custom("ten", 3).is_err());
+
+        assert_eq!(Difficulty::from_key(&custom.key()), Ok(custom));
+        assert_eq!(Difficulty::from_key("easy"), Ok(Difficulty::Easy));
+        assert!(Difficulty::from_key("custom 1-5").is_err());
+    }
+}
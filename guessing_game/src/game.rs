@@ -0,0 +1,438 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 05:49:34
+/// @Description: Game State Machine
+/*
+- A Game starts in State::Playing and ends in State::Won or State::Lost. Every guess is answered with an Outcome:
+    -> TooSmall / TooBig: a wrong guess, the game goes on.
+    -> Won: the guess was the secret number.
+    -> OutOfAttempts: the guess was wrong and it was the last attempt.
+    -> InvalidInput: the input was not a number or not in the range. It does not use up an attempt, play() tells the player what was wrong with it.
+- Once the game is over further guesses are not counted, they get the final outcome again.
+- The secret number is passed in, so tests can choose it. Game::random picks one with any random number generator and Game::seeded derives it from a seed,
+  which gives the same secret number for the same seed and difficulty every time.
+- Game is also the number variant of the Variant trait. play() runs any variant over a Console, the classic game and the ones of the variant module go
+  through the same loop: attempts, invalid input, winning and losing, the summary and the frames of a full-screen console.
+*/
+use crate::console::{Console, Frame};
+use crate::difficulty::Difficulty;
+use crate::tui;
+use crate::variant::{Judgement, Variant};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::cmp::Ordering;
+use std::fmt;
+use std::io;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum State {
+    Playing,
+    Won,
+    Lost,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InvalidInput {
+    NotANumber(String),
+    OutOfRange(u32),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    TooSmall,
+    TooBig,
+    Won,
+    OutOfAttempts,
+    InvalidInput(InvalidInput),
+}
+
+#[derive(Debug, Clone)]
+pub struct Game {
+    difficulty: Difficulty,
+    secret: u32,
+    guesses: Vec<u32>,
+    state: State,
+}
+
+impl Game {
+    pub fn new(difficulty: Difficulty, secret: u32) -> Game {
+        assert!(
+            difficulty.range().contains(&secret),
+            "secret number {} is outside the range of {}",
+            secret,
+            difficulty
+        );
+        Game {
+            difficulty,
+            secret,
+            guesses: Vec::new(),
+            state: State::Playing,
+        }
+    }
+
+    pub fn random<R: Rng>(difficulty: Difficulty, rng: &mut R) -> Game {
+        Game::new(difficulty, rng.gen_range(difficulty.range()))
+    }
+
+    pub fn seeded(difficulty: Difficulty, seed: u64) -> Game {
+        Game::random(difficulty, &mut StdRng::seed_from_u64(seed))
+    }
+
+    pub fn difficulty(&self) -> Difficulty {
+        self.difficulty
+    }
+
+    pub fn secret(&self) -> u32 {
+        self.secret
+    }
+
+    pub fn state(&self) -> State {
+        self.state
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.state != State::Playing
+    }
+
+    pub fn guesses(&self) -> &[u32] {
+        &self.guesses
+    }
+
+    pub fn attempts(&self) -> u32 {
+        self.guesses.len() as u32
+    }
+
+    pub fn attempts_left(&self) -> u32 {
+        self.difficulty.max_attempts() - self.attempts()
+    }
+
+    pub fn guess(&mut self, input: &str) -> Outcome {
+        match input.trim().parse() {
+            Ok(number) => self.guess_number(number),
+            Err(_) if self.is_over() => self.final_outcome(),
+            Err(_) => Outcome::InvalidInput(InvalidInput::NotANumber(input.trim().to_string())),
+        }
+    }
+
+    pub fn guess_number(&mut self, number: u32) -> Outcome {
+        if self.is_over() {
+            return self.final_outcome();
+        }
+        if !self.difficulty.range().contains(&number) {
+            return Outcome::InvalidInput(InvalidInput::OutOfRange(number));
+        }
+        self.guesses.push(number);
+
+        match number.cmp(&self.secret) {
+            Ordering::Equal => {
+                self.state = State::Won;
+                Outcome::Won
+            }
+            _ if self.attempts_left() == 0 => {
+                self.state = State::Lost;
+                Outcome::OutOfAttempts
+            }
+            Ordering::Less => Outcome::TooSmall,
+            Ordering::Greater => Outcome::TooBig,
+        }
+    }
+
+    // What play() tells the player about input that was not counted.
+    pub fn explain(&self, invalid: &InvalidInput) -> String {
+        let range = self.difficulty.range();
+        match invalid {
+            InvalidInput::NotANumber(text) => format!("'{}' is not a number.", text),
+            InvalidInput::OutOfRange(_) => format!(
+                "The number is between {} and {}.",
+                range.start(),
+                range.end()
+            ),
+        }
+    }
+
+    fn final_outcome(&self) -> Outcome {
+        match self.state {
+            State::Won => Outcome::Won,
+            _ => Outcome::OutOfAttempts,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Summary {
+    pub state: State,
+    pub attempts: u32,
+    // A number in the classic game, a code or a word in the other variants. secret_kind says which one.
+    pub secret: String,
+    pub secret_kind: &'static str,
+    pub elapsed: Duration,
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Attempts used: {} in {:.1}s. The secret {} was {}.",
+            self.attempts,
+            self.elapsed.as_secs_f64(),
+            self.secret_kind,
+            self.secret
+        )
+    }
+}
+
+impl Variant for Game {
+    fn name(&self) -> &'static str {
+        "number"
+    }
+
+    fn key(&self) -> String {
+        self.difficulty.key()
+    }
+
+    fn introduction(&self) -> String {
+        format!("Guess the number! Difficulty: {}", self.difficulty)
+    }
+
+    fn max_attempts(&self) -> u32 {
+        self.difficulty.max_attempts()
+    }
+
+    fn secret(&self) -> String {
+        self.secret.to_string()
+    }
+
+    fn judge(&mut self, guess: &str) -> Judgement {
+        match self.guess(guess) {
+            Outcome::Won => Judgement::Solved,
+            Outcome::TooSmall => Judgement::Hint("Too small!".to_string()),
+            Outcome::TooBig => Judgement::Hint("Too big!".to_string()),
+            // The last guess, the loop of play() says that the game is lost.
+            Outcome::OutOfAttempts => {
+                match self.guesses.last().map(|last| last.cmp(&self.secret)) {
+                    Some(Ordering::Less) => Judgement::Hint("Too small!".to_string()),
+                    _ => Judgement::Hint("Too big!".to_string()),
+                }
+            }
+            Outcome::InvalidInput(invalid) => Judgement::Invalid(self.explain(&invalid)),
+        }
+    }
+
+    fn echo(&self) -> Option<String> {
+        self.guesses
+            .last()
+            .map(|number| format!("You guessed: {}", number))
+    }
+
+    fn board(&self) -> Vec<String> {
+        tui::number_board(self)
+    }
+}
+
+fn frame<V: Variant + ?Sized>(variant: &V, attempts: u32, state: State) -> Frame {
+    Frame {
+        title: variant.introduction(),
+        attempts_left: variant.max_attempts() - attempts,
+        max_attempts: variant.max_attempts(),
+        state,
+        board: variant.board(),
+    }
+}
+
+// Plays the variant to the end, or until the input runs out, which counts as a loss.
+pub fn play<V, C>(variant: &mut V, console: &mut C) -> io::Result<Summary>
+where
+    V: Variant + ?Sized,
+    C: Console + ?Sized,
+{
+    let started = Instant::now();
+    console.write_line(&variant.introduction())?;
+    let mut attempts = 0;
+    let mut state = State::Playing;
+
+    while state == State::Playing {
+        console.show(&frame(variant, attempts, state))?;
+        console.prompt(&format!(
+            "Please input your guess ({} attempts left).",
+            variant.max_attempts() - attempts
+        ))?;
+        let line = match console.read_line()? {
+            Some(line) => line,
+            None => {
+                state = State::Lost;
+                break;
+            }
+        };
+
+        // None when the guess solved it.
+        let hint = match variant.judge(line.trim()) {
+            Judgement::Invalid(message) => {
+                console.write_line(&message)?;
+                continue;
+            }
+            Judgement::Solved => None,
+            Judgement::Hint(hint) => Some(hint),
+        };
+        attempts += 1;
+        if let Some(echo) = variant.echo() {
+            console.write_line(&echo)?;
+        }
+        match hint {
+            None => {
+                state = State::Won;
+                console.write_line("You win!")?;
+            }
+            Some(hint) => {
+                console.write_line(&hint)?;
+                if attempts == variant.max_attempts() {
+                    state = State::Lost;
+                    console.write_line("You lose!")?;
+                }
+            }
+        }
+    }
+
+    let summary = Summary {
+        state,
+        attempts,
+        secret: variant.secret(),
+        secret_kind: variant.secret_kind(),
+        elapsed: console.elapsed(started),
+    };
+    console.write_line(&summary.to_string())?;
+    console.show(&frame(variant, attempts, state))?;
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::console::Scripted;
+
+    fn custom(low: u32, high: u32, max_attempts: u32) -> Difficulty {
+        Difficulty::Custom {
+            low,
+            high,
+            max_attempts,
+        }
+    }
+
+    #[test]
+    fn outcomes_of_a_won_game() {
+        let mut game = Game::new(Difficulty::Normal, 42);
+        assert_eq!(game.guess("50"), Outcome::TooBig);
+        assert_eq!(game.guess(" 20\n"), Outcome::TooSmall);
+        assert_eq!(
+            game.guess("forty"),
+            Outcome::InvalidInput(InvalidInput::NotANumber("forty".to_string()))
+        );
+        assert_eq!(
+            game.guess("101"),
+            Outcome::InvalidInput(InvalidInput::OutOfRange(101))
+        );
+        assert_eq!(game.attempts(), 2);
+        assert_eq!(game.guess("42"), Outcome::Won);
+        assert_eq!(game.state(), State::Won);
+        assert_eq!(game.guess("13"), Outcome::Won);
+        assert_eq!(game.guesses(), &[50, 20, 42]);
+    }
+
+    #[test]
+    fn last_wrong_guess_loses() {
+        let mut game = Game::new(custom(1, 10, 2), 7);
+        assert_eq!(game.guess_number(1), Outcome::TooSmall);
+        assert_eq!(game.guess_number(2), Outcome::OutOfAttempts);
+        assert_eq!(game.state(), State::Lost);
+        assert_eq!(game.guess_number(7), Outcome::OutOfAttempts);
+        assert_eq!(game.attempts(), 2);
+    }
+
+    #[test]
+    fn scripted_game_output() {
+        let mut game = Game::new(custom(1, 10, 3), 4);
+        let mut console = Scripted::new(["5", "abc", "11", "2", "4", "9"]);
+        let summary = play(&mut game, &mut console).unwrap();
+
+        assert_eq!(summary.state, State::Won);
+        assert_eq!(summary.attempts, 3);
+        assert_eq!(
+            console.output[..console.output.len() - 1],
+            [
+                "Guess the number! Difficulty: custom (1 to 10, 3 attempts)",
+                "Please input your guess (3 attempts left).",
+                "You guessed: 5",
+                "Too big!",
+                "Please input your guess (2 attempts left).",
+                "'abc' is not a number.",
+                "Please input your guess (2 attempts left).",
+                "The number is between 1 and 10.",
+                "Please input your guess (2 attempts left).",
+                "You guessed: 2",
+                "Too small!",
+                "Please input your guess (1 attempts left).",
+                "You guessed: 4",
+                "You win!",
+            ]
+        );
+        assert!(console
+            .output
+            .last()
+            .unwrap()
+            .ends_with("The secret number was 4."));
+    }
+
+    // Like a full-screen console, it leaves the prompts out.
+    struct NoPrompts(Scripted);
+
+    impl Console for NoPrompts {
+        fn read_line(&mut self) -> io::Result<Option<String>> {
+            self.0.read_line()
+        }
+
+        fn write_line(&mut self, line: &str) -> io::Result<()> {
+            self.0.write_line(line)
+        }
+
+        fn prompt(&mut self, _line: &str) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn prompts_go_through_their_own_method() {
+        let mut game = Game::new(custom(1, 10, 3), 4);
+        let mut console = NoPrompts(Scripted::new(["5", "4"]));
+        play(&mut game, &mut console).unwrap();
+        assert_eq!(
+            console.0.output[..4],
+            [
+                "Guess the number! Difficulty: custom (1 to 10, 3 attempts)",
+                "You guessed: 5",
+                "Too big!",
+                "You guessed: 4",
+            ]
+        );
+    }
+
+    #[test]
+    fn same_seed_same_secret() {
+        for seed in 0..20 {
+            assert_eq!(
+                Game::seeded(Difficulty::Hard, seed).secret(),
+                Game::seeded(Difficulty::Hard, seed).secret()
+            );
+        }
+        let secrets: Vec<u32> = (0..20)
+            .map(|seed| Game::seeded(Difficulty::Hard, seed).secret())
+            .collect();
+        assert!(secrets.windows(2).any(|pair| pair[0] != pair[1]));
+    }
+
+    #[test]
+    fn input_running_out_is_a_loss() {
+        let mut game = Game::new(Difficulty::Easy, 30);
+        let summary = play(&mut game, &mut Scripted::new(["10"])).unwrap();
+        assert_eq!(summary.state, State::Lost);
+        assert_eq!(summary.attempts, 1);
+    }
+}
@@ -0,0 +1,332 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 05:48:45
+/// @Description: High Score Leaderboard
+/*
+- The leaderboard keeps the best game of every player on every difficulty. Fewer attempts is better, the faster game wins a tie.
+- Scores are stored in a small binary file:
+      magic "GGLB", format version (u8), number of scores (u32)
+      per score: player (u16 length + UTF-8 bytes), difficulty (u8 length + UTF-8 bytes), attempts (u32), elapsed milliseconds (u64), unix time (u64)
+  All integers are little endian. A file with the wrong magic or version, or one that ends early, is rejected instead of being half read.
+- Saving never overwrites the scores file in place. The new contents go to a temporary file in the same directory which is flushed to disk and then renamed
+  over the old file. A rename within a directory is atomic, so after a crash the file holds either the old or the new scores, never a mix.
+  -> The temporary file is named after the process and a counter, two games saving at once never write to the same one.
+  -> The file is read again right before writing and the scores saved since it was loaded are merged in, so a game finishing in between keeps its score.
+     Only two saves within the time it takes to write the file can still lose one of the two scores.
+*/
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const MAGIC: &[u8; 4] = b"GGLB";
+const VERSION: u8 = 1;
+
+static SAVES: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Score {
+    pub player: String,
+    pub difficulty: String,
+    pub attempts: u32,
+    pub elapsed: Duration,
+    pub recorded_at: u64,
+}
+
+impl Score {
+    pub fn new(player: &str, difficulty: &str, attempts: u32, elapsed: Duration) -> Score {
+        let recorded_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|since| since.as_secs())
+            .unwrap_or(0);
+        Score {
+            player: player.to_string(),
+            difficulty: difficulty.to_string(),
+            attempts,
+            elapsed,
+            recorded_at,
+        }
+    }
+
+    fn beats(&self, other: &Score) -> bool {
+        (self.attempts, self.elapsed) < (other.attempts, other.elapsed)
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Leaderboard {
+    best: HashMap<(String, String), Score>,
+}
+
+impl Leaderboard {
+    // A missing file is an empty leaderboard, any other problem with the file is an error.
+    pub fn load(path: &Path) -> io::Result<Leaderboard> {
+        match File::open(path) {
+            Ok(mut file) => {
+                let mut bytes = Vec::new();
+                file.read_to_end(&mut bytes)?;
+                Leaderboard::decode(&bytes)
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Leaderboard::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut merged = Leaderboard::load(path)?;
+        for score in self.best.values() {
+            merged.record(score.clone());
+        }
+
+        let mut temporary = PathBuf::from(path);
+        let mut name = path.file_name().unwrap_or_default().to_os_string();
+        name.push(format!(
+            ".{}.{}.tmp",
+            process::id(),
+            SAVES.fetch_add(1, Ordering::Relaxed)
+        ));
+        temporary.set_file_name(name);
+
+        let written = File::create(&temporary).and_then(|mut file| {
+            file.write_all(&merged.encode())?;
+            file.sync_all()
+        });
+        if let Err(err) = written.and_then(|_| fs::rename(&temporary, path)) {
+            let _ = fs::remove_file(&temporary);
+            return Err(err);
+        }
+
+        // Flushing the directory makes the rename itself durable. Not every platform can open a directory, so this is best effort.
+        if let Some(directory) = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            if let Ok(directory) = File::open(directory) {
+                let _ = directory.sync_all();
+            }
+        }
+        Ok(())
+    }
+
+    // Returns true when the score is a new best for its player and difficulty.
+    pub fn record(&mut self, score: Score) -> bool {
+        let key = (score.player.clone(), score.difficulty.clone());
+        match self.best.get(&key) {
+            Some(best) if !score.beats(best) => false,
+            _ => {
+                self.best.insert(key, score);
+                true
+            }
+        }
+    }
+
+    pub fn difficulties(&self) -> Vec<&str> {
+        let mut difficulties: Vec<&str> = self
+            .best
+            .keys()
+            .map(|(_, difficulty)| difficulty.as_str())
+            .collect();
+        difficulties.sort_unstable();
+        difficulties.dedup();
+        difficulties
+    }
+
+    pub fn top(&self, difficulty: &str, count: usize) -> Vec<&Score> {
+        let mut scores: Vec<&Score> = self
+            .best
+            .values()
+            .filter(|score| score.difficulty == difficulty)
+            .collect();
+        scores.sort_by(|a, b| {
+            (a.attempts, a.elapsed, &a.player).cmp(&(b.attempts, b.elapsed, &b.player))
+        });
+        scores.truncate(count);
+        scores
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut scores: Vec<&Score> = self.best.values().collect();
+        scores.sort_by(|a, b| (&a.difficulty, &a.player).cmp(&(&b.difficulty, &b.player)));
+
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(MAGIC);
+        bytes.push(VERSION);
+        bytes.extend_from_slice(&(scores.len() as u32).to_le_bytes());
+        for score in scores {
+            let player = truncate_utf8(&score.player, u16::MAX as usize);
+            let difficulty = truncate_utf8(&score.difficulty, u8::MAX as usize);
+            bytes.extend_from_slice(&(player.len() as u16).to_le_bytes());
+            bytes.extend_from_slice(player.as_bytes());
+            bytes.push(difficulty.len() as u8);
+            bytes.extend_from_slice(difficulty.as_bytes());
+            bytes.extend_from_slice(&score.attempts.to_le_bytes());
+            bytes.extend_from_slice(&(score.elapsed.as_millis() as u64).to_le_bytes());
+            bytes.extend_from_slice(&score.recorded_at.to_le_bytes());
+        }
+        bytes
+    }
+
+    fn decode(bytes: &[u8]) -> io::Result<Leaderboard> {
+        let mut reader = ByteReader { bytes };
+        if reader.take(4)? != MAGIC {
+            return Err(invalid("not a leaderboard file"));
+        }
+        let version = reader.take(1)?[0];
+        if version != VERSION {
+            return Err(invalid(&format!(
+                "unsupported leaderboard version {}",
+                version
+            )));
+        }
+        let count = u32::from_le_bytes(reader.array()?);
+        let mut leaderboard = Leaderboard::default();
+        for _ in 0..count {
+            let player_len = u16::from_le_bytes(reader.array()?) as usize;
+            let player = reader.string(player_len)?;
+            let difficulty_len = reader.take(1)?[0] as usize;
+            let difficulty = reader.string(difficulty_len)?;
+            let score = Score {
+                player,
+                difficulty,
+                attempts: u32::from_le_bytes(reader.array()?),
+                elapsed: Duration::from_millis(u64::from_le_bytes(reader.array()?)),
+                recorded_at: u64::from_le_bytes(reader.array()?),
+            };
+            leaderboard.record(score);
+        }
+        if !reader.bytes.is_empty() {
+            return Err(invalid("unexpected bytes after the last score"));
+        }
+        Ok(leaderboard)
+    }
+}
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+fn truncate_utf8(text: &str, max_len: usize) -> &str {
+    let mut end = text.len().min(max_len);
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    &text[..end]
+}
+
+struct ByteReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> ByteReader<'a> {
+    fn take(&mut self, len: usize) -> io::Result<&'a [u8]> {
+        if self.bytes.len() < len {
+            return Err(invalid("leaderboard file ends early"));
+        }
+        let (taken, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(taken)
+    }
+
+    fn array<const N: usize>(&mut self) -> io::Result<[u8; N]> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.take(N)?);
+        Ok(array)
+    }
+
+    fn string(&mut self, len: usize) -> io::Result<String> {
+        String::from_utf8(self.take(len)?.to_vec()).map_err(|_| invalid("name is not valid UTF-8"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn score(player: &str, difficulty: &str, attempts: u32, millis: u64) -> Score {
+        Score {
+            player: player.to_string(),
+            difficulty: difficulty.to_string(),
+            attempts,
+            elapsed: Duration::from_millis(millis),
+            recorded_at: 1_700_000_000,
+        }
+    }
+
+    #[test]
+    fn keeps_the_best_game_per_player_and_difficulty() {
+        let mut leaderboard = Leaderboard::default();
+        assert!(leaderboard.record(score("ana", "normal", 6, 9000)));
+        assert!(!leaderboard.record(score("ana", "normal", 7, 1000)));
+        assert!(leaderboard.record(score("ana", "normal", 6, 8000)));
+        assert!(leaderboard.record(score("ana", "hard", 9, 20000)));
+        assert!(leaderboard.record(score("bo", "normal", 4, 30000)));
+
+        let top: Vec<(&str, u32)> = leaderboard
+            .top("normal", 10)
+            .iter()
+            .map(|score| (score.player.as_str(), score.attempts))
+            .collect();
+        assert_eq!(top, vec![("bo", 4), ("ana", 6)]);
+        assert_eq!(leaderboard.difficulties(), vec!["hard", "normal"]);
+    }
+
+    #[test]
+    fn encode_decode_round_trip() {
+        let mut leaderboard = Leaderboard::default();
+        leaderboard.record(score("ana", "normal", 6, 9000));
+        leaderboard.record(score("Zoë", "custom 1-500/12", 11, 123_456));
+        let bytes = leaderboard.encode();
+        assert_eq!(Leaderboard::decode(&bytes).unwrap(), leaderboard);
+
+        assert!(Leaderboard::decode(&bytes[..bytes.len() - 1]).is_err());
+        assert!(Leaderboard::decode(b"JSON{}").is_err());
+    }
+
+    #[test]
+    fn save_replaces_the_file_atomically() {
+        let directory =
+            std::env::temp_dir().join(format!("guessing_game_leaderboard_{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        let path = directory.join("scores.bin");
+
+        assert_eq!(Leaderboard::load(&path).unwrap(), Leaderboard::default());
+        let mut leaderboard = Leaderboard::default();
+        leaderboard.record(score("ana", "easy", 3, 2500));
+        leaderboard.save(&path).unwrap();
+        leaderboard.record(score("bo", "easy", 5, 2500));
+        leaderboard.save(&path).unwrap();
+
+        assert_eq!(Leaderboard::load(&path).unwrap(), leaderboard);
+        assert_eq!(fs::read_dir(&directory).unwrap().count(), 1);
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn save_keeps_scores_saved_since_the_load() {
+        let directory = std::env::temp_dir().join(format!(
+            "guessing_game_leaderboard_merge_{}",
+            std::process::id()
+        ));
+        fs::create_dir_all(&directory).unwrap();
+        let path = directory.join("scores.bin");
+
+        let mut first = Leaderboard::load(&path).unwrap();
+        let mut second = Leaderboard::load(&path).unwrap();
+        first.record(score("ana", "easy", 3, 2500));
+        second.record(score("bo", "easy", 5, 2500));
+        second.record(score("ana", "easy", 4, 2500));
+        first.save(&path).unwrap();
+        second.save(&path).unwrap();
+
+        let saved = Leaderboard::load(&path).unwrap();
+        let top: Vec<(&str, u32)> = saved
+            .top("easy", 10)
+            .iter()
+            .map(|score| (score.player.as_str(), score.attempts))
+            .collect();
+        assert_eq!(top, vec![("ana", 3), ("bo", 5)]);
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
@@ -0,0 +1,34 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 05:49:34
+/// @Description: Guessing Game Library
+/*
+- The game itself lives in the library so it can be played by other front ends and scripted in tests. main.rs only reads the command line and wires the
+  pieces together.
+- Game is a state machine. It takes one guess at a time and answers with an Outcome, it never reads or prints anything itself.
+- Console is the input and output of a front end. play() runs a whole game over any Console: Terminal talks to stdin and stdout, Scripted replays fixed
+  lines and collects what would have been printed.
+- Games are seeded, and the replay module records a game so it can be played back with exactly the same output.
+- The multiplayer module runs games over TCP, players race to guess the same number. guessing_client in src/bin is the client for it.
+- The variant module has other games behind the Variant trait: hot and cold, bulls and cows and a word game. Game is the number variant of the same trait,
+  so all of them are played by play() and share the replays, the full-screen console and the leaderboard.
+- The tui module is an optional full-screen Console, the plain line console stays the default.
+- The solver module lets the computer play with pluggable strategies, against itself or against a player who thinks of the number.
+*/
+pub mod console;
+pub mod difficulty;
+pub mod game;
+pub mod leaderboard;
+pub mod multiplayer;
+pub mod replay;
+pub mod solver;
+pub mod tui;
+pub mod variant;
+
+pub use console::{Console, Frame, Scripted, Terminal};
+pub use difficulty::Difficulty;
+pub use game::{play, Game, InvalidInput, Outcome, State, Summary};
+pub use leaderboard::{Leaderboard, Score};
+pub use replay::{Recorder, Replay, ReplayError};
+pub use solver::{evaluate, play_reverse, solve, Report, Strategy};
+pub use tui::Tui;
+pub use variant::{variant_by_name, Judgement, Variant, VARIANTS};
@@ -1,38 +1,500 @@
 /// @Author: Mitul
 /// @Date:   2024-11-23 22:18:40
 /// @Description: Guessing Game
+/*
+- guessing_game runs in one of six modes, picked by at most one mode flag. Options that the chosen mode does not use are rejected, not ignored.
+- Playing, the default: guessing_game [--difficulty easy|normal|hard] picks a secret number in the range of the difficulty and gives the player a limited
+  number of attempts.
+    -> --difficulty custom --range 1-500 --attempts 12 uses a custom range and attempt limit, here and in every other mode that takes a difficulty.
+    -> --variant hot-cold|bulls-and-cows|word plays another game instead of the number game, see the variant module. bulls-and-cows and word have
+       fixed rules and reject --difficulty, --range and --attempts. --tui draws the game on a full screen when stdout is a terminal and falls back
+       to line by line otherwise.
+    -> Input that is not a valid guess does not use up an attempt.
+    -> Won games are recorded on the leaderboard under --player (default: the USER environment variable) in the --scores file (default:
+       guessing_game.scores).
+    -> Every game is recorded to a replay file in --replay-dir (default: guessing_game_replays). --seed N fixes the secret, without it a random seed is
+       picked and printed.
+- --leaderboard prints the top games of every difficulty, or only of the one given with --difficulty.
+- --replay FILE plays a recorded game back with exactly the same output. Replayed games do not go on the leaderboard.
+- --solver lets the --strategy binary|random|golden|adversarial (default: all of them) play --games N games (default: 10000) and compares them.
+- --reverse turns the roles around: the player thinks of a number and the --strategy (default: binary) guesses it, --seed fixes the random strategy.
+- --serve [ADDRESS] (default: 127.0.0.1:7878) hosts a multiplayer race over TCP, see the multiplayer module and the guessing_client binary.
+*/
+use fundamentals_core::{exit_with_usage, Args};
+use guessing_game::multiplayer::Server;
+use guessing_game::solver::{self, ReverseResult};
+use guessing_game::{
+    play, variant_by_name, Console, Difficulty, Leaderboard, Recorder, Replay, Score, State,
+    Summary, Terminal, Tui, Variant, VARIANTS,
+};
 use rand::Rng;
-use std::cmp::Ordering;
-use std::io;
+use std::env;
+use std::fs;
+use std::io::{self, IsTerminal};
+use std::path::{Path, PathBuf};
+use std::process;
 
-fn main() {
-    println!("Guess the number!");
+const USAGE: &str = "\
+Usage: guessing_game [--difficulty easy|normal|hard|custom] [--range LOW-HIGH] [--attempts N] [--player NAME] [--scores FILE]
+                     [--seed N] [--replay-dir DIR] [--tui]
+                     [--variant number|hot-cold|bulls-and-cows|word]
+       guessing_game --leaderboard [--difficulty ...] [--scores FILE]
+       guessing_game --replay FILE
+       guessing_game --solver [--strategy binary|random|golden|adversarial] [--games N] [--difficulty ...] [--seed N]
+       guessing_game --reverse [--strategy ...] [--difficulty ...]
+       guessing_game --serve [ADDRESS] [--difficulty ...] [--seed N]";
+const LEADERBOARD_SIZE: usize = 10;
+
+struct Options {
+    difficulty: Difficulty,
+    difficulty_given: bool,
+    player: String,
+    scores: PathBuf,
+    show_leaderboard: bool,
+    seed: Option<u64>,
+    replay: Option<PathBuf>,
+    replay_dir: PathBuf,
+    solver: bool,
+    reverse: bool,
+    strategy: Option<String>,
+    games: u32,
+    serve: Option<String>,
+    tui: bool,
+    variant: String,
+}
+
+fn parse_args() -> Result<Options, String> {
+    let mut difficulty = None;
+    let mut range = None;
+    let mut attempts = None;
+    let mut player = None;
+    let mut scores = None;
+    let mut show_leaderboard = false;
+    let mut seed = None;
+    let mut replay = None;
+    let mut replay_dir = None;
+    let mut solver = false;
+    let mut reverse = false;
+    let mut strategy = None;
+    let mut games = None;
+    let mut serve = None;
+    let mut tui = false;
+    let mut variant = None;
+    let mut given = Vec::new();
+    let mut args = Args::from_env();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--difficulty" => difficulty = Some(args.value(&arg)?),
+            "--range" => range = Some(args.value(&arg)?),
+            "--attempts" => attempts = Some(args.number::<u32>(&arg)?),
+            "--player" => player = Some(args.value(&arg)?),
+            "--scores" => scores = Some(PathBuf::from(args.value(&arg)?)),
+            "--leaderboard" => show_leaderboard = true,
+            "--seed" => seed = Some(args.number::<u64>(&arg)?),
+            "--replay" => replay = Some(PathBuf::from(args.value(&arg)?)),
+            "--replay-dir" => replay_dir = Some(PathBuf::from(args.value(&arg)?)),
+            "--tui" => tui = true,
+            "--variant" => variant = Some(args.value(&arg)?),
+            "--solver" => solver = true,
+            "--reverse" => reverse = true,
+            "--strategy" => strategy = Some(args.value(&arg)?),
+            "--serve" => {
+                serve = Some(
+                    args.optional()
+                        .unwrap_or_else(|| "127.0.0.1:7878".to_string()),
+                )
+            }
+            "--games" => games = Some(args.number::<u32>(&arg)?),
+            _ => return Err(format!("unexpected argument '{}'", arg)),
+        }
+        given.push(arg);
+    }
+    check_modes(&given)?;
+
+    if let Some(name) = &strategy {
+        if solver::strategy_by_name(name, 0).is_none() {
+            return Err(format!("unknown strategy '{}'", name));
+        }
+    }
+    let variant = variant.unwrap_or_else(|| "number".to_string());
+    if !VARIANTS.contains(&variant.as_str()) {
+        return Err(format!("unknown variant '{}'", variant));
+    }
+    check_variant(&variant, &given)?;
 
-    let secret_number = rand::thread_rng().gen_range(1,100);
+    let difficulty_given = difficulty.is_some();
+    let difficulty = match difficulty.as_deref() {
+        Some("custom") => Difficulty::custom(
+            range.as_deref().ok_or("custom difficulty needs --range")?,
+            attempts.ok_or("custom difficulty needs --attempts")?,
+        )?,
+        _ if range.is_some() || attempts.is_some() => {
+            return Err("--range and --attempts only go with --difficulty custom".to_string())
+        }
+        Some(name) => name.parse()?,
+        None => Difficulty::default(),
+    };
+    let player = player
+        .or_else(|| env::var("USER").ok())
+        .filter(|name| !name.trim().is_empty())
+        .unwrap_or_else(|| "player".to_string());
 
-    loop {
-        println!("Please input your guess.");
+    Ok(Options {
+        difficulty,
+        difficulty_given,
+        player,
+        scores: scores.unwrap_or_else(|| PathBuf::from("guessing_game.scores")),
+        show_leaderboard,
+        seed,
+        replay,
+        replay_dir: replay_dir.unwrap_or_else(|| PathBuf::from("guessing_game_replays")),
+        solver,
+        reverse,
+        strategy,
+        games: games.unwrap_or(10_000),
+        serve,
+        tui,
+        variant,
+    })
+}
 
-        let mut guess = String::new();
+// The mode flags and the options every mode takes, None is playing a game.
+const MODES: [(Option<&str>, &[&str]); 6] = [
+    (
+        None,
+        &[
+            "--difficulty",
+            "--range",
+            "--attempts",
+            "--player",
+            "--scores",
+            "--seed",
+            "--replay-dir",
+            "--tui",
+            "--variant",
+        ],
+    ),
+    (
+        Some("--leaderboard"),
+        &["--difficulty", "--range", "--attempts", "--scores"],
+    ),
+    (Some("--replay"), &[]),
+    (
+        Some("--solver"),
+        &[
+            "--difficulty",
+            "--range",
+            "--attempts",
+            "--seed",
+            "--strategy",
+            "--games",
+        ],
+    ),
+    (
+        Some("--reverse"),
+        &[
+            "--difficulty",
+            "--range",
+            "--attempts",
+            "--seed",
+            "--strategy",
+        ],
+    ),
+    (
+        Some("--serve"),
+        &["--difficulty", "--range", "--attempts", "--seed"],
+    ),
+];
+
+fn check_modes(given: &[String]) -> Result<(), String> {
+    let is_mode = |flag: &str| MODES.iter().any(|(mode, _)| *mode == Some(flag));
+    let mut modes: Vec<&str> = Vec::new();
+    for flag in given.iter().filter(|flag| is_mode(flag)) {
+        if !modes.contains(&flag.as_str()) {
+            modes.push(flag);
+        }
+    }
+    if modes.len() > 1 {
+        return Err(format!("{} cannot be combined", modes.join(" and ")));
+    }
+    let mode = modes.first().copied();
+    let (_, options) = MODES.iter().find(|(flag, _)| *flag == mode).unwrap();
+    for option in given.iter().filter(|flag| !is_mode(flag)) {
+        if !options.contains(&option.as_str()) {
+            return Err(match mode {
+                Some(mode) => format!("{} does not go with {}", option, mode),
+                None => {
+                    let modes: Vec<&str> = MODES
+                        .iter()
+                        .filter(|(_, options)| options.contains(&option.as_str()))
+                        .filter_map(|(mode, _)| *mode)
+                        .collect();
+                    format!("{} needs {}", option, modes.join(" or "))
+                }
+            });
+        }
+    }
+    Ok(())
+}
 
-        io::stdin()
-            .read_line(&mut guess)
-            .expect("Failed to read line");
+// bulls-and-cows and word have fixed rules, a difficulty would not change them.
+fn check_variant(variant: &str, given: &[String]) -> Result<(), String> {
+    if ["number", "hot-cold"].contains(&variant) {
+        return Ok(());
+    }
+    match given
+        .iter()
+        .find(|flag| ["--difficulty", "--range", "--attempts"].contains(&flag.as_str()))
+    {
+        Some(flag) => Err(format!("{} does not go with --variant {}", flag, variant)),
+        None => Ok(()),
+    }
+}
 
-        let guess: u32 = match guess.trim().parse() {
-            Ok(num) => num,
-            Err(_) => continue,
-        };
+fn print_leaderboard(leaderboard: &Leaderboard, only: Option<&str>) {
+    let difficulties = match only {
+        Some(difficulty) => vec![difficulty],
+        None => leaderboard.difficulties(),
+    };
+    if difficulties.is_empty() {
+        println!("No scores yet.");
+    }
+    for difficulty in difficulties {
+        println!("== {} ==", difficulty);
+        for (rank, score) in leaderboard
+            .top(difficulty, LEADERBOARD_SIZE)
+            .iter()
+            .enumerate()
+        {
+            println!(
+                "{:>2}. {:<20} {:>3} attempts {:>8.1}s",
+                rank + 1,
+                score.player,
+                score.attempts,
+                score.elapsed.as_secs_f64()
+            );
+        }
+    }
+}
 
-        println!("You guessed: {guess}");
+fn main() {
+    let options = parse_args().unwrap_or_else(|err| {
+        exit_with_usage(&err, USAGE);
+    });
+    let difficulty = options.difficulty;
 
-        match guess.cmp(&secret_number) {
-            Ordering::Less => println!("Too small!"),
-            Ordering::Greater => println!("Too big!"),
-            Ordering::Equal => {
-                println!("You win!");
-                break;
+    if options.show_leaderboard {
+        let key = difficulty.key();
+        match Leaderboard::load(&options.scores) {
+            Ok(leaderboard) => print_leaderboard(
+                &leaderboard,
+                options.difficulty_given.then_some(key.as_str()),
+            ),
+            Err(err) => {
+                eprintln!("error: cannot read {}: {}", options.scores.display(), err);
+                process::exit(1);
             }
         }
+        return;
+    }
+
+    if let Some(path) = &options.replay {
+        play_replay(path);
+        return;
+    }
+
+    let seed = options.seed.unwrap_or_else(|| rand::thread_rng().gen());
+    if options.solver {
+        compare_strategies(&options, seed);
+        return;
+    }
+    if options.reverse {
+        play_reverse(&options, seed);
+        return;
+    }
+    if let Some(address) = &options.serve {
+        serve(address, difficulty, seed);
+        return;
+    }
+
+    let mut variant =
+        variant_by_name(&options.variant, difficulty, seed).expect("checked in parse_args");
+    let tui = options.tui && io::stdout().is_terminal();
+    if options.tui && !tui {
+        eprintln!("warning: --tui needs a terminal, playing line by line");
+    }
+    let result = if tui {
+        play_recorded(variant.as_mut(), Tui::new(), difficulty, seed)
+    } else {
+        play_recorded(variant.as_mut(), Terminal, difficulty, seed)
+    };
+    let (summary, replay) = match result {
+        Ok(result) => result,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            process::exit(1);
+        }
+    };
+    save_replay(&options.replay_dir, &replay);
+    if summary.state == State::Won {
+        let key = variant.key();
+        record_score(
+            &options,
+            &key,
+            Score::new(&options.player, &key, summary.attempts, summary.elapsed),
+        );
+    }
+}
+
+fn compare_strategies(options: &Options, seed: u64) {
+    println!(
+        "{} games per strategy on {}, seed {}",
+        options.games, options.difficulty, seed
+    );
+    for mut strategy in solver::strategies(seed) {
+        if options
+            .strategy
+            .as_deref()
+            .is_some_and(|name| name != strategy.name())
+        {
+            continue;
+        }
+        println!(
+            "{}",
+            solver::evaluate(strategy.as_mut(), options.difficulty, options.games, seed)
+        );
+    }
+}
+
+fn play_reverse(options: &Options, seed: u64) {
+    let name = options.strategy.as_deref().unwrap_or("binary");
+    let mut strategy = solver::strategy_by_name(name, seed).expect("checked in parse_args");
+    match solver::play_reverse(options.difficulty, strategy.as_mut(), &mut Terminal) {
+        Ok(ReverseResult::Contradiction { .. }) => process::exit(1),
+        Ok(_) => {}
+        Err(err) => {
+            eprintln!("error: {}", err);
+            process::exit(1);
+        }
+    }
+}
+
+fn serve(address: &str, difficulty: Difficulty, seed: u64) {
+    let result = Server::bind(address, difficulty, seed).and_then(|server| {
+        println!(
+            "Serving {} on {}, seed {}",
+            difficulty,
+            server.local_addr()?,
+            seed
+        );
+        server.run()
+    });
+    if let Err(err) = result {
+        eprintln!("error: cannot serve on {}: {}", address, err);
+        process::exit(1);
+    }
+}
+
+fn play_recorded<C: Console>(
+    variant: &mut dyn Variant,
+    console: C,
+    difficulty: Difficulty,
+    seed: u64,
+) -> io::Result<(Summary, Replay)> {
+    let mut recorder = Recorder::new(console, variant, difficulty, seed);
+    let summary = play(variant, &mut recorder)?;
+    Ok((summary, recorder.into_replay()))
+}
+
+fn play_replay(path: &Path) {
+    let result = Replay::load(path).and_then(|replay| {
+        let mut variant = replay.variant()?;
+        let mut console = replay.console();
+        play(variant.as_mut(), &mut console)?;
+        Ok(console.output)
+    });
+    match result {
+        Ok(output) => output.iter().for_each(|line| println!("{}", line)),
+        Err(err) => {
+            eprintln!("error: cannot replay {}: {}", path.display(), err);
+            process::exit(1);
+        }
+    }
+}
+
+fn save_replay(directory: &Path, replay: &Replay) {
+    let path = directory.join(format!(
+        "{}-{}-{}.replay",
+        replay.variant, replay.started_at, replay.seed
+    ));
+    match fs::create_dir_all(directory).and_then(|_| replay.save(&path)) {
+        Ok(()) => println!("Seed {}, replay saved to {}", replay.seed, path.display()),
+        Err(err) => eprintln!(
+            "warning: could not save the replay to {}: {}",
+            path.display(),
+            err
+        ),
+    }
+}
+
+// A leaderboard that cannot be read or written costs the player their score but not the game, so problems are only reported.
+fn record_score(options: &Options, key: &str, score: Score) {
+    let result = Leaderboard::load(&options.scores).and_then(|mut leaderboard| {
+        let best = leaderboard.record(score);
+        leaderboard.save(&options.scores)?;
+        Ok(best)
+    });
+    match result {
+        Ok(true) => println!("New best for {} on {}!", options.player, key),
+        Ok(false) => {}
+        Err(err) => eprintln!(
+            "warning: could not save the score to {}: {}",
+            options.scores.display(),
+            err
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(line: &str) -> Result<(), String> {
+        let given: Vec<String> = line.split_whitespace().map(String::from).collect();
+        check_modes(&given)
+    }
+
+    #[test]
+    fn modes_do_not_mix() {
+        assert!(check("--difficulty --tui --variant --seed").is_ok());
+        assert!(check("--solver --strategy --games --seed").is_ok());
+        assert_eq!(
+            check("--replay --solver --replay"),
+            Err("--replay and --solver cannot be combined".to_string())
+        );
+        assert_eq!(
+            check("--serve --tui"),
+            Err("--tui does not go with --serve".to_string())
+        );
+        assert_eq!(
+            check("--strategy"),
+            Err("--strategy needs --solver or --reverse".to_string())
+        );
+    }
+
+    #[test]
+    fn fixed_rule_variants_take_no_difficulty() {
+        let given =
+            |line: &str| -> Vec<String> { line.split_whitespace().map(String::from).collect() };
+        assert!(check_variant("hot-cold", &given("--difficulty --variant")).is_ok());
+        assert!(check_variant("word", &given("--variant --seed")).is_ok());
+        assert_eq!(
+            check_variant("word", &given("--variant --difficulty")),
+            Err("--difficulty does not go with --variant word".to_string())
+        );
+        assert_eq!(
+            check_variant("bulls-and-cows", &given("--attempts --variant")),
+            Err("--attempts does not go with --variant bulls-and-cows".to_string())
+        );
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,749 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 05:58:34
+/// @Description: Multiplayer Server and Client
+/*
+- The server keeps one round going at a time. Every player in the round guesses the same secret number with their own Game, so the attempt limit of the
+  difficulty applies to each player separately. The first player to guess it wins the round and a new round starts right away.
+- Round n uses the secret number of Game::seeded(difficulty, seed + n), so a server started with the same seed plays the same rounds.
+- Every connection gets a thread that reads it and one that writes to it. The lobby is shared behind a mutex. Messages are queued for the writing threads
+  while it is locked, so every player sees the messages in the same order, and written after it is released, so a player that stops reading holds up
+  nobody but itself.
+- A request line is at most 256 bytes. The rest of a longer one is skipped without being kept and the client gets an error, so a client cannot make the
+  server buffer without end.
+- The protocol is one line per message, words separated by spaces.
+    -> client to server: HELLO <name>, GUESS <number>, QUIT
+    -> server to client: WELCOME <name>, ROUND <round> <low> <high> <attempts>, JOINED <name>, LEFT <name>, HINT <name> <guess> SMALL|BIG, OUT <name>,
+       WINNER <name> <attempts> <secret>, NOWINNER <secret>, ERROR <text>, BYE
+- A connection has to say HELLO with a name nobody else uses before it can guess. HINT, OUT and WINNER go to every player, so everyone can use the hints of
+  the others in the race. When every player has run out of attempts the server sends NOWINNER and starts a new round.
+*/
+use crate::difficulty::Difficulty;
+use crate::game::{Game, InvalidInput, Outcome, State};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::Shutdown;
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::str::FromStr;
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+const MAX_NAME_LENGTH: usize = 20;
+const MAX_LINE_LENGTH: u64 = 256;
+// A player whose connection does not take our messages for this long is dropped instead of holding up everyone else.
+const WRITE_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Request {
+    Hello(String),
+    Guess(String),
+    Quit,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    TooSmall,
+    TooBig,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Message {
+    Welcome(String),
+    Round {
+        round: u32,
+        low: u32,
+        high: u32,
+        attempts: u32,
+    },
+    Joined(String),
+    Left(String),
+    Hint {
+        player: String,
+        guess: u32,
+        hint: Hint,
+    },
+    Out(String),
+    Winner {
+        player: String,
+        attempts: u32,
+        secret: u32,
+    },
+    NoWinner(u32),
+    Error(String),
+    Bye,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProtocolError {
+    pub line: String,
+}
+
+impl fmt::Display for ProtocolError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unexpected line '{}'", self.line)
+    }
+}
+
+impl std::error::Error for ProtocolError {}
+
+impl fmt::Display for Request {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Request::Hello(name) => write!(f, "HELLO {}", name),
+            Request::Guess(text) => write!(f, "GUESS {}", text),
+            Request::Quit => write!(f, "QUIT"),
+        }
+    }
+}
+
+impl FromStr for Request {
+    type Err = ProtocolError;
+
+    fn from_str(line: &str) -> Result<Request, ProtocolError> {
+        let (command, rest) = split_command(line);
+        match (command, rest) {
+            ("HELLO", name) if !name.is_empty() => Ok(Request::Hello(name.to_string())),
+            ("GUESS", text) => Ok(Request::Guess(text.to_string())),
+            ("QUIT", "") => Ok(Request::Quit),
+            _ => Err(ProtocolError {
+                line: line.to_string(),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for Hint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Hint::TooSmall => write!(f, "SMALL"),
+            Hint::TooBig => write!(f, "BIG"),
+        }
+    }
+}
+
+impl fmt::Display for Message {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Message::Welcome(name) => write!(f, "WELCOME {}", name),
+            Message::Round {
+                round,
+                low,
+                high,
+                attempts,
+            } => write!(f, "ROUND {} {} {} {}", round, low, high, attempts),
+            Message::Joined(name) => write!(f, "JOINED {}", name),
+            Message::Left(name) => write!(f, "LEFT {}", name),
+            Message::Hint {
+                player,
+                guess,
+                hint,
+            } => write!(f, "HINT {} {} {}", player, guess, hint),
+            Message::Out(name) => write!(f, "OUT {}", name),
+            Message::Winner {
+                player,
+                attempts,
+                secret,
+            } => write!(f, "WINNER {} {} {}", player, attempts, secret),
+            Message::NoWinner(secret) => write!(f, "NOWINNER {}", secret),
+            Message::Error(text) => write!(f, "ERROR {}", text),
+            Message::Bye => write!(f, "BYE"),
+        }
+    }
+}
+
+impl FromStr for Message {
+    type Err = ProtocolError;
+
+    fn from_str(line: &str) -> Result<Message, ProtocolError> {
+        let error = || ProtocolError {
+            line: line.to_string(),
+        };
+        let (command, rest) = split_command(line);
+        let words: Vec<&str> = rest.split_whitespace().collect();
+        let number = |word: &str| word.parse::<u32>().map_err(|_| error());
+        let message = match (command, words.as_slice()) {
+            ("WELCOME", [name]) => Message::Welcome(name.to_string()),
+            ("ROUND", [round, low, high, attempts]) => Message::Round {
+                round: number(round)?,
+                low: number(low)?,
+                high: number(high)?,
+                attempts: number(attempts)?,
+            },
+            ("JOINED", [name]) => Message::Joined(name.to_string()),
+            ("LEFT", [name]) => Message::Left(name.to_string()),
+            ("HINT", [player, guess, hint]) => Message::Hint {
+                player: player.to_string(),
+                guess: number(guess)?,
+                hint: match *hint {
+                    "SMALL" => Hint::TooSmall,
+                    "BIG" => Hint::TooBig,
+                    _ => return Err(error()),
+                },
+            },
+            ("OUT", [name]) => Message::Out(name.to_string()),
+            ("WINNER", [player, attempts, secret]) => Message::Winner {
+                player: player.to_string(),
+                attempts: number(attempts)?,
+                secret: number(secret)?,
+            },
+            ("NOWINNER", [secret]) => Message::NoWinner(number(secret)?),
+            ("ERROR", _) => Message::Error(rest.to_string()),
+            ("BYE", []) => Message::Bye,
+            _ => return Err(error()),
+        };
+        Ok(message)
+    }
+}
+
+fn split_command(line: &str) -> (&str, &str) {
+    let line = line.trim();
+    match line.split_once(' ') {
+        Some((command, rest)) => (command, rest.trim()),
+        None => (line, ""),
+    }
+}
+
+fn send(stream: &mut TcpStream, message: &Message) -> io::Result<()> {
+    stream.write_all(format!("{}\n", message).as_bytes())
+}
+
+// The thread ends when every sender of the outbox is gone and the queued messages are written. A connection that fails is shut down, the reading thread
+// then sees the end of the stream and removes the player.
+fn spawn_writer(mut stream: TcpStream) -> mpsc::Sender<Message> {
+    let (outbox, messages) = mpsc::channel::<Message>();
+    thread::spawn(move || {
+        for message in messages {
+            if send(&mut stream, &message).is_err() {
+                let _ = stream.shutdown(Shutdown::Both);
+                break;
+            }
+        }
+    });
+    outbox
+}
+
+fn check_name(name: &str) -> Result<(), String> {
+    if name.len() > MAX_NAME_LENGTH || name.contains(char::is_whitespace) {
+        return Err(format!(
+            "names are one word of at most {} characters",
+            MAX_NAME_LENGTH
+        ));
+    }
+    Ok(())
+}
+
+//------------------------------------------------------------Server----------------------------------------------------------
+
+struct Player {
+    name: String,
+    outbox: mpsc::Sender<Message>,
+    game: Game,
+}
+
+struct Lobby {
+    difficulty: Difficulty,
+    seed: u64,
+    round: u32,
+    secret: u32,
+    players: BTreeMap<u64, Player>,
+}
+
+impl Lobby {
+    fn new(difficulty: Difficulty, seed: u64) -> Lobby {
+        let mut lobby = Lobby {
+            difficulty,
+            seed,
+            round: 0,
+            secret: 0,
+            players: BTreeMap::new(),
+        };
+        lobby.new_round();
+        lobby
+    }
+
+    fn round_message(&self) -> Message {
+        let range = self.difficulty.range();
+        Message::Round {
+            round: self.round,
+            low: *range.start(),
+            high: *range.end(),
+            attempts: self.difficulty.max_attempts(),
+        }
+    }
+
+    // Only queues the message. The writing thread of a player whose connection failed is gone, the player is removed once its reading thread ends.
+    fn send_to(&mut self, id: u64, message: &Message) {
+        if let Some(player) = self.players.get(&id) {
+            let _ = player.outbox.send(message.clone());
+        }
+    }
+
+    fn broadcast(&mut self, message: &Message) {
+        let ids: Vec<u64> = self.players.keys().copied().collect();
+        for id in ids {
+            self.send_to(id, message);
+        }
+    }
+
+    fn new_round(&mut self) {
+        self.round += 1;
+        self.secret =
+            Game::seeded(self.difficulty, self.seed.wrapping_add(self.round as u64)).secret();
+        for player in self.players.values_mut() {
+            player.game = Game::new(self.difficulty, self.secret);
+        }
+        let message = self.round_message();
+        self.broadcast(&message);
+    }
+
+    fn join(&mut self, id: u64, name: &str, outbox: mpsc::Sender<Message>) -> Result<(), String> {
+        check_name(name)?;
+        if self.players.values().any(|player| player.name == name) {
+            return Err(format!("the name {} is taken", name));
+        }
+        self.broadcast(&Message::Joined(name.to_string()));
+        let _ = outbox.send(Message::Welcome(name.to_string()));
+        let _ = outbox.send(self.round_message());
+        self.players.insert(
+            id,
+            Player {
+                name: name.to_string(),
+                outbox,
+                game: Game::new(self.difficulty, self.secret),
+            },
+        );
+        Ok(())
+    }
+
+    fn leave(&mut self, id: u64) {
+        if let Some(player) = self.players.remove(&id) {
+            self.broadcast(&Message::Left(player.name));
+            self.end_round_if_everyone_lost();
+        }
+    }
+
+    fn guess(&mut self, id: u64, text: &str) {
+        let player = match self.players.get_mut(&id) {
+            Some(player) => player,
+            None => return,
+        };
+        if player.game.is_over() {
+            self.send_to(
+                id,
+                &Message::Error("no attempts left this round".to_string()),
+            );
+            return;
+        }
+        let name = player.name.clone();
+        let outcome = player.game.guess(text);
+        let attempts = player.game.attempts();
+        let guess = player.game.guesses().last().copied().unwrap_or(0);
+        let range = self.difficulty.range();
+        match outcome {
+            Outcome::TooSmall | Outcome::TooBig => self.broadcast(&Message::Hint {
+                player: name,
+                guess,
+                hint: if outcome == Outcome::TooSmall {
+                    Hint::TooSmall
+                } else {
+                    Hint::TooBig
+                },
+            }),
+            Outcome::Won => {
+                let secret = self.secret;
+                self.broadcast(&Message::Winner {
+                    player: name,
+                    attempts,
+                    secret,
+                });
+                self.new_round();
+            }
+            Outcome::OutOfAttempts => {
+                self.broadcast(&Message::Out(name));
+                self.end_round_if_everyone_lost();
+            }
+            Outcome::InvalidInput(InvalidInput::NotANumber(text)) => {
+                self.send_to(id, &Message::Error(format!("'{}' is not a number", text)))
+            }
+            Outcome::InvalidInput(InvalidInput::OutOfRange(number)) => self.send_to(
+                id,
+                &Message::Error(format!(
+                    "{} is not between {} and {}",
+                    number,
+                    range.start(),
+                    range.end()
+                )),
+            ),
+        }
+    }
+
+    fn end_round_if_everyone_lost(&mut self) {
+        if !self.players.is_empty()
+            && self
+                .players
+                .values()
+                .all(|player| player.game.state() == State::Lost)
+        {
+            let secret = self.secret;
+            self.broadcast(&Message::NoWinner(secret));
+            self.new_round();
+        }
+    }
+}
+
+pub struct Server {
+    listener: TcpListener,
+    lobby: Arc<Mutex<Lobby>>,
+}
+
+impl Server {
+    pub fn bind<A: ToSocketAddrs>(
+        address: A,
+        difficulty: Difficulty,
+        seed: u64,
+    ) -> io::Result<Server> {
+        Ok(Server {
+            listener: TcpListener::bind(address)?,
+            lobby: Arc::new(Mutex::new(Lobby::new(difficulty, seed))),
+        })
+    }
+
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    // Accepts connections until accepting fails.
+    pub fn run(self) -> io::Result<()> {
+        for (id, stream) in (0u64..).zip(self.listener.incoming()) {
+            let stream = stream?;
+            let lobby = Arc::clone(&self.lobby);
+            thread::spawn(move || {
+                // A connection that breaks only ends that player's game.
+                let _ = serve_connection(&lobby, id, stream);
+                lobby.lock().unwrap().leave(id);
+            });
+        }
+        Ok(())
+    }
+}
+
+fn skip_line<R: BufRead>(reader: &mut R) -> io::Result<()> {
+    loop {
+        let buffer = reader.fill_buf()?;
+        if buffer.is_empty() {
+            return Ok(());
+        }
+        match buffer.iter().position(|&byte| byte == b'\n') {
+            Some(end) => {
+                reader.consume(end + 1);
+                return Ok(());
+            }
+            None => {
+                let len = buffer.len();
+                reader.consume(len);
+            }
+        }
+    }
+}
+
+// Everything for this connection goes through its outbox, also before it joined, so its messages never interleave.
+fn serve_connection(lobby: &Mutex<Lobby>, id: u64, stream: TcpStream) -> io::Result<()> {
+    stream.set_write_timeout(Some(WRITE_TIMEOUT))?;
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let outbox = spawn_writer(stream);
+    let mut joined = false;
+    let mut line = Vec::new();
+    loop {
+        line.clear();
+        let read = (&mut reader)
+            .take(MAX_LINE_LENGTH + 1)
+            .read_until(b'\n', &mut line)?;
+        if read == 0 {
+            return Ok(());
+        }
+        if read as u64 > MAX_LINE_LENGTH && !line.ends_with(b"\n") {
+            skip_line(&mut reader)?;
+            let text = format!("lines are at most {} bytes", MAX_LINE_LENGTH);
+            let _ = outbox.send(Message::Error(text));
+            continue;
+        }
+        let mut lobby = lobby.lock().unwrap();
+        let error = match String::from_utf8_lossy(&line).parse::<Request>() {
+            Ok(Request::Hello(name)) if !joined => match lobby.join(id, &name, outbox.clone()) {
+                Ok(()) => {
+                    joined = true;
+                    None
+                }
+                Err(text) => Some(text),
+            },
+            Ok(Request::Hello(_)) => Some("you already joined".to_string()),
+            Ok(Request::Guess(_)) if !joined => Some("say HELLO <name> first".to_string()),
+            Ok(Request::Guess(text)) => {
+                lobby.guess(id, &text);
+                None
+            }
+            Ok(Request::Quit) => {
+                let _ = outbox.send(Message::Bye);
+                return Ok(());
+            }
+            Err(err) => Some(err.to_string()),
+        };
+        if let Some(text) = error {
+            let _ = outbox.send(Message::Error(text));
+        }
+    }
+}
+
+//------------------------------------------------------------Client----------------------------------------------------------
+
+pub struct Client {
+    reader: BufReader<TcpStream>,
+    writer: TcpStream,
+    name: String,
+}
+
+// Sends requests from another thread than the one waiting for messages.
+pub struct Sender {
+    stream: TcpStream,
+}
+
+impl Sender {
+    pub fn send(&mut self, request: &Request) -> io::Result<()> {
+        self.stream.write_all(format!("{}\n", request).as_bytes())
+    }
+}
+
+impl Client {
+    // Says HELLO and waits for the server to welcome the name. A name the server refuses is an error.
+    pub fn connect<A: ToSocketAddrs>(address: A, name: &str) -> io::Result<Client> {
+        let stream = TcpStream::connect(address)?;
+        let mut client = Client {
+            reader: BufReader::new(stream.try_clone()?),
+            writer: stream,
+            name: name.to_string(),
+        };
+        client.send(&Request::Hello(name.to_string()))?;
+        match client.receive()? {
+            Some(Message::Welcome(_)) => Ok(client),
+            Some(Message::Error(text)) => Err(io::Error::other(text)),
+            other => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("expected WELCOME, got {:?}", other),
+            )),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn set_read_timeout(&self, timeout: Option<Duration>) -> io::Result<()> {
+        self.writer.set_read_timeout(timeout)
+    }
+
+    pub fn sender(&self) -> io::Result<Sender> {
+        Ok(Sender {
+            stream: self.writer.try_clone()?,
+        })
+    }
+
+    pub fn send(&mut self, request: &Request) -> io::Result<()> {
+        self.writer.write_all(format!("{}\n", request).as_bytes())
+    }
+
+    pub fn guess(&mut self, text: &str) -> io::Result<()> {
+        self.send(&Request::Guess(text.to_string()))
+    }
+
+    // None once the server closed the connection.
+    pub fn receive(&mut self) -> io::Result<Option<Message>> {
+        let mut line = String::new();
+        if self.reader.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        line.parse()
+            .map(Some)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SEED: u64 = 7;
+
+    fn start(difficulty: Difficulty) -> SocketAddr {
+        let server = Server::bind("127.0.0.1:0", difficulty, SEED).unwrap();
+        let address = server.local_addr().unwrap();
+        thread::spawn(move || server.run());
+        address
+    }
+
+    fn connect(address: SocketAddr, name: &str) -> Client {
+        let client = Client::connect(address, name).unwrap();
+        client
+            .set_read_timeout(Some(Duration::from_secs(5)))
+            .unwrap();
+        client
+    }
+
+    fn next(client: &mut Client) -> Message {
+        client.receive().unwrap().unwrap()
+    }
+
+    fn secret(difficulty: Difficulty, round: u64) -> u32 {
+        Game::seeded(difficulty, SEED + round).secret()
+    }
+
+    #[test]
+    fn protocol_lines_round_trip() {
+        let messages = [
+            Message::Welcome("ann".to_string()),
+            Message::Round {
+                round: 3,
+                low: 1,
+                high: 100,
+                attempts: 8,
+            },
+            Message::Hint {
+                player: "bob".to_string(),
+                guess: 50,
+                hint: Hint::TooBig,
+            },
+            Message::Winner {
+                player: "ann".to_string(),
+                attempts: 4,
+                secret: 17,
+            },
+            Message::NoWinner(9),
+            Message::Error("'x' is not a number".to_string()),
+            Message::Bye,
+        ];
+        for message in messages {
+            assert_eq!(message.to_string().parse::<Message>(), Ok(message));
+        }
+        assert_eq!("GUESS 12".parse(), Ok(Request::Guess("12".to_string())));
+        assert!("HELLO".parse::<Request>().is_err());
+        assert!("ROUND 1 2".parse::<Message>().is_err());
+    }
+
+    #[test]
+    fn race_on_loopback() {
+        let difficulty = Difficulty::Normal;
+        let address = start(difficulty);
+        let mut ann = connect(address, "ann");
+        assert_eq!(
+            next(&mut ann),
+            Message::Round {
+                round: 1,
+                low: 1,
+                high: 100,
+                attempts: 8
+            }
+        );
+        let mut bob = connect(address, "bob");
+        assert_eq!(next(&mut ann), Message::Joined("bob".to_string()));
+        assert!(matches!(next(&mut bob), Message::Round { round: 1, .. }));
+        assert!(Client::connect(address, "ann").is_err());
+
+        let secret = secret(difficulty, 1);
+        let wrong = if secret == 1 { 2 } else { secret - 1 };
+        ann.guess(&wrong.to_string()).unwrap();
+        let hint = Message::Hint {
+            player: "ann".to_string(),
+            guess: wrong,
+            hint: if wrong < secret {
+                Hint::TooSmall
+            } else {
+                Hint::TooBig
+            },
+        };
+        assert_eq!(next(&mut ann), hint);
+        assert_eq!(next(&mut bob), hint);
+
+        bob.guess("forty").unwrap();
+        assert_eq!(
+            next(&mut bob),
+            Message::Error("'forty' is not a number".to_string())
+        );
+
+        bob.guess(&secret.to_string()).unwrap();
+        let winner = Message::Winner {
+            player: "bob".to_string(),
+            attempts: 1,
+            secret,
+        };
+        for client in [&mut ann, &mut bob] {
+            assert_eq!(next(client), winner);
+            assert!(matches!(next(client), Message::Round { round: 2, .. }));
+        }
+
+        bob.send(&Request::Quit).unwrap();
+        assert_eq!(next(&mut bob), Message::Bye);
+        assert_eq!(next(&mut ann), Message::Left("bob".to_string()));
+    }
+
+    #[test]
+    fn new_round_when_everyone_is_out() {
+        let difficulty = Difficulty::Custom {
+            low: 1,
+            high: 10,
+            max_attempts: 1,
+        };
+        let address = start(difficulty);
+        let mut ann = connect(address, "ann");
+        next(&mut ann);
+        let secret = secret(difficulty, 1);
+        let wrong = if secret == 1 { 2 } else { 1 };
+        ann.guess(&wrong.to_string()).unwrap();
+        assert_eq!(next(&mut ann), Message::Out("ann".to_string()));
+        assert_eq!(next(&mut ann), Message::NoWinner(secret));
+        assert!(matches!(next(&mut ann), Message::Round { round: 2, .. }));
+    }
+
+    #[test]
+    fn long_lines_are_skipped() {
+        let address = start(Difficulty::Normal);
+        let mut stream = TcpStream::connect(address).unwrap();
+        stream
+            .set_read_timeout(Some(Duration::from_secs(5)))
+            .unwrap();
+        stream
+            .write_all(format!("HELLO {}\nHELLO ann\n", "x".repeat(100_000)).as_bytes())
+            .unwrap();
+        let mut reader = BufReader::new(stream);
+        let mut receive = || {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            line.parse::<Message>().unwrap()
+        };
+        assert_eq!(
+            receive(),
+            Message::Error("lines are at most 256 bytes".to_string())
+        );
+        assert_eq!(receive(), Message::Welcome("ann".to_string()));
+    }
+
+    #[test]
+    fn a_player_that_does_not_read_holds_up_nobody() {
+        let difficulty = Difficulty::Normal;
+        let address = start(difficulty);
+        let mut ann = connect(address, "ann");
+        next(&mut ann);
+        // bob never reads, the messages pile up in his socket buffers and then in his outbox.
+        let bob = connect(address, "bob");
+        next(&mut ann);
+        let secret = secret(difficulty, 1);
+        let wrong = if secret == 1 { 2 } else { secret - 1 };
+        let mut sender = bob.sender().unwrap();
+        for _ in 0..20_000 {
+            sender.send(&Request::Guess("x".to_string())).unwrap();
+        }
+        ann.guess(&wrong.to_string()).unwrap();
+        assert!(matches!(next(&mut ann), Message::Hint { .. }));
+        drop(bob);
+    }
+}
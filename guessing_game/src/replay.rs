@@ -0,0 +1,385 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 05:50:59
+/// @Description: Recording and Replaying Games
+/*
+- Every game is played with a seed. The seed fixes the secret, so the seed, the variant, the difficulty and the lines the player typed are all it takes to
+  play the same game again and get exactly the same output, which is what a bug report or a regression test needs.
+- Recorder wraps the Console of a game and writes down every input line with the time since the start of the game. Replay is the recorded session, it can
+  be saved to and loaded from a plain text file that is easy to read and to edit by hand:
+      guessing_game replay 2
+      started 1760812315
+      seed 8117542861187325741
+      variant number
+      difficulty normal
+      secret 37
+      input 1.532 50
+      input 3.004 25
+  started is the unix time of the game, the input times are seconds since the start of the game. Files of version 1 have no variant line, they are
+  games of the number variant.
+- The secret is stored as well. Playing a replay back checks that the seed still gives the same secret, the random number generator of the rand crate is
+  allowed to change between versions and a replay that no longer matches should fail loudly instead of showing a different game.
+*/
+use crate::console::{Console, Frame, Scripted};
+use crate::difficulty::Difficulty;
+use crate::variant::{variant_by_name, Variant, VARIANTS};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+const HEADER: &str = "guessing_game replay 2";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Replay {
+    pub started_at: u64,
+    pub seed: u64,
+    pub variant: String,
+    pub difficulty: Difficulty,
+    pub secret: String,
+    pub inputs: Vec<(Duration, String)>,
+}
+
+#[derive(Debug)]
+pub enum ReplayError {
+    Io(io::Error),
+    Parse { line: usize, message: String },
+    SecretMismatch { recorded: String, generated: String },
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ReplayError::Io(err) => write!(f, "{}", err),
+            ReplayError::Parse { line, message } => write!(f, "line {}: {}", line, message),
+            ReplayError::SecretMismatch { recorded, generated } => write!(
+                f,
+                "the seed now gives secret {} but the replay was recorded with {}, the random number generator has changed",
+                generated, recorded
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ReplayError {}
+
+impl From<io::Error> for ReplayError {
+    fn from(err: io::Error) -> ReplayError {
+        ReplayError::Io(err)
+    }
+}
+
+impl Replay {
+    pub fn variant(&self) -> Result<Box<dyn Variant>, ReplayError> {
+        let variant =
+            variant_by_name(&self.variant, self.difficulty, self.seed).ok_or_else(|| {
+                ReplayError::Parse {
+                    line: 4,
+                    message: format!("unknown variant '{}'", self.variant),
+                }
+            })?;
+        if variant.secret() != self.secret {
+            return Err(ReplayError::SecretMismatch {
+                recorded: self.secret.clone(),
+                generated: variant.secret(),
+            });
+        }
+        Ok(variant)
+    }
+
+    pub fn console(&self) -> Scripted {
+        Scripted::timed(self.inputs.clone())
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    pub fn load(path: &Path) -> Result<Replay, ReplayError> {
+        fs::read_to_string(path)?.parse()
+    }
+}
+
+impl fmt::Display for Replay {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}", HEADER)?;
+        writeln!(f, "started {}", self.started_at)?;
+        writeln!(f, "seed {}", self.seed)?;
+        writeln!(f, "variant {}", self.variant)?;
+        writeln!(f, "difficulty {}", self.difficulty.key())?;
+        writeln!(f, "secret {}", self.secret)?;
+        for (time, line) in &self.inputs {
+            writeln!(f, "input {:.3} {}", time.as_secs_f64(), line)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for Replay {
+    type Err = ReplayError;
+
+    fn from_str(text: &str) -> Result<Replay, ReplayError> {
+        let mut lines = text.lines().enumerate();
+        let mut field = |name: &str| -> Result<(usize, String), ReplayError> {
+            let (index, line) = lines.next().ok_or_else(|| ReplayError::Parse {
+                line: 0,
+                message: format!("missing '{}'", name),
+            })?;
+            let value = line
+                .strip_prefix(name)
+                .and_then(|rest| {
+                    rest.strip_prefix(' ')
+                        .or(if rest.is_empty() { Some("") } else { None })
+                })
+                .ok_or_else(|| ReplayError::Parse {
+                    line: index + 1,
+                    message: format!("expected '{}'", name),
+                })?;
+            Ok((index + 1, value.to_string()))
+        };
+        let number = |(line, value): (usize, String)| -> Result<u64, ReplayError> {
+            value.parse().map_err(|_| ReplayError::Parse {
+                line,
+                message: format!("'{}' is not a number", value),
+            })
+        };
+
+        let (_, version) = field("guessing_game replay")?;
+        if version != "1" && version != "2" {
+            return Err(ReplayError::Parse {
+                line: 1,
+                message: format!("unsupported replay version '{}'", version),
+            });
+        }
+        let started_at = number(field("started")?)?;
+        let seed = number(field("seed")?)?;
+        let variant = if version == "1" {
+            "number".to_string()
+        } else {
+            let (line, variant) = field("variant")?;
+            if !VARIANTS.contains(&variant.as_str()) {
+                return Err(ReplayError::Parse {
+                    line,
+                    message: format!("unknown variant '{}'", variant),
+                });
+            }
+            variant
+        };
+        let (line, key) = field("difficulty")?;
+        let difficulty =
+            Difficulty::from_key(&key).map_err(|message| ReplayError::Parse { line, message })?;
+        let (line, secret) = field("secret")?;
+        if secret.is_empty() {
+            return Err(ReplayError::Parse {
+                line,
+                message: "the secret is missing".to_string(),
+            });
+        }
+        if variant == "number" || variant == "hot-cold" {
+            u32::try_from(number((line, secret.clone()))?).map_err(|_| ReplayError::Parse {
+                line,
+                message: format!("secret number {} is too large", secret),
+            })?;
+        }
+
+        let mut inputs = Vec::new();
+        for (index, line) in text.lines().enumerate().skip(line) {
+            let parse_error = || ReplayError::Parse {
+                line: index + 1,
+                message: "expected 'input SECONDS TEXT'".to_string(),
+            };
+            let rest = line.strip_prefix("input ").ok_or_else(parse_error)?;
+            let (time, input) = rest.split_once(' ').unwrap_or((rest, ""));
+            let seconds: f64 = time.parse().map_err(|_| parse_error())?;
+            if !seconds.is_finite() || seconds < 0.0 {
+                return Err(parse_error());
+            }
+            inputs.push((Duration::from_secs_f64(seconds), input.to_string()));
+        }
+
+        Ok(Replay {
+            started_at,
+            seed,
+            variant,
+            difficulty,
+            secret,
+            inputs,
+        })
+    }
+}
+
+// Records what is read through the wrapped console. Times are rounded to milliseconds, the precision the replay file keeps.
+pub struct Recorder<C: Console> {
+    inner: C,
+    started: Instant,
+    replay: Replay,
+}
+
+impl<C: Console> Recorder<C> {
+    pub fn new(inner: C, variant: &dyn Variant, difficulty: Difficulty, seed: u64) -> Recorder<C> {
+        let started_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|since| since.as_secs())
+            .unwrap_or(0);
+        Recorder {
+            inner,
+            started: Instant::now(),
+            replay: Replay {
+                started_at,
+                seed,
+                variant: variant.name().to_string(),
+                difficulty,
+                secret: variant.secret(),
+                inputs: Vec::new(),
+            },
+        }
+    }
+
+    pub fn into_replay(self) -> Replay {
+        self.replay
+    }
+}
+
+impl<C: Console> Console for Recorder<C> {
+    fn read_line(&mut self) -> io::Result<Option<String>> {
+        let line = self.inner.read_line()?;
+        if let Some(line) = &line {
+            let time = Duration::from_millis(self.started.elapsed().as_millis() as u64);
+            let line = line.trim_end_matches(['\n', '\r']).to_string();
+            self.replay.inputs.push((time, line));
+        }
+        Ok(line)
+    }
+
+    fn write_line(&mut self, line: &str) -> io::Result<()> {
+        self.inner.write_line(line)
+    }
+
+    fn prompt(&mut self, line: &str) -> io::Result<()> {
+        self.inner.prompt(line)
+    }
+
+    fn show(&mut self, frame: &Frame) -> io::Result<()> {
+        self.inner.show(frame)
+    }
+
+    fn elapsed(&self, _started: Instant) -> Duration {
+        let last = self.replay.inputs.last().map(|(time, _)| *time);
+        last.unwrap_or_else(|| Duration::from_millis(self.started.elapsed().as_millis() as u64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::{play, Game};
+
+    const RECORDED: &str = "\
+guessing_game replay 2
+started 1760812315
+seed 42
+variant number
+difficulty custom 1-100/5
+secret 14
+input 1.250 50
+input 2.000 fifty
+input 3.500 25
+input 4.125 12
+input 5.000 14
+";
+
+    #[test]
+    fn replay_file_round_trip() {
+        let replay: Replay = RECORDED.parse().unwrap();
+        assert_eq!(replay.seed, 42);
+        assert_eq!(
+            replay.inputs[1],
+            (Duration::from_millis(2000), "fifty".to_string())
+        );
+        assert_eq!(replay.to_string(), RECORDED);
+
+        assert!(matches!(
+            "guessing_game replay 3\n".parse::<Replay>(),
+            Err(ReplayError::Parse { line: 1, .. })
+        ));
+        let huge = RECORDED.replace("secret 14", "secret 4294967310");
+        assert!(matches!(
+            huge.parse::<Replay>(),
+            Err(ReplayError::Parse { line: 6, .. })
+        ));
+        let unknown = RECORDED.replace("variant number", "variant chess");
+        assert!(matches!(
+            unknown.parse::<Replay>(),
+            Err(ReplayError::Parse { line: 4, .. })
+        ));
+        let broken = RECORDED.replace("input 3.500 25", "guess 25");
+        assert!(matches!(
+            broken.parse::<Replay>(),
+            Err(ReplayError::Parse { line: 9, .. })
+        ));
+    }
+
+    #[test]
+    fn version_1_files_are_number_games() {
+        let old = RECORDED
+            .replace("replay 2", "replay 1")
+            .replace("variant number\n", "");
+        let replay: Replay = old.parse().unwrap();
+        assert_eq!(replay.variant, "number");
+        assert_eq!(replay.inputs.len(), 5);
+        assert_eq!(replay.to_string(), RECORDED);
+    }
+
+    #[test]
+    fn recorded_games_replay_with_the_same_output() {
+        let seed = 7;
+        for (name, inputs) in [
+            ("number", ["1", "oops", "100", "50"]),
+            ("word", ["e", "oops", "a", "o"]),
+            ("bulls-and-cows", ["1234", "1123", "5678", "9012"]),
+        ] {
+            let mut variant = variant_by_name(name, Difficulty::Normal, seed).unwrap();
+            let mut recorder = Recorder::new(
+                Scripted::new(inputs),
+                variant.as_ref(),
+                Difficulty::Normal,
+                seed,
+            );
+            play(variant.as_mut(), &mut recorder).unwrap();
+            let original = recorder.inner.output.clone();
+            let replay = recorder.into_replay();
+            assert_eq!(replay.variant, name);
+
+            let replay: Replay = replay.to_string().parse().unwrap();
+            let mut variant = replay.variant().unwrap();
+            let mut console = replay.console();
+            play(variant.as_mut(), &mut console).unwrap();
+            assert_eq!(console.output, original);
+        }
+    }
+
+    #[test]
+    fn fixed_replay_output() {
+        let replay: Replay = RECORDED.parse().unwrap();
+        let mut game = Game::new(replay.difficulty, replay.secret.parse().unwrap());
+        let mut console = replay.console();
+        let summary = play(&mut game, &mut console).unwrap();
+        assert_eq!(summary.attempts, 4);
+        assert_eq!(summary.elapsed, Duration::from_secs(5));
+        assert_eq!(
+            console.output.last().unwrap(),
+            "Attempts used: 4 in 5.0s. The secret number was 14."
+        );
+    }
+
+    #[test]
+    fn changed_generator_is_detected() {
+        let mut replay: Replay = RECORDED.parse().unwrap();
+        let secret = Game::seeded(replay.difficulty, replay.seed).secret();
+        replay.secret = (secret % 100 + 1).to_string();
+        assert!(matches!(
+            replay.variant(),
+            Err(ReplayError::SecretMismatch { .. })
+        ));
+    }
+}
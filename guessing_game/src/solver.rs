@@ -0,0 +1,387 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 05:56:38
+/// @Description: Solver Bot and Reverse Game
+/*
+- A Strategy picks the next guess from the range the secret number is still known to be in. The solver keeps that range up to date from the Too small / Too
+  big answers, so every strategy only has to decide where in the range to guess.
+    -> binary: the middle of the range. Every answer halves the range, so a range of n numbers needs at most ceil(log2(n + 1)) guesses.
+    -> random: anywhere in the range. About 2 ln n guesses on average and a long tail.
+    -> golden: the golden section point, 38.2% into the range instead of 50%. The smaller side comes up less often but the bigger side is bigger, on average
+       it loses to binary search.
+    -> adversarial: any guess that keeps the worst case of binary search, picked at random. A host who moves the secret number to wherever a predictable
+       strategy is slowest gains nothing against it, and it is never worse than binary search.
+- evaluate() lets a strategy play thousands of seeded games and reports the average and the worst number of attempts.
+- The reverse game turns the roles around: the player thinks of a number and the strategy guesses, the player answers with small, big or correct. Answers that
+  contradict each other are caught as soon as no number is left in the range.
+*/
+use crate::console::Console;
+use crate::difficulty::Difficulty;
+use crate::game::{Game, Outcome};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::fmt;
+use std::io;
+
+pub trait Strategy {
+    fn name(&self) -> &'static str;
+    // low <= high, both inclusive. The guess must be in that range.
+    fn next_guess(&mut self, low: u32, high: u32) -> u32;
+}
+
+pub struct BinarySearch;
+
+pub struct RandomGuess {
+    rng: StdRng,
+}
+
+pub struct GoldenSection;
+
+pub struct AdversarialAware {
+    rng: StdRng,
+}
+
+impl RandomGuess {
+    pub fn new(seed: u64) -> RandomGuess {
+        RandomGuess {
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+}
+
+impl AdversarialAware {
+    pub fn new(seed: u64) -> AdversarialAware {
+        AdversarialAware {
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+}
+
+impl Strategy for BinarySearch {
+    fn name(&self) -> &'static str {
+        "binary"
+    }
+
+    fn next_guess(&mut self, low: u32, high: u32) -> u32 {
+        low + (high - low) / 2
+    }
+}
+
+impl Strategy for RandomGuess {
+    fn name(&self) -> &'static str {
+        "random"
+    }
+
+    fn next_guess(&mut self, low: u32, high: u32) -> u32 {
+        self.rng.gen_range(low..=high)
+    }
+}
+
+impl Strategy for GoldenSection {
+    fn name(&self) -> &'static str {
+        "golden"
+    }
+
+    fn next_guess(&mut self, low: u32, high: u32) -> u32 {
+        const SECTION: f64 = 0.381_966_011_250_105; // 1 - 1/phi
+        low + ((high - low) as f64 * SECTION).round() as u32
+    }
+}
+
+// Guesses needed in the worst case for a range of `size` numbers with perfect halving.
+fn worst_case_guesses(size: u64) -> u32 {
+    u64::BITS - size.leading_zeros()
+}
+
+impl Strategy for AdversarialAware {
+    fn name(&self) -> &'static str {
+        "adversarial"
+    }
+
+    // After the guess each side must be solvable in one guess less than the whole range, i.e. hold at most 2^(k-1) - 1 numbers.
+    fn next_guess(&mut self, low: u32, high: u32) -> u32 {
+        let size = (high - low) as u64 + 1;
+        let side_limit = (1u64 << (worst_case_guesses(size) - 1)) - 1;
+        let earliest = low as u64 + size.saturating_sub(side_limit + 1);
+        let latest = low as u64 + side_limit.min(size - 1);
+        self.rng.gen_range(earliest..=latest) as u32
+    }
+}
+
+pub fn strategies(seed: u64) -> Vec<Box<dyn Strategy>> {
+    vec![
+        Box::new(BinarySearch),
+        Box::new(RandomGuess::new(seed)),
+        Box::new(GoldenSection),
+        Box::new(AdversarialAware::new(seed)),
+    ]
+}
+
+pub fn strategy_by_name(name: &str, seed: u64) -> Option<Box<dyn Strategy>> {
+    strategies(seed)
+        .into_iter()
+        .find(|strategy| strategy.name() == name)
+}
+
+// Plays the game with the strategy until it is over and returns the final outcome.
+pub fn solve<S: Strategy + ?Sized>(game: &mut Game, strategy: &mut S) -> Outcome {
+    let range = game.difficulty().range();
+    let (mut low, mut high) = (*range.start(), *range.end());
+    loop {
+        let guess = strategy.next_guess(low, high);
+        match game.guess_number(guess) {
+            Outcome::TooSmall => low = guess + 1,
+            Outcome::TooBig => high = guess - 1,
+            outcome => return outcome,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub strategy: &'static str,
+    pub games: u32,
+    pub wins: u32,
+    pub average_attempts: f64,
+    pub worst_attempts: u32,
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{:<12} {:>8} games {:>8} wins {:>8.3} average {:>4} worst",
+            self.strategy, self.games, self.wins, self.average_attempts, self.worst_attempts
+        )
+    }
+}
+
+// Game i uses seed + i, so two strategies evaluated with the same seed face the same secret numbers. The games are played without an attempt limit so
+// the averages are not cut off, a game counts as won if it took no more attempts than the difficulty allows.
+pub fn evaluate<S: Strategy + ?Sized>(
+    strategy: &mut S,
+    difficulty: Difficulty,
+    games: u32,
+    seed: u64,
+) -> Report {
+    let range = difficulty.range();
+    let unlimited = Difficulty::Custom {
+        low: *range.start(),
+        high: *range.end(),
+        max_attempts: u32::MAX,
+    };
+    let mut wins = 0;
+    let mut total_attempts = 0u64;
+    let mut worst_attempts = 0;
+    for i in 0..games {
+        let mut game = Game::seeded(unlimited, seed.wrapping_add(i as u64));
+        solve(&mut game, strategy);
+        wins += (game.attempts() <= difficulty.max_attempts()) as u32;
+        total_attempts += game.attempts() as u64;
+        worst_attempts = worst_attempts.max(game.attempts());
+    }
+    Report {
+        strategy: strategy.name(),
+        games,
+        wins,
+        average_attempts: if games == 0 {
+            0.0
+        } else {
+            total_attempts as f64 / games as f64
+        },
+        worst_attempts,
+    }
+}
+
+//------------------------------------------------------------Reverse Game----------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Answer {
+    TooSmall,
+    TooBig,
+    Correct,
+}
+
+impl Answer {
+    pub fn parse(input: &str) -> Option<Answer> {
+        match input.trim().to_lowercase().as_str() {
+            "s" | "small" | "too small" | "<" => Some(Answer::TooSmall),
+            "b" | "big" | "too big" | ">" => Some(Answer::TooBig),
+            "c" | "correct" | "y" | "yes" | "=" => Some(Answer::Correct),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReverseResult {
+    Found { number: u32, attempts: u32 },
+    Contradiction { attempts: u32 },
+    OutOfAttempts,
+    Abandoned,
+}
+
+pub fn play_reverse<C: Console, S: Strategy + ?Sized>(
+    difficulty: Difficulty,
+    strategy: &mut S,
+    console: &mut C,
+) -> io::Result<ReverseResult> {
+    let range = difficulty.range();
+    let (mut low, mut high) = (*range.start(), *range.end());
+    console.write_line(&format!(
+        "Think of a number between {} and {}. I have {} attempts.",
+        low,
+        high,
+        difficulty.max_attempts()
+    ))?;
+    console.write_line("Answer with small (my guess is too small), big (too big) or correct.")?;
+
+    let mut attempts = 0;
+    while attempts < difficulty.max_attempts() {
+        let guess = strategy.next_guess(low, high);
+        attempts += 1;
+        console.write_line(&format!("My guess: {}", guess))?;
+
+        let answer = loop {
+            let line = match console.read_line()? {
+                Some(line) => line,
+                None => return Ok(ReverseResult::Abandoned),
+            };
+            match Answer::parse(&line) {
+                Some(answer) => break answer,
+                None => console.write_line("Please answer small, big or correct.")?,
+            }
+        };
+
+        match answer {
+            Answer::Correct => {
+                console.write_line(&format!("Got it in {} attempts!", attempts))?;
+                return Ok(ReverseResult::Found {
+                    number: guess,
+                    attempts,
+                });
+            }
+            Answer::TooSmall if guess == high => {
+                console.write_line("That contradicts your earlier answers, no number is left.")?;
+                return Ok(ReverseResult::Contradiction { attempts });
+            }
+            Answer::TooBig if guess == low => {
+                console.write_line("That contradicts your earlier answers, no number is left.")?;
+                return Ok(ReverseResult::Contradiction { attempts });
+            }
+            Answer::TooSmall => low = guess + 1,
+            Answer::TooBig => high = guess - 1,
+        }
+    }
+    console.write_line("I ran out of attempts, you win!")?;
+    Ok(ReverseResult::OutOfAttempts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::console::Scripted;
+
+    fn every_secret_worst_case(strategy: &mut dyn Strategy, low: u32, high: u32) -> u32 {
+        let difficulty = Difficulty::Custom {
+            low,
+            high,
+            max_attempts: 1000,
+        };
+        (low..=high)
+            .map(|secret| {
+                let mut game = Game::new(difficulty, secret);
+                assert_eq!(solve(&mut game, strategy), Outcome::Won);
+                game.attempts()
+            })
+            .max()
+            .unwrap()
+    }
+
+    #[test]
+    fn binary_and_adversarial_are_optimal_in_the_worst_case() {
+        for (low, high) in [(1, 100), (1, 1000), (5, 6), (1, 1024), (7, 7 + 126)] {
+            let optimal = worst_case_guesses((high - low + 1) as u64);
+            assert_eq!(
+                every_secret_worst_case(&mut BinarySearch, low, high),
+                optimal
+            );
+            assert_eq!(
+                every_secret_worst_case(&mut AdversarialAware::new(3), low, high),
+                optimal
+            );
+        }
+    }
+
+    #[test]
+    fn guesses_stay_in_range() {
+        for strategy in strategies(11).iter_mut() {
+            for (low, high) in [(1, 1), (1, 2), (10, 20), (0, u32::MAX)] {
+                for _ in 0..50 {
+                    let guess = strategy.next_guess(low, high);
+                    assert!(
+                        (low..=high).contains(&guess),
+                        "{} guessed {} in {}..={}",
+                        strategy.name(),
+                        guess,
+                        low,
+                        high
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn evaluation_report() {
+        let binary = evaluate(&mut BinarySearch, Difficulty::Hard, 2000, 1);
+        assert_eq!(binary.wins, 2000);
+        assert_eq!(binary.worst_attempts, 10);
+        let golden = evaluate(&mut GoldenSection, Difficulty::Hard, 2000, 1);
+        assert!(golden.average_attempts > binary.average_attempts);
+        assert!(golden.worst_attempts > binary.worst_attempts);
+        let random = evaluate(&mut RandomGuess::new(5), Difficulty::Hard, 2000, 1);
+        assert!(random.wins < 2000);
+        assert!(random.worst_attempts > 10);
+    }
+
+    #[test]
+    fn reverse_game() {
+        // The player thinks of 71 in 1..=100.
+        let mut console = Scripted::new(["small", "what?", "b", "s", "s", "correct"]);
+        let result = play_reverse(Difficulty::Normal, &mut BinarySearch, &mut console).unwrap();
+        assert_eq!(
+            result,
+            ReverseResult::Found {
+                number: 71,
+                attempts: 5
+            }
+        );
+        let guesses: Vec<&String> = console
+            .output
+            .iter()
+            .filter(|line| line.starts_with("My guess"))
+            .collect();
+        assert_eq!(
+            guesses,
+            [
+                "My guess: 50",
+                "My guess: 75",
+                "My guess: 62",
+                "My guess: 68",
+                "My guess: 71"
+            ]
+        );
+    }
+
+    #[test]
+    fn reverse_game_catches_contradictions() {
+        let difficulty = Difficulty::Custom {
+            low: 1,
+            high: 3,
+            max_attempts: 5,
+        };
+        let mut console = Scripted::new(["big", "big"]);
+        let result = play_reverse(difficulty, &mut BinarySearch, &mut console).unwrap();
+        assert_eq!(result, ReverseResult::Contradiction { attempts: 2 });
+    }
+}
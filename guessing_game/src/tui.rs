@@ -0,0 +1,319 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 06:00:12
+/// @Description: Full-Screen Terminal UI
+/*
+- Tui is a Console that redraws the whole screen with ANSI escape codes every time the game or the messages change. The input is still read a line at a time,
+  so it works on any terminal that understands the escape codes and needs no raw mode.
+- The screen shows the title of the game, a timer and the attempts left, the board of the variant and the last few messages of the game, with the prompt
+  at the bottom. The board of the number game is a bar of the range with the part the secret number can still be in and the guesses so far with their
+  hints, the other variants bring their own.
+- A background thread updates the timer line once a second while the game is running. It saves and restores the cursor around the update, so it does not
+  get in the way of typing.
+- render() only builds the lines of the screen, so the layout can be tested without a terminal.
+*/
+use crate::console::{Console, Frame};
+use crate::difficulty::Difficulty;
+use crate::game::{Game, State};
+use std::cmp::Ordering;
+use std::io::{self, BufRead, Write};
+use std::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+const BAR_WIDTH: usize = 50;
+const HISTORY_LINES: usize = 10;
+const MESSAGE_LINES: usize = 3;
+// The timer is on the second line of the screen.
+const TIMER_ROW: usize = 2;
+
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+const SAVE_CURSOR: &str = "\x1b7";
+const RESTORE_CURSOR: &str = "\x1b8";
+const CLEAR_LINE: &str = "\x1b[2K";
+
+// What the player knows about the game, taken from a Game without giving away the secret number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct View {
+    pub difficulty: Difficulty,
+    // Every guess with how it compares to the secret number.
+    pub guesses: Vec<(u32, Ordering)>,
+    pub attempts_left: u32,
+    pub state: State,
+    // Where the secret number can still be after the hints so far.
+    pub low: u32,
+    pub high: u32,
+}
+
+impl View {
+    pub fn of(game: &Game) -> View {
+        let range = game.difficulty().range();
+        let (mut low, mut high) = (*range.start(), *range.end());
+        let guesses: Vec<(u32, Ordering)> = game
+            .guesses()
+            .iter()
+            .map(|&guess| (guess, guess.cmp(&game.secret())))
+            .collect();
+        for &(guess, ordering) in &guesses {
+            match ordering {
+                Ordering::Less => low = low.max(guess + 1),
+                Ordering::Greater => high = high.min(guess - 1),
+                Ordering::Equal => (low, high) = (guess, guess),
+            }
+        }
+        View {
+            difficulty: game.difficulty(),
+            guesses,
+            attempts_left: game.attempts_left(),
+            state: game.state(),
+            low,
+            high,
+        }
+    }
+}
+
+fn status_line(frame: &Frame, elapsed: Duration) -> String {
+    let seconds = elapsed.as_secs();
+    format!(
+        "Time {}:{:02}    Attempts left {}/{}",
+        seconds / 60,
+        seconds % 60,
+        frame.attempts_left,
+        frame.max_attempts
+    )
+}
+
+// '#' for the cells that can still hold the secret number, '.' for the ruled out ones. A cell covers a slice of the range, it is '#' if any number of
+// its slice is still possible, so a single possible number always shows.
+fn range_bar(view: &View) -> String {
+    let range = view.difficulty.range();
+    let (start, end) = (*range.start() as u64, *range.end() as u64);
+    let size = end - start + 1;
+    let cells = (size as usize).min(BAR_WIDTH);
+    let bar: String = (0..cells as u64)
+        .map(|cell| {
+            let first = start + cell * size / cells as u64;
+            let last = start + (cell + 1) * size / cells as u64 - 1;
+            if first <= view.high as u64 && last >= view.low as u64 {
+                '#'
+            } else {
+                '.'
+            }
+        })
+        .collect();
+    format!("{} [{}] {}", start, bar, end)
+}
+
+// The board of the number game.
+pub fn number_board(game: &Game) -> Vec<String> {
+    let view = View::of(game);
+    let mut lines = vec![
+        range_bar(&view),
+        match view.state {
+            State::Won => format!("Found: {}", view.low),
+            _ if view.low > view.high => "No number is left".to_string(),
+            _ => format!("Still possible: {} to {}", view.low, view.high),
+        },
+        String::new(),
+        "Guesses:".to_string(),
+    ];
+    let skipped = view.guesses.len().saturating_sub(HISTORY_LINES);
+    for (i, (guess, ordering)) in view.guesses.iter().enumerate().skip(skipped) {
+        let hint = match ordering {
+            Ordering::Less => "too small",
+            Ordering::Greater => "too big",
+            Ordering::Equal => "correct",
+        };
+        lines.push(format!("{:>3}. {:>10}  {}", i + 1, guess, hint));
+    }
+    lines
+}
+
+pub fn render(frame: &Frame, elapsed: Duration, messages: &[String]) -> Vec<String> {
+    let mut lines = vec![
+        frame.title.clone(),
+        status_line(frame, elapsed),
+        String::new(),
+    ];
+    lines.extend(frame.board.iter().cloned());
+    lines.push(String::new());
+    let skipped = messages.len().saturating_sub(MESSAGE_LINES);
+    lines.extend(messages[skipped..].iter().cloned());
+    lines
+}
+
+struct Screen {
+    frame: Option<Frame>,
+    messages: Vec<String>,
+    started: Instant,
+    input_closed: bool,
+}
+
+impl Screen {
+    fn draw(&self) -> io::Result<()> {
+        let frame = match &self.frame {
+            Some(frame) => frame,
+            None => return Ok(()),
+        };
+        let mut stdout = io::stdout().lock();
+        write!(stdout, "{}", CLEAR_SCREEN)?;
+        for line in render(frame, self.started.elapsed(), &self.messages) {
+            writeln!(stdout, "{}", line)?;
+        }
+        if frame.state == State::Playing && !self.input_closed {
+            write!(stdout, "> ")?;
+        }
+        stdout.flush()
+    }
+
+    fn draw_timer(&self) -> io::Result<()> {
+        let frame = match &self.frame {
+            Some(frame) if frame.state == State::Playing && !self.input_closed => frame,
+            _ => return Ok(()),
+        };
+        let mut stdout = io::stdout().lock();
+        write!(
+            stdout,
+            "{}\x1b[{};1H{}{}{}",
+            SAVE_CURSOR,
+            TIMER_ROW,
+            CLEAR_LINE,
+            status_line(frame, self.started.elapsed()),
+            RESTORE_CURSOR
+        )?;
+        stdout.flush()
+    }
+}
+
+pub struct Tui {
+    screen: Arc<Mutex<Screen>>,
+    stopped: Arc<AtomicBool>,
+}
+
+impl Tui {
+    pub fn new() -> Tui {
+        let screen = Arc::new(Mutex::new(Screen {
+            frame: None,
+            messages: Vec::new(),
+            started: Instant::now(),
+            input_closed: false,
+        }));
+        let stopped = Arc::new(AtomicBool::new(false));
+        let (ticking, stop) = (Arc::clone(&screen), Arc::clone(&stopped));
+        thread::spawn(move || {
+            while !stop.load(AtomicOrdering::Relaxed) {
+                thread::sleep(Duration::from_secs(1));
+                if ticking.lock().unwrap().draw_timer().is_err() {
+                    break;
+                }
+            }
+        });
+        Tui { screen, stopped }
+    }
+}
+
+impl Default for Tui {
+    fn default() -> Tui {
+        Tui::new()
+    }
+}
+
+impl Drop for Tui {
+    fn drop(&mut self) {
+        self.stopped.store(true, AtomicOrdering::Relaxed);
+    }
+}
+
+impl Console for Tui {
+    fn read_line(&mut self) -> io::Result<Option<String>> {
+        let mut line = String::new();
+        match io::stdin().lock().read_line(&mut line)? {
+            0 => {
+                self.screen.lock().unwrap().input_closed = true;
+                Ok(None)
+            }
+            _ => Ok(Some(line)),
+        }
+    }
+
+    fn write_line(&mut self, line: &str) -> io::Result<()> {
+        let mut screen = self.screen.lock().unwrap();
+        screen.messages.push(line.to_string());
+        screen.draw()
+    }
+
+    // The screen has its own prompt and shows the attempts left.
+    fn prompt(&mut self, _line: &str) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn show(&mut self, frame: &Frame) -> io::Result<()> {
+        let mut screen = self.screen.lock().unwrap();
+        screen.frame = Some(frame.clone());
+        screen.draw()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn view_narrows_the_range() {
+        let mut game = Game::new(Difficulty::Normal, 42);
+        game.guess_number(50);
+        game.guess_number(20);
+        game.guess_number(45);
+        let view = View::of(&game);
+        assert_eq!((view.low, view.high), (21, 44));
+        assert_eq!(
+            view.guesses,
+            [
+                (50, Ordering::Greater),
+                (20, Ordering::Less),
+                (45, Ordering::Greater)
+            ]
+        );
+        assert_eq!(view.attempts_left, 5);
+    }
+
+    #[test]
+    fn rendered_screen() {
+        let mut game = Game::new(Difficulty::Easy, 30);
+        game.guess_number(26);
+        let frame = Frame {
+            title: "Guess the number! easy (1 to 50, 10 attempts)".to_string(),
+            attempts_left: game.attempts_left(),
+            max_attempts: 10,
+            state: game.state(),
+            board: number_board(&game),
+        };
+        let messages = ["Too small!".to_string()];
+        let lines = render(&frame, Duration::from_secs(75), &messages);
+        assert_eq!(
+            lines,
+            [
+                "Guess the number! easy (1 to 50, 10 attempts)",
+                "Time 1:15    Attempts left 9/10",
+                "",
+                "1 [..........................########################] 50",
+                "Still possible: 27 to 50",
+                "",
+                "Guesses:",
+                "  1.         26  too small",
+                "",
+                "Too small!",
+            ]
+        );
+    }
+
+    #[test]
+    fn bar_keeps_a_single_number_visible() {
+        let mut game = Game::new(Difficulty::Hard, 501);
+        game.guess_number(500);
+        game.guess_number(502);
+        let bar = range_bar(&View::of(&game));
+        assert_eq!(bar.matches('#').count(), 1);
+        assert_eq!(bar.len(), "1 [] 1000".len() + BAR_WIDTH);
+    }
+}
@@ -0,0 +1,532 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 06:02:01
+/// @Description: Game Variants
+/*
+- A Variant only knows its secret and how to judge a guess, play() of the game module runs the same loop for all of them: attempts, invalid input, winning
+  and losing, the summary and the replay. The Summary it returns goes on the leaderboard under the key of the variant.
+- Every guess is judged with a Judgement:
+    -> Solved: the guess was the secret.
+    -> Hint: a wrong guess with what it tells about the secret, it uses up an attempt.
+    -> Invalid: the guess does not fit the variant, it does not use up an attempt.
+- The variants:
+    -> number: the classic game, Game itself. The Ordering of the guess and the secret number gives Too small or Too big.
+    -> hot-cold: a number again, but the hint is how close the guess is and whether it is closer than the guess before. The Ordering of the two distances
+       decides warmer or colder. It plays a Game underneath for the range and the parsing of the guesses.
+    -> bulls-and-cows: a code of 4 different digits. A bull is a right digit in the right place, a cow a right digit in the wrong place.
+    -> word: a word guessed one letter at a time, or all at once. A letter that was tried already does not use up an attempt.
+- The number variants use the range and attempts of the difficulty, bulls-and-cows and word have fixed rules, main.rs turns a difficulty away for them.
+  The secret comes from a seed, so a seed and the name of the variant are enough to play a recorded game again.
+*/
+use crate::difficulty::Difficulty;
+use crate::game::{Game, Outcome};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+
+pub const VARIANTS: [&str; 4] = ["number", "hot-cold", "bulls-and-cows", "word"];
+
+const CODE_LENGTH: usize = 4;
+const CODE_ATTEMPTS: u32 = 10;
+// Wrong letters the word game allows on top of the letters of the word.
+const WORD_SPARE_ATTEMPTS: u32 = 6;
+const WORDS: [&str; 24] = [
+    "ownership",
+    "borrow",
+    "lifetime",
+    "trait",
+    "closure",
+    "iterator",
+    "module",
+    "crate",
+    "struct",
+    "vector",
+    "string",
+    "pattern",
+    "thread",
+    "mutex",
+    "channel",
+    "generic",
+    "macro",
+    "option",
+    "result",
+    "slice",
+    "reference",
+    "compiler",
+    "cargo",
+    "enum",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Judgement {
+    Solved,
+    Hint(String),
+    Invalid(String),
+}
+
+pub trait Variant {
+    fn name(&self) -> &'static str;
+    // The leaderboard key, only games with the same key compete.
+    fn key(&self) -> String;
+    fn introduction(&self) -> String;
+    fn max_attempts(&self) -> u32;
+    fn secret(&self) -> String;
+    // What the secret is called in the summary.
+    fn secret_kind(&self) -> &'static str {
+        "number"
+    }
+    fn judge(&mut self, guess: &str) -> Judgement;
+    // A line shown before the hint of a counted guess.
+    fn echo(&self) -> Option<String> {
+        None
+    }
+    // What the player found out so far, drawn by full-screen consoles.
+    fn board(&self) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+pub fn variant_by_name(name: &str, difficulty: Difficulty, seed: u64) -> Option<Box<dyn Variant>> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let variant: Box<dyn Variant> = match name {
+        "number" => Box::new(Game::random(difficulty, &mut rng)),
+        "hot-cold" => Box::new(HotCold::new(Game::random(difficulty, &mut rng))),
+        "bulls-and-cows" => {
+            let mut digits: Vec<u8> = (0..10).collect();
+            digits.shuffle(&mut rng);
+            Box::new(BullsAndCows::new(&digits[..CODE_LENGTH]))
+        }
+        "word" => Box::new(Word::new(WORDS.choose(&mut rng).unwrap())),
+        _ => return None,
+    };
+    Some(variant)
+}
+
+//------------------------------------------------------------Hot and Cold----------------------------------------------------------
+
+pub struct HotCold {
+    game: Game,
+    last_distance: Option<u32>,
+    history: Vec<(u32, String)>,
+}
+
+impl HotCold {
+    pub fn new(game: Game) -> HotCold {
+        HotCold {
+            game,
+            last_distance: None,
+            history: Vec::new(),
+        }
+    }
+
+    // How close a distance is, measured against the size of the range.
+    fn temperature(&self, distance: u32) -> &'static str {
+        let range = self.game.difficulty().range();
+        let percent = distance as u64 * 100 / (*range.end() - *range.start()).max(1) as u64;
+        match percent {
+            0..=2 => "Boiling",
+            3..=5 => "Hot",
+            6..=15 => "Warm",
+            16..=35 => "Cold",
+            _ => "Freezing",
+        }
+    }
+}
+
+impl Variant for HotCold {
+    fn name(&self) -> &'static str {
+        "hot-cold"
+    }
+
+    fn key(&self) -> String {
+        format!("hot-cold {}", self.game.difficulty().key())
+    }
+
+    fn introduction(&self) -> String {
+        format!(
+            "Find the number by how hot or cold your guesses are! Difficulty: {}",
+            self.game.difficulty()
+        )
+    }
+
+    fn max_attempts(&self) -> u32 {
+        self.game.difficulty().max_attempts()
+    }
+
+    fn secret(&self) -> String {
+        self.game.secret().to_string()
+    }
+
+    fn judge(&mut self, guess: &str) -> Judgement {
+        match self.game.guess(guess) {
+            Outcome::InvalidInput(invalid) => {
+                return Judgement::Invalid(self.game.explain(&invalid))
+            }
+            Outcome::Won => return Judgement::Solved,
+            _ => {}
+        }
+        let number = *self.game.guesses().last().expect("the guess was counted");
+        let distance = number.abs_diff(self.game.secret());
+        let temperature = self.temperature(distance);
+        let hint = match self.last_distance.map(|last| distance.cmp(&last)) {
+            None => format!("{}!", temperature),
+            Some(Ordering::Less) => format!("{}, warmer than before!", temperature),
+            Some(Ordering::Greater) => format!("{}, colder than before!", temperature),
+            Some(Ordering::Equal) => format!("{}, as close as before!", temperature),
+        };
+        self.last_distance = Some(distance);
+        self.history.push((number, hint.clone()));
+        Judgement::Hint(hint)
+    }
+
+    fn board(&self) -> Vec<String> {
+        guess_list(
+            self.history
+                .iter()
+                .map(|(number, hint)| (number.to_string(), hint.as_str())),
+        )
+    }
+}
+
+fn guess_list<'a, I: Iterator<Item = (String, &'a str)>>(guesses: I) -> Vec<String> {
+    let mut lines = vec!["Guesses:".to_string()];
+    for (i, (guess, hint)) in guesses.enumerate() {
+        lines.push(format!("{:>3}. {:>10}  {}", i + 1, guess, hint));
+    }
+    lines
+}
+
+//------------------------------------------------------------Bulls and Cows----------------------------------------------------------
+
+pub struct BullsAndCows {
+    code: Vec<u8>,
+    history: Vec<(String, String)>,
+}
+
+impl BullsAndCows {
+    pub fn new(code: &[u8]) -> BullsAndCows {
+        assert!(
+            valid_code(code),
+            "the code must be {} different digits",
+            CODE_LENGTH
+        );
+        BullsAndCows {
+            code: code.to_vec(),
+            history: Vec::new(),
+        }
+    }
+
+    pub fn score(&self, guess: &[u8]) -> (usize, usize) {
+        let bulls = guess.iter().zip(&self.code).filter(|(a, b)| a == b).count();
+        let common = guess
+            .iter()
+            .filter(|digit| self.code.contains(digit))
+            .count();
+        (bulls, common - bulls)
+    }
+}
+
+fn valid_code(code: &[u8]) -> bool {
+    code.len() == CODE_LENGTH
+        && code.iter().all(|&digit| digit < 10)
+        && code.iter().collect::<BTreeSet<_>>().len() == CODE_LENGTH
+}
+
+fn plural(count: usize, word: &str) -> String {
+    format!("{} {}{}", count, word, if count == 1 { "" } else { "s" })
+}
+
+impl Variant for BullsAndCows {
+    fn name(&self) -> &'static str {
+        "bulls-and-cows"
+    }
+
+    fn key(&self) -> String {
+        "bulls-and-cows".to_string()
+    }
+
+    fn introduction(&self) -> String {
+        format!("Guess the code of {} different digits! Bulls are right digits in the right place, cows right digits in the wrong place.", CODE_LENGTH)
+    }
+
+    fn max_attempts(&self) -> u32 {
+        CODE_ATTEMPTS
+    }
+
+    fn secret_kind(&self) -> &'static str {
+        "code"
+    }
+
+    fn secret(&self) -> String {
+        self.code
+            .iter()
+            .map(|digit| char::from(b'0' + digit))
+            .collect()
+    }
+
+    fn judge(&mut self, guess: &str) -> Judgement {
+        let digits: Option<Vec<u8>> = guess
+            .chars()
+            .map(|c| c.to_digit(10).map(|digit| digit as u8))
+            .collect();
+        let digits = match digits {
+            Some(digits) if valid_code(&digits) => digits,
+            _ => {
+                return Judgement::Invalid(format!(
+                    "'{}' is not {} different digits.",
+                    guess, CODE_LENGTH
+                ))
+            }
+        };
+        let (bulls, cows) = match self.score(&digits) {
+            (CODE_LENGTH, _) => return Judgement::Solved,
+            score => score,
+        };
+        let hint = format!("{}, {}.", plural(bulls, "bull"), plural(cows, "cow"));
+        self.history.push((guess.to_string(), hint.clone()));
+        Judgement::Hint(hint)
+    }
+
+    fn board(&self) -> Vec<String> {
+        guess_list(
+            self.history
+                .iter()
+                .map(|(guess, hint)| (guess.clone(), hint.as_str())),
+        )
+    }
+}
+
+//------------------------------------------------------------Word----------------------------------------------------------
+
+pub struct Word {
+    word: String,
+    tried: BTreeSet<char>,
+}
+
+impl Word {
+    pub fn new(word: &str) -> Word {
+        assert!(
+            !word.is_empty() && word.chars().all(|c| c.is_ascii_lowercase()),
+            "the word must be lowercase letters"
+        );
+        Word {
+            word: word.to_string(),
+            tried: BTreeSet::new(),
+        }
+    }
+
+    // The word with the letters not found yet as '_'.
+    pub fn pattern(&self) -> String {
+        let letters: Vec<String> = self
+            .word
+            .chars()
+            .map(|c| {
+                if self.tried.contains(&c) {
+                    c.to_string()
+                } else {
+                    "_".to_string()
+                }
+            })
+            .collect();
+        letters.join(" ")
+    }
+
+    fn distinct_letters(&self) -> u32 {
+        self.word.chars().collect::<BTreeSet<_>>().len() as u32
+    }
+}
+
+impl Variant for Word {
+    fn name(&self) -> &'static str {
+        "word"
+    }
+
+    fn key(&self) -> String {
+        "word".to_string()
+    }
+
+    fn introduction(&self) -> String {
+        format!(
+            "Guess the word of {} letters, one letter at a time or the whole word!",
+            self.word.len()
+        )
+    }
+
+    fn max_attempts(&self) -> u32 {
+        self.distinct_letters() + WORD_SPARE_ATTEMPTS
+    }
+
+    fn secret(&self) -> String {
+        self.word.clone()
+    }
+
+    fn secret_kind(&self) -> &'static str {
+        "word"
+    }
+
+    fn judge(&mut self, guess: &str) -> Judgement {
+        let guess = guess.to_lowercase();
+        if guess == self.word {
+            return Judgement::Solved;
+        }
+        let mut chars = guess.chars();
+        let letter = match (chars.next(), chars.next()) {
+            (Some(letter), None) if letter.is_ascii_lowercase() => letter,
+            _ if guess.len() == self.word.len()
+                && guess.chars().all(|c| c.is_ascii_lowercase()) =>
+            {
+                return Judgement::Hint(format!("That is not the word. {}", self.pattern()))
+            }
+            _ => {
+                return Judgement::Invalid(format!(
+                    "'{}' is not a letter or a word of {} letters.",
+                    guess,
+                    self.word.len()
+                ))
+            }
+        };
+        if !self.tried.insert(letter) {
+            return Judgement::Invalid(format!(
+                "You tried '{}' already. {}",
+                letter,
+                self.pattern()
+            ));
+        }
+        if self.word.chars().all(|c| self.tried.contains(&c)) {
+            return Judgement::Solved;
+        }
+        let found = if self.word.contains(letter) {
+            "Yes"
+        } else {
+            "No"
+        };
+        Judgement::Hint(format!("{}, '{}'. {}", found, letter, self.pattern()))
+    }
+
+    fn board(&self) -> Vec<String> {
+        let tried: String = self.tried.iter().collect();
+        vec![
+            format!("Word:  {}", self.pattern()),
+            format!("Tried: {}", tried),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::console::Scripted;
+    use crate::game::{play, State};
+
+    #[test]
+    fn number_is_the_classic_game() {
+        let mut variant = variant_by_name("number", Difficulty::Normal, 5).unwrap();
+        let secret = Game::seeded(Difficulty::Normal, 5).secret();
+        assert_eq!(variant.secret(), secret.to_string());
+        let mut console = Scripted::new(["x".to_string(), secret.to_string()]);
+        let summary = play(variant.as_mut(), &mut console).unwrap();
+        assert_eq!(summary.state, State::Won);
+        assert_eq!(summary.attempts, 1);
+        assert_eq!(
+            console.output[4..6],
+            [format!("You guessed: {}", secret), "You win!".to_string()]
+        );
+    }
+
+    #[test]
+    fn hot_and_cold() {
+        let mut variant = HotCold::new(Game::new(Difficulty::Hard, 500));
+        assert_eq!(variant.judge("1"), Judgement::Hint("Freezing!".to_string()));
+        assert_eq!(
+            variant.judge("400"),
+            Judgement::Hint("Warm, warmer than before!".to_string())
+        );
+        assert_eq!(
+            variant.judge("510"),
+            Judgement::Hint("Boiling, warmer than before!".to_string())
+        );
+        assert_eq!(
+            variant.judge("490"),
+            Judgement::Hint("Boiling, as close as before!".to_string())
+        );
+        assert_eq!(
+            variant.judge("700"),
+            Judgement::Hint("Cold, colder than before!".to_string())
+        );
+        assert_eq!(variant.judge("500"), Judgement::Solved);
+    }
+
+    #[test]
+    fn bulls_and_cows() {
+        let mut variant = BullsAndCows::new(&[1, 2, 3, 4]);
+        assert_eq!(variant.score(&[1, 2, 3, 4]), (4, 0));
+        assert_eq!(variant.score(&[4, 3, 2, 1]), (0, 4));
+        assert_eq!(variant.score(&[1, 5, 4, 6]), (1, 1));
+        assert_eq!(
+            variant.judge("5678"),
+            Judgement::Hint("0 bulls, 0 cows.".to_string())
+        );
+        assert_eq!(
+            variant.judge("1243"),
+            Judgement::Hint("2 bulls, 2 cows.".to_string())
+        );
+        assert!(matches!(variant.judge("1123"), Judgement::Invalid(_)));
+        assert!(matches!(variant.judge("12a4"), Judgement::Invalid(_)));
+        assert_eq!(variant.judge("1234"), Judgement::Solved);
+        assert_eq!(variant.secret(), "1234");
+    }
+
+    #[test]
+    fn word_letter_by_letter() {
+        let mut variant = Word::new("cargo");
+        assert_eq!(variant.max_attempts(), 11);
+        assert_eq!(
+            variant.judge("a"),
+            Judgement::Hint("Yes, 'a'. _ a _ _ _".to_string())
+        );
+        assert_eq!(
+            variant.judge("z"),
+            Judgement::Hint("No, 'z'. _ a _ _ _".to_string())
+        );
+        assert!(matches!(variant.judge("A"), Judgement::Invalid(_)));
+        assert!(matches!(variant.judge("ab"), Judgement::Invalid(_)));
+        assert_eq!(
+            variant.judge("cargs"),
+            Judgement::Hint("That is not the word. _ a _ _ _".to_string())
+        );
+        for letter in ["c", "r", "g"] {
+            assert!(matches!(variant.judge(letter), Judgement::Hint(_)));
+        }
+        assert_eq!(variant.judge("o"), Judgement::Solved);
+    }
+
+    #[test]
+    fn running_out_of_attempts() {
+        let mut variant = BullsAndCows::new(&[9, 8, 7, 6]);
+        let guesses = vec!["0123"; CODE_ATTEMPTS as usize];
+        let mut console = Scripted::new(guesses);
+        let summary = play(&mut variant, &mut console).unwrap();
+        assert_eq!(summary.state, State::Lost);
+        assert_eq!(summary.attempts, CODE_ATTEMPTS);
+        assert!(console.output.contains(&"You lose!".to_string()));
+        assert!(console
+            .output
+            .last()
+            .unwrap()
+            .ends_with("The secret code was 9876."));
+    }
+
+    #[test]
+    fn every_variant_by_name() {
+        for name in VARIANTS {
+            let variant = variant_by_name(name, Difficulty::Easy, 3).unwrap();
+            assert_eq!(variant.name(), name);
+            assert_eq!(
+                variant_by_name(name, Difficulty::Easy, 3).unwrap().secret(),
+                variant.secret()
+            );
+        }
+        assert!(variant_by_name("chess", Difficulty::Easy, 3).is_none());
+    }
+}
@@ -0,0 +1,232 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 06:21:21
+/// @Description: Plugin ABI of managin_growing_projects
+/*
+- A plugin is a shared library (crate-type = ["cdylib"]) that exports one function, managin_plugin_declaration, returning a pointer to a static
+  PluginDeclaration. Everything the host and the plugin exchange is #[repr(C)] and extern "C", so the plugin does not need the compiler, or even the
+  language, the host was built with.
+- The ABI is versioned. abi_version is the first field of the declaration and stays the first field in every version, so the host can read it before
+  anything else and turn away a plugin built for another version instead of misreading it. ABI_VERSION goes up whenever a #[repr(C)] type here changes.
+- capabilities says what the plugin offers and needs, as bits:
+    -> COMMANDS: it has commands for the registry.
+    -> READS_INPUT: its commands get the input of the tool, the host reads it for them.
+  A bit the host does not know means the plugin needs something the host cannot give, and it is rejected.
+- A command is called with a Call: the arguments and three callbacks into the host, one to read the input, one to write output and one to say what went
+  wrong. It returns one of the STATUS codes.
+- The input is pulled by the plugin, a piece at a time and only when it wants it, so a command that does not need it does not wait for stdin, and one
+  that handles it line by line does not hold all of it in memory. Version 1 handed every command the whole input up front.
+- A panic must not unwind into the host, that is undefined behaviour across extern "C". run_guarded() catches it and hands the plugin an Invocation with
+  safe methods, every command function of a plugin should be a call to it.
+*/
+use std::ffi::{c_char, c_void, CStr};
+use std::io::{self, Read};
+use std::panic::{self, AssertUnwindSafe};
+
+pub const ABI_VERSION: u32 = 2;
+pub const ENTRY_SYMBOL: &CStr = c"managin_plugin_declaration";
+
+pub const COMMANDS: u32 = 1 << 0;
+pub const READS_INPUT: u32 = 1 << 1;
+pub const KNOWN_CAPABILITIES: u32 = COMMANDS | READS_INPUT;
+
+pub const STATUS_OK: i32 = 0;
+pub const STATUS_FAILED: i32 = 1;
+pub const STATUS_USAGE: i32 = 2;
+pub const STATUS_PANICKED: i32 = 3;
+
+pub type EntryFn = extern "C" fn() -> *const PluginDeclaration;
+// A reference is a pointer that is never null in the C ABI.
+pub type RunFn = extern "C" fn(call: &Call) -> i32;
+// Reads up to capacity bytes of the input into data. Returns how many, 0 at the end of the input and -1 when reading failed.
+pub type ReadFn = extern "C" fn(context: *mut c_void, data: *mut u8, capacity: usize) -> isize;
+// Both take the context of the Call and a UTF-8 text that does not need to end with a 0.
+pub type WriteFn = extern "C" fn(context: *mut c_void, data: *const u8, len: usize) -> i32;
+pub type FailFn = extern "C" fn(context: *mut c_void, data: *const u8, len: usize);
+
+#[repr(C)]
+pub struct PluginDeclaration {
+    pub abi_version: u32,
+    pub capabilities: u32,
+    pub name: *const c_char,
+    pub version: *const c_char,
+    pub commands: *const CommandDeclaration,
+    pub command_count: usize,
+}
+
+#[repr(C)]
+pub struct CommandDeclaration {
+    pub name: *const c_char,
+    pub summary: *const c_char,
+    pub usage: *const c_char,
+    pub run: RunFn,
+}
+
+// The declarations are statics in the plugin that point at other statics, they are never written to.
+unsafe impl Sync for PluginDeclaration {}
+unsafe impl Sync for CommandDeclaration {}
+
+#[repr(C)]
+pub struct Call {
+    pub argc: usize,
+    pub argv: *const *const c_char,
+    pub context: *mut c_void,
+    pub read: ReadFn,
+    pub write: WriteFn,
+    pub fail: FailFn,
+}
+
+//------------------------------------------------------------Plugin Side----------------------------------------------------------
+
+pub enum Failure {
+    Usage(String),
+    Failed(String),
+}
+
+pub struct Invocation<'a> {
+    call: &'a Call,
+}
+
+impl Invocation<'_> {
+    pub fn args(&self) -> Vec<String> {
+        if self.call.argc == 0 {
+            return Vec::new();
+        }
+        let argv = unsafe { std::slice::from_raw_parts(self.call.argv, self.call.argc) };
+        argv.iter()
+            .map(|&arg| {
+                unsafe { CStr::from_ptr(arg) }
+                    .to_string_lossy()
+                    .into_owned()
+            })
+            .collect()
+    }
+
+    // Empty unless the plugin has the READS_INPUT capability. Nothing is read before the Input is.
+    pub fn input(&self) -> Input<'_> {
+        Input { call: self.call }
+    }
+
+    pub fn read_input(&self) -> Result<String, Failure> {
+        let mut text = String::new();
+        self.input()
+            .read_to_string(&mut text)
+            .map_err(|err| Failure::Failed(format!("cannot read the input: {}", err)))?;
+        Ok(text)
+    }
+
+    pub fn write(&self, text: &str) -> Result<(), Failure> {
+        match (self.call.write)(self.call.context, text.as_ptr(), text.len()) {
+            STATUS_OK => Ok(()),
+            _ => Err(Failure::Failed(
+                "the host could not write the output".to_string(),
+            )),
+        }
+    }
+}
+
+pub struct Input<'a> {
+    call: &'a Call,
+}
+
+impl Read for Input<'_> {
+    fn read(&mut self, buffer: &mut [u8]) -> io::Result<usize> {
+        match (self.call.read)(self.call.context, buffer.as_mut_ptr(), buffer.len()) {
+            count if count >= 0 => Ok(count as usize),
+            _ => Err(io::Error::other("the host could not read the input")),
+        }
+    }
+}
+
+pub fn run_guarded<F>(call: &Call, command: F) -> i32
+where
+    F: FnOnce(&Invocation) -> Result<(), Failure>,
+{
+    let invocation = Invocation { call };
+    let fail = |message: &str| (call.fail)(call.context, message.as_ptr(), message.len());
+    match panic::catch_unwind(AssertUnwindSafe(|| command(&invocation))) {
+        Ok(Ok(())) => STATUS_OK,
+        Ok(Err(Failure::Usage(message))) => {
+            fail(&message);
+            STATUS_USAGE
+        }
+        Ok(Err(Failure::Failed(message))) => {
+            fail(&message);
+            STATUS_FAILED
+        }
+        Err(_) => {
+            fail("the plugin command panicked");
+            STATUS_PANICKED
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct Host {
+        input: io::Cursor<Vec<u8>>,
+        output: String,
+        failure: String,
+    }
+
+    extern "C" fn read(context: *mut c_void, data: *mut u8, capacity: usize) -> isize {
+        let host = unsafe { &mut *(context as *mut Host) };
+        let buffer = unsafe { std::slice::from_raw_parts_mut(data, capacity) };
+        host.input.read(buffer).unwrap() as isize
+    }
+
+    extern "C" fn write(context: *mut c_void, data: *const u8, len: usize) -> i32 {
+        let host = unsafe { &mut *(context as *mut Host) };
+        let text = unsafe { std::slice::from_raw_parts(data, len) };
+        host.output.push_str(std::str::from_utf8(text).unwrap());
+        STATUS_OK
+    }
+
+    extern "C" fn fail(context: *mut c_void, data: *const u8, len: usize) {
+        let host = unsafe { &mut *(context as *mut Host) };
+        let text = unsafe { std::slice::from_raw_parts(data, len) };
+        host.failure.push_str(std::str::from_utf8(text).unwrap());
+    }
+
+    fn call<F: FnOnce(&Invocation) -> Result<(), Failure>>(
+        args: &[&CStr],
+        command: F,
+    ) -> (i32, Host) {
+        let mut host = Host {
+            input: io::Cursor::new(b"line\n".to_vec()),
+            ..Host::default()
+        };
+        let argv: Vec<*const c_char> = args.iter().map(|arg| arg.as_ptr()).collect();
+        let call = Call {
+            argc: argv.len(),
+            argv: argv.as_ptr(),
+            context: &mut host as *mut Host as *mut c_void,
+            read,
+            write,
+            fail,
+        };
+        let status = run_guarded(&call, command);
+        (status, host)
+    }
+
+    #[test]
+    fn commands_see_arguments_and_input() {
+        let (status, host) = call(&[c"a", c"b"], |invocation| {
+            let text = format!("{:?} {:?}", invocation.args(), invocation.read_input()?);
+            invocation.write(&text)
+        });
+        assert_eq!(status, STATUS_OK);
+        assert_eq!(host.output, r#"["a", "b"] "line\n""#);
+    }
+
+    #[test]
+    fn failures_and_panics_become_status_codes() {
+        let (status, host) = call(&[], |_| Err(Failure::Usage("no".to_string())));
+        assert_eq!((status, host.failure.as_str()), (STATUS_USAGE, "no"));
+        let (status, host) = call(&[], |_| panic!("boom"));
+        assert_eq!(status, STATUS_PANICKED);
+        assert_eq!(host.failure, "the plugin command panicked");
+    }
+}
@@ -0,0 +1,77 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 06:21:21
+/// @Description: Sample Plugin
+/*
+- A plugin with two text commands, built as a shared library: cargo build -p sample_plugin, then copy target/debug/libsample_plugin.so (.dylib on macOS)
+  into the plugins directory next to the managin_growing_projects executable, or the directory in MANAGIN_PLUGINS.
+    -> shout [WORDS...] prints its words in capitals, or the input when it has none.
+    -> reverse prints the lines of the input in reverse order.
+- All a plugin has to write: the statics of its declaration, managin_plugin_declaration() returning it, and a run function per command that goes through
+  run_guarded(). The strings are C string literals, so they already end with the 0 the ABI needs.
+- shout with words never reads the input, without them it goes through the input a line at a time. reverse has to see the last line first, so it reads
+  all of it.
+*/
+use plugin_api::{
+    run_guarded, Call, CommandDeclaration, Failure, PluginDeclaration, ABI_VERSION, COMMANDS,
+    READS_INPUT,
+};
+use std::io::{BufRead, BufReader};
+
+static COMMAND_DECLARATIONS: [CommandDeclaration; 2] = [
+    CommandDeclaration {
+        name: c"shout".as_ptr(),
+        summary: c"Prints WORDS, or the input, in capitals".as_ptr(),
+        usage: c"[WORDS...]".as_ptr(),
+        run: shout,
+    },
+    CommandDeclaration {
+        name: c"reverse".as_ptr(),
+        summary: c"Prints the lines of the input in reverse order".as_ptr(),
+        usage: c"".as_ptr(),
+        run: reverse,
+    },
+];
+
+static DECLARATION: PluginDeclaration = PluginDeclaration {
+    abi_version: ABI_VERSION,
+    capabilities: COMMANDS | READS_INPUT,
+    name: c"text tools".as_ptr(),
+    version: c"0.1.0".as_ptr(),
+    commands: COMMAND_DECLARATIONS.as_ptr(),
+    command_count: COMMAND_DECLARATIONS.len(),
+};
+
+#[no_mangle]
+pub extern "C" fn managin_plugin_declaration() -> *const PluginDeclaration {
+    &DECLARATION
+}
+
+extern "C" fn shout(call: &Call) -> i32 {
+    run_guarded(call, |invocation| {
+        let args = invocation.args();
+        if !args.is_empty() {
+            return invocation.write(&format!("{}\n", args.join(" ").to_uppercase()));
+        }
+        for line in BufReader::new(invocation.input()).lines() {
+            let line =
+                line.map_err(|err| Failure::Failed(format!("cannot read the input: {}", err)))?;
+            invocation.write(&line.to_uppercase())?;
+            invocation.write("\n")?;
+        }
+        Ok(())
+    })
+}
+
+extern "C" fn reverse(call: &Call) -> i32 {
+    run_guarded(call, |invocation| {
+        if !invocation.args().is_empty() {
+            return Err(Failure::Usage("reverse takes no arguments".to_string()));
+        }
+        let text = invocation.read_input()?;
+        for line in text.lines().rev() {
+            invocation.write(line)?;
+            invocation.write("\n")?;
+        }
+        Ok(())
+    })
+}
@@ -1,3 +0,0 @@
-/// @Author: Mitul
-/// @Date:   2024-11-15 20:05:04
-/// @Description: Another Binary Crate
\ No newline at end of file
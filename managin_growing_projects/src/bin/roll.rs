@@ -0,0 +1,10 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 06:17:19
+/// @Description: Standalone roll Command
+use managin_growing_projects::commands::Roll;
+use managin_growing_projects::run_command;
+use std::process;
+
+fn main() {
+    process::exit(run_command(&Roll));
+}
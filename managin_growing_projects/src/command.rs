@@ -0,0 +1,356 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 06:17:19
+/// @Description: Commands and the Registry
+/*
+- A Command has a name, a one line summary, the usage of its arguments and a run method. run() gets the arguments after the name, the input and the
+  output, and says what went wrong through a CommandError.
+- The names and texts are &str and not &'static str, so a command can also be built at run time from something it read.
+- The Registry keeps the commands in the order they were registered, which is the order of the help text. Two commands cannot share a name.
+- dispatch() is the whole command line handling of the tool: help, help <COMMAND>, <COMMAND> --help and <COMMAND> [ARGS]. run_registry() and
+  run_command() wrap it for main functions: they use the real arguments, stdin and stdout, print errors to stderr and return the exit code.
+*/
+use fundamentals_core::UsageError;
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead, Write};
+
+pub trait Command {
+    fn name(&self) -> &str;
+    fn summary(&self) -> &str;
+    // What follows the name on the usage line, like "[NdM] [--seed N]".
+    fn usage(&self) -> &str;
+    fn run(
+        &self,
+        args: &[String],
+        input: &mut dyn BufRead,
+        out: &mut dyn Write,
+    ) -> Result<(), CommandError>;
+}
+
+#[derive(Debug)]
+pub enum CommandError {
+    // Called the wrong way, the help text goes with the message.
+    Usage(String),
+    Failed(String),
+    Io(io::Error),
+}
+
+impl CommandError {
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            CommandError::Usage(_) => 2,
+            CommandError::Failed(_) | CommandError::Io(_) => 1,
+        }
+    }
+}
+
+impl fmt::Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CommandError::Usage(message) | CommandError::Failed(message) => {
+                write!(f, "{}", message)
+            }
+            CommandError::Io(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl Error for CommandError {}
+
+impl From<io::Error> for CommandError {
+    fn from(err: io::Error) -> CommandError {
+        CommandError::Io(err)
+    }
+}
+
+impl From<UsageError> for CommandError {
+    fn from(err: UsageError) -> CommandError {
+        CommandError::Usage(err.0)
+    }
+}
+
+fn wants_help(args: &[String]) -> bool {
+    args.iter().any(|arg| arg == "-h" || arg == "--help")
+}
+
+// The help of one command. program is how the command is started, with the tool name in front when it goes through the registry.
+pub fn command_help(program: &str, command: &dyn Command) -> String {
+    format!(
+        "Usage: {} {}\n\n{}",
+        program,
+        command.usage(),
+        command.summary()
+    )
+}
+
+#[derive(Default)]
+pub struct Registry {
+    commands: Vec<Box<dyn Command>>,
+}
+
+impl Registry {
+    pub fn new() -> Registry {
+        Registry::default()
+    }
+
+    pub fn register(&mut self, command: Box<dyn Command>) -> Result<(), CommandError> {
+        if command.name() == "help" {
+            return Err(CommandError::Failed(
+                "help is a command of the registry itself".to_string(),
+            ));
+        }
+        if self.get(command.name()).is_some() {
+            return Err(CommandError::Failed(format!(
+                "a command named '{}' is already registered",
+                command.name()
+            )));
+        }
+        self.commands.push(command);
+        Ok(())
+    }
+
+    pub fn get(&self, name: &str) -> Option<&dyn Command> {
+        self.commands
+            .iter()
+            .find(|command| command.name() == name)
+            .map(|command| command.as_ref())
+    }
+
+    fn command(&self, name: &str) -> Result<&dyn Command, CommandError> {
+        self.get(name)
+            .ok_or_else(|| CommandError::Usage(format!("unknown command '{}'", name)))
+    }
+
+    pub fn commands(&self) -> impl Iterator<Item = &dyn Command> {
+        self.commands.iter().map(|command| command.as_ref())
+    }
+
+    pub fn len(&self) -> usize {
+        self.commands.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    pub fn help(&self, program: &str) -> String {
+        let width = self
+            .commands()
+            .map(|command| command.name().len())
+            .chain(Some("help".len()))
+            .max()
+            .unwrap_or(0);
+        let mut help = format!("Usage: {} <COMMAND> [ARGS]\n\nCommands:\n", program);
+        for command in self.commands() {
+            help.push_str(&format!(
+                "  {:<width$}  {}\n",
+                command.name(),
+                command.summary(),
+                width = width
+            ));
+        }
+        help.push_str(&format!(
+            "  {:<width$}  Prints this help or the help of a command\n\nRun {} help <COMMAND> for the arguments of a command.",
+            "help",
+            program,
+            width = width
+        ));
+        help
+    }
+
+    // The help that goes with a usage error: the one of the command if the arguments name one, the list of commands otherwise.
+    pub fn help_for(&self, program: &str, args: &[String]) -> String {
+        match args.first().and_then(|name| self.get(name)) {
+            Some(command) => command_help(&format!("{} {}", program, command.name()), command),
+            None => self.help(program),
+        }
+    }
+
+    pub fn dispatch(
+        &self,
+        program: &str,
+        args: &[String],
+        input: &mut dyn BufRead,
+        out: &mut dyn Write,
+    ) -> Result<(), CommandError> {
+        let (name, rest) = match args.split_first() {
+            Some((name, rest)) => (name.as_str(), rest),
+            None => return Err(CommandError::Usage("no command given".to_string())),
+        };
+        match name {
+            "help" | "-h" | "--help" => {
+                let help = match rest.first() {
+                    Some(name) => {
+                        let command = self.command(name)?;
+                        command_help(&format!("{} {}", program, name), command)
+                    }
+                    None => self.help(program),
+                };
+                writeln!(out, "{}", help)?;
+                Ok(())
+            }
+            _ => {
+                let command = self.command(name)?;
+                if wants_help(rest) {
+                    writeln!(out, "{}", self.help_for(program, args))?;
+                    return Ok(());
+                }
+                command.run(rest, input, out)
+            }
+        }
+    }
+}
+
+fn report(err: &CommandError, help: impl FnOnce() -> String) -> i32 {
+    match err {
+        CommandError::Usage(_) => eprintln!("error: {}\n{}", err, help()),
+        _ => eprintln!("error: {}", err),
+    }
+    err.exit_code()
+}
+
+// Runs the command named by the first argument of the program, for main.rs.
+pub fn run_registry(registry: &Registry, program: &str) -> i32 {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let mut stdout = io::stdout().lock();
+    let result = registry.dispatch(program, &args, &mut io::stdin().lock(), &mut stdout);
+    match result.and_then(|()| Ok(stdout.flush()?)) {
+        Ok(()) => 0,
+        Err(err) => report(&err, || registry.help_for(program, &args)),
+    }
+}
+
+// Runs one command with all arguments of the program, for the binaries in src/bin.
+pub fn run_command(command: &dyn Command) -> i32 {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let mut stdout = io::stdout().lock();
+    let result = if wants_help(&args) {
+        writeln!(stdout, "{}", command_help(command.name(), command)).map_err(CommandError::from)
+    } else {
+        command.run(&args, &mut io::stdin().lock(), &mut stdout)
+    };
+    match result.and_then(|()| Ok(stdout.flush()?)) {
+        Ok(()) => 0,
+        Err(err) => report(&err, || command_help(command.name(), command)),
+    }
+}
+
+// Runs command with the words of line as its arguments and input as its input, and returns what it wrote. For the tests of the commands.
+#[cfg(test)]
+pub(crate) fn run_line(
+    command: &dyn Command,
+    line: &str,
+    input: &str,
+) -> Result<String, CommandError> {
+    let args: Vec<String> = line.split_whitespace().map(String::from).collect();
+    let mut out = Vec::new();
+    command.run(&args, &mut input.as_bytes(), &mut out)?;
+    Ok(String::from_utf8(out).expect("commands write UTF-8"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Echo;
+
+    impl Command for Echo {
+        fn name(&self) -> &str {
+            "echo"
+        }
+
+        fn summary(&self) -> &str {
+            "Prints its arguments"
+        }
+
+        fn usage(&self) -> &str {
+            "[WORDS...]"
+        }
+
+        fn run(
+            &self,
+            args: &[String],
+            _input: &mut dyn BufRead,
+            out: &mut dyn Write,
+        ) -> Result<(), CommandError> {
+            if args.iter().any(|arg| arg.starts_with("--")) {
+                return Err(CommandError::Usage("echo takes no options".to_string()));
+            }
+            writeln!(out, "{}", args.join(" "))?;
+            Ok(())
+        }
+    }
+
+    // The registry as the tool that dispatches to it, so run_line can drive it.
+    struct Tool(Registry);
+
+    impl Command for Tool {
+        fn name(&self) -> &str {
+            "tool"
+        }
+
+        fn summary(&self) -> &str {
+            "Runs one of its commands"
+        }
+
+        fn usage(&self) -> &str {
+            "<COMMAND> [ARGS]"
+        }
+
+        fn run(
+            &self,
+            args: &[String],
+            input: &mut dyn BufRead,
+            out: &mut dyn Write,
+        ) -> Result<(), CommandError> {
+            self.0.dispatch("tool", args, input, out)
+        }
+    }
+
+    fn tool() -> Tool {
+        let mut registry = Registry::new();
+        registry.register(Box::new(Echo)).unwrap();
+        Tool(registry)
+    }
+
+    #[test]
+    fn dispatches_by_name() {
+        assert_eq!(run_line(&tool(), "echo a b", "").unwrap(), "a b\n");
+        assert!(matches!(
+            run_line(&tool(), "shout a", ""),
+            Err(CommandError::Usage(message)) if message == "unknown command 'shout'"
+        ));
+        assert!(matches!(
+            run_line(&tool(), "", ""),
+            Err(CommandError::Usage(_))
+        ));
+        assert_eq!(
+            run_line(&tool(), "echo --loud", "")
+                .unwrap_err()
+                .exit_code(),
+            2
+        );
+    }
+
+    #[test]
+    fn help_lists_commands_and_explains_one() {
+        let tool = tool();
+        assert_eq!(
+            run_line(&tool, "help", "").unwrap(),
+            "Usage: tool <COMMAND> [ARGS]\n\nCommands:\n  echo  Prints its arguments\n  help  Prints this help or the help of a command\n\n\
+             Run tool help <COMMAND> for the arguments of a command.\n"
+        );
+        let echo_help = "Usage: tool echo [WORDS...]\n\nPrints its arguments\n";
+        assert_eq!(run_line(&tool, "help echo", "").unwrap(), echo_help);
+        assert_eq!(run_line(&tool, "echo a --help", "").unwrap(), echo_help);
+        assert!(run_line(&tool, "help shout", "").is_err());
+    }
+
+    #[test]
+    fn names_are_unique() {
+        let Tool(mut registry) = tool();
+        assert!(registry.register(Box::new(Echo)).is_err());
+        assert_eq!(registry.len(), 1);
+    }
+}
@@ -0,0 +1,14 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 06:17:19
+/// @Description: Built-in Commands
+/*
+- One file per command in src/commands, this file only declares them. It is the newer style of a module with submodules, the older one would be
+  src/commands/mod.rs.
+*/
+mod hello;
+mod roll;
+mod word_count;
+
+pub use hello::Hello;
+pub use roll::Roll;
+pub use word_count::WordCount;
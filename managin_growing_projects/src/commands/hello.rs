@@ -0,0 +1,52 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 06:17:19
+/// @Description: Hello Command
+use crate::command::{Command, CommandError};
+use std::io::{BufRead, Write};
+
+// The hello world the crate started with, as a command.
+pub struct Hello;
+
+impl Command for Hello {
+    fn name(&self) -> &str {
+        "hello"
+    }
+
+    fn summary(&self) -> &str {
+        "Greets NAME, or the world"
+    }
+
+    fn usage(&self) -> &str {
+        "[NAME]"
+    }
+
+    fn run(
+        &self,
+        args: &[String],
+        _input: &mut dyn BufRead,
+        out: &mut dyn Write,
+    ) -> Result<(), CommandError> {
+        match args {
+            [] => writeln!(out, "Hello, world!")?,
+            [name] => writeln!(out, "Hello, {}!", name)?,
+            _ => return Err(CommandError::Usage("hello takes one name".to_string())),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::run_line;
+
+    #[test]
+    fn greets() {
+        assert_eq!(run_line(&Hello, "", "").unwrap(), "Hello, world!\n");
+        assert_eq!(run_line(&Hello, "Ferris", "").unwrap(), "Hello, Ferris!\n");
+        assert!(matches!(
+            run_line(&Hello, "a b", ""),
+            Err(CommandError::Usage(_))
+        ));
+    }
+}
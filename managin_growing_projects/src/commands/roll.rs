@@ -0,0 +1,166 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 06:17:19
+/// @Description: Roll Command
+/*
+- roll [NdM] [--seed N] rolls N dice with M sides each, 1d6 when left out, and prints every die and the sum: 2d6: 3 + 5 = 8.
+- The dice come from rand, the dependency of the package. --seed makes the roll repeatable.
+- The sum is a u64, 100 dice of u32::MAX sides do not fit in a u32.
+*/
+use crate::command::{Command, CommandError};
+use fundamentals_core::Args;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::io::{BufRead, Write};
+use std::str::FromStr;
+
+const MAX_DICE: u32 = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dice {
+    pub count: u32,
+    pub sides: u32,
+}
+
+impl FromStr for Dice {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Dice, String> {
+        let invalid = || format!("'{}' is not a roll like 2d6", text);
+        let (count, sides) = text.split_once(['d', 'D']).ok_or_else(invalid)?;
+        let count = if count.is_empty() {
+            1
+        } else {
+            count.parse().map_err(|_| invalid())?
+        };
+        let sides = sides.parse().map_err(|_| invalid())?;
+        if !(1..=MAX_DICE).contains(&count) || sides < 2 {
+            return Err(format!("roll 1 to {} dice with at least 2 sides", MAX_DICE));
+        }
+        Ok(Dice { count, sides })
+    }
+}
+
+pub struct Roll;
+
+impl Command for Roll {
+    fn name(&self) -> &str {
+        "roll"
+    }
+
+    fn summary(&self) -> &str {
+        "Rolls N dice with M sides, 1d6 by default"
+    }
+
+    fn usage(&self) -> &str {
+        "[NdM] [--seed N]"
+    }
+
+    fn run(
+        &self,
+        args: &[String],
+        _input: &mut dyn BufRead,
+        out: &mut dyn Write,
+    ) -> Result<(), CommandError> {
+        let mut dice = None;
+        let mut seed = None;
+        let mut args = Args::new(args.iter().cloned());
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--seed" => seed = Some(args.number::<u64>("--seed")?),
+                _ if dice.is_none() => dice = Some(arg.parse().map_err(CommandError::Usage)?),
+                _ => {
+                    return Err(CommandError::Usage(format!(
+                        "unexpected argument '{}'",
+                        arg
+                    )))
+                }
+            }
+        }
+        let dice = dice.unwrap_or(Dice { count: 1, sides: 6 });
+        let mut rng = match seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+
+        let rolls: Vec<u32> = (0..dice.count)
+            .map(|_| rng.gen_range(1..=dice.sides))
+            .collect();
+        let faces: Vec<String> = rolls.iter().map(u32::to_string).collect();
+        writeln!(
+            out,
+            "{}d{}: {} = {}",
+            dice.count,
+            dice.sides,
+            faces.join(" + "),
+            rolls.iter().map(|&roll| u64::from(roll)).sum::<u64>()
+        )?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::run_line;
+
+    #[test]
+    fn parses_dice() {
+        assert_eq!("2d6".parse(), Ok(Dice { count: 2, sides: 6 }));
+        assert_eq!(
+            "d20".parse(),
+            Ok(Dice {
+                count: 1,
+                sides: 20
+            })
+        );
+        assert!("2x6".parse::<Dice>().is_err());
+        assert!("0d6".parse::<Dice>().is_err());
+        assert!("3d1".parse::<Dice>().is_err());
+    }
+
+    #[test]
+    fn seeded_rolls_repeat_and_stay_in_range() {
+        let first = run_line(&Roll, "3d6 --seed 7", "").unwrap();
+        assert_eq!(first, run_line(&Roll, "--seed 7 3d6", "").unwrap());
+        let (_, rest) = first.trim_end().split_once(": ").unwrap();
+        let (faces, sum) = rest.split_once(" = ").unwrap();
+        let faces: Vec<u32> = faces
+            .split(" + ")
+            .map(|face| face.parse().unwrap())
+            .collect();
+        assert_eq!(faces.len(), 3);
+        assert!(faces.iter().all(|face| (1..=6).contains(face)));
+        assert_eq!(faces.iter().sum::<u32>(), sum.parse::<u32>().unwrap());
+    }
+
+    #[test]
+    fn the_sum_of_huge_dice_does_not_overflow() {
+        let out = run_line(&Roll, "100d4294967295 --seed 1", "").unwrap();
+        let (faces, sum) = out.trim_end().split_once(" = ").unwrap();
+        let faces: u64 = faces
+            .split_once(": ")
+            .unwrap()
+            .1
+            .split(" + ")
+            .map(|face| face.parse::<u64>().unwrap())
+            .sum();
+        assert!(faces > u64::from(u32::MAX));
+        assert_eq!(faces, sum.parse::<u64>().unwrap());
+    }
+
+    #[test]
+    fn bad_arguments_are_usage_errors() {
+        assert!(matches!(
+            run_line(&Roll, "2d6 3d6", ""),
+            Err(CommandError::Usage(_))
+        ));
+        assert!(matches!(
+            run_line(&Roll, "--seed x", ""),
+            Err(CommandError::Usage(_))
+        ));
+        assert!(matches!(
+            run_line(&Roll, "big", ""),
+            Err(CommandError::Usage(_))
+        ));
+    }
+}
@@ -0,0 +1,109 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 06:17:19
+/// @Description: Word Count Command
+/*
+- wc [FILE] counts the lines, words and bytes of FILE, or of the input when no file is given, and prints them in that order like the Unix tool does.
+- A word is a run of characters that are not whitespace. A last line without a line break still counts as a line.
+- The file is opened with open_or_stdin of fundamentals_core. The lines are read raw rather than with data_lines, wc counts blank lines and # lines too.
+*/
+use crate::command::{Command, CommandError};
+use fundamentals_core::open_or_stdin;
+use std::io::{BufRead, Write};
+use std::path::Path;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Counts {
+    pub lines: usize,
+    pub words: usize,
+    pub bytes: usize,
+}
+
+pub fn count<R: BufRead + ?Sized>(input: &mut R) -> std::io::Result<Counts> {
+    let mut counts = Counts::default();
+    let mut line = Vec::new();
+    while input.read_until(b'\n', &mut line)? > 0 {
+        counts.lines += 1;
+        counts.bytes += line.len();
+        // Counted on the bytes, so a file that is not UTF-8 can still be counted.
+        counts.words += line
+            .split(|byte| byte.is_ascii_whitespace())
+            .filter(|word| !word.is_empty())
+            .count();
+        line.clear();
+    }
+    Ok(counts)
+}
+
+pub struct WordCount;
+
+impl Command for WordCount {
+    fn name(&self) -> &str {
+        "wc"
+    }
+
+    fn summary(&self) -> &str {
+        "Counts the lines, words and bytes of FILE or of stdin"
+    }
+
+    fn usage(&self) -> &str {
+        "[FILE]"
+    }
+
+    fn run(
+        &self,
+        args: &[String],
+        input: &mut dyn BufRead,
+        out: &mut dyn Write,
+    ) -> Result<(), CommandError> {
+        let (counts, name) = match args {
+            [] => (count(input)?, None),
+            [path] => {
+                let mut file = open_or_stdin(Some(Path::new(path))).map_err(|err| {
+                    CommandError::Failed(format!("cannot read {}: {}", path, err))
+                })?;
+                (count(&mut file)?, Some(path))
+            }
+            _ => return Err(CommandError::Usage("wc counts one file".to_string())),
+        };
+        write!(
+            out,
+            "{:>7} {:>7} {:>7}",
+            counts.lines, counts.words, counts.bytes
+        )?;
+        match name {
+            Some(name) => writeln!(out, " {}", name)?,
+            None => writeln!(out)?,
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::run_line;
+
+    #[test]
+    fn counts_lines_words_and_bytes() {
+        let counts = count(&mut "one two\n\nthree  four five\nlast".as_bytes()).unwrap();
+        assert_eq!(
+            counts,
+            Counts {
+                lines: 4,
+                words: 6,
+                bytes: 30
+            }
+        );
+        assert_eq!(count(&mut "".as_bytes()).unwrap(), Counts::default());
+    }
+
+    #[test]
+    fn reads_the_input_without_a_file() {
+        assert_eq!(
+            run_line(&WordCount, "", "a b\nc\n").unwrap(),
+            "      2       3       6\n"
+        );
+        let missing = run_line(&WordCount, "no/such/file", "");
+        assert!(matches!(missing, Err(CommandError::Failed(_))));
+    }
+}
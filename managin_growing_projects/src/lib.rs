@@ -1,3 +1,37 @@
 /// @Author: Mitul
 /// @Date:   2024-11-15 20:05:04
-/// @Description: Library Crate
\ No newline at end of file
+/// @Description: Library Crate
+/*
+- The library holds everything, the binaries only pick what to run. A new command is a type that implements Command, registered in builtin(). main.rs
+  does not change when a command is added.
+- The command module has the Command trait, the Registry that finds a command by name and writes the help text, and the runners that turn an error into
+  an exit code: 0 on success, 1 when the command failed, 2 when it was called the wrong way.
+- The commands module has the commands themselves, one file each.
+- Two ways to start a command, both go through the same code:
+    -> managin_growing_projects <COMMAND> [ARGS] runs any registered command, help lists them.
+    -> every file in src/bin is a standalone binary for one command: cargo run --bin roll -- 2d6.
+- Commands read from a BufRead and write to a Write they are handed, so tests run them on strings instead of stdin and stdout.
+- The plugin module adds commands at run time from shared libraries, main.rs loads them from the plugins directory. The ABI they are built against is
+  the plugin_api crate next to this one.
+*/
+pub mod command;
+pub mod commands;
+pub mod plugin;
+
+pub use command::{command_help, run_command, run_registry, Command, CommandError, Registry};
+pub use plugin::{load_into, LoadError, PluginInfo, PluginReport, Plugins};
+
+pub fn builtin() -> Registry {
+    let mut registry = Registry::new();
+    let commands: [Box<dyn Command>; 3] = [
+        Box::new(commands::Hello),
+        Box::new(commands::Roll),
+        Box::new(commands::WordCount),
+    ];
+    for command in commands {
+        registry
+            .register(command)
+            .expect("the built-in commands have different names");
+    }
+    registry
+}
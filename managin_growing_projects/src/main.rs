@@ -8,7 +8,36 @@
 - A package should have atleast one crate. It can have atmost one library crate and zero or more binary crates. To create more binary crates a bin folder is creatd
   in the src directory and define multiple crates.
 - To create a binary crate use --bin and --lib for a library crate with cargo new.
+- This package uses both: the library has the commands and the registry, main.rs dispatches to any of them by name and every file in src/bin runs a
+  single one. With more than one binary, default-run in Cargo.toml says which one cargo run starts.
+- Plugins are loaded from the directory in MANAGIN_PLUGINS, or from the plugins directory next to the executable when it is not set. Never from the
+  working directory: loading a library runs its code, and a plugins folder in whatever directory the tool is started from is not one the user chose.
+  A plugin that cannot be loaded is reported on stderr and skipped, managin_growing_projects plugins lists what was found.
 */
+use managin_growing_projects::{builtin, load_into, run_registry, PluginReport, Plugins};
+use std::path::PathBuf;
+use std::{env, process};
+
+// None when the executable cannot be located, no plugins are loaded then.
+fn plugin_dir() -> Option<PathBuf> {
+    match env::var_os("MANAGIN_PLUGINS") {
+        Some(dir) => Some(PathBuf::from(dir)),
+        None => Some(env::current_exe().ok()?.parent()?.join("plugins")),
+    }
+}
+
 fn main() {
-    println!("Hello, world!");
+    let mut registry = builtin();
+    let report = match plugin_dir() {
+        Some(dir) => load_into(&mut registry, &dir),
+        None => PluginReport::default(),
+    };
+    for rejection in &report.rejected {
+        eprintln!("warning: {}", rejection);
+    }
+    // A plugin may have taken the name already, its command wins then.
+    if let Err(err) = registry.register(Box::new(Plugins::new(report))) {
+        eprintln!("warning: {}", err);
+    }
+    process::exit(run_registry(&registry, "managin_growing_projects"));
 }
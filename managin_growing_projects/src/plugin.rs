@@ -0,0 +1,661 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 06:21:21
+/// @Description: Loading Plugins
+/*
+- load_into() opens every shared library in a directory (.so on Linux, .dylib on macOS), asks it for its PluginDeclaration and registers its commands.
+  The ABI is described in the plugin_api crate, sample_plugin is a plugin to copy from.
+- Opening a library runs its constructors, so the directory has to be one the user chose. main.rs passes MANAGIN_PLUGINS or the plugins directory
+  next to the executable, never a path relative to the working directory.
+- A plugin is checked before anything of it is used, and turned away with the reason when:
+    -> it cannot be opened, or it does not export managin_plugin_declaration: it is not a plugin.
+    -> its abi_version is not ABI_VERSION. Nothing but the version is read from it then, the rest of its declaration may look different.
+    -> it has capability bits the host does not know, or does not offer commands.
+    -> a string is missing or not UTF-8, or one of its commands has the name of a command that is already registered.
+  A turned away plugin is reported and the tool goes on without it, one broken plugin does not stop the others.
+- Discovery: the plugins command lists every plugin with its version, ABI and capabilities, its commands, and the ones that were turned away.
+- A plugin command reads the input through the read callback of its Call, only as much of it and only when it wants to. The host hands it the input of
+  the tool only with the READS_INPUT capability, without it the input is empty.
+- The libraries are opened with dlopen() from the C library, on other systems loading fails with an error. Every command of a plugin keeps the library
+  open through an Rc, it is closed when the registry goes away.
+*/
+use crate::command::{Command, CommandError, Registry};
+use plugin_api::{
+    Call, CommandDeclaration, EntryFn, PluginDeclaration, RunFn, ABI_VERSION, COMMANDS,
+    ENTRY_SYMBOL, KNOWN_CAPABILITIES, READS_INPUT, STATUS_OK, STATUS_PANICKED, STATUS_USAGE,
+};
+use std::ffi::{c_char, c_void, CStr, CString};
+use std::fmt;
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+//------------------------------------------------------------Shared Libraries----------------------------------------------------------
+
+#[cfg(unix)]
+mod dl {
+    use std::ffi::{c_char, c_int, c_void};
+
+    pub const RTLD_NOW: c_int = 2;
+
+    // glibc before 2.34 keeps these in libdl, newer ones and musl have an empty libdl for compatibility.
+    #[cfg_attr(target_os = "linux", link(name = "dl"))]
+    extern "C" {
+        pub fn dlopen(filename: *const c_char, flags: c_int) -> *mut c_void;
+        pub fn dlsym(handle: *mut c_void, symbol: *const c_char) -> *mut c_void;
+        pub fn dlclose(handle: *mut c_void) -> c_int;
+        pub fn dlerror() -> *mut c_char;
+    }
+}
+
+struct Library {
+    handle: *mut c_void,
+}
+
+impl Library {
+    #[cfg(unix)]
+    fn open(path: &Path) -> Result<Library, LoadError> {
+        use std::os::unix::ffi::OsStrExt;
+        let name = CString::new(path.as_os_str().as_bytes())
+            .map_err(|_| LoadError::Open("the path contains a 0 byte".to_string()))?;
+        let handle = unsafe { dl::dlopen(name.as_ptr(), dl::RTLD_NOW) };
+        if handle.is_null() {
+            let message = unsafe { dl::dlerror() };
+            let message = if message.is_null() {
+                "unknown error".to_string()
+            } else {
+                unsafe { CStr::from_ptr(message) }
+                    .to_string_lossy()
+                    .into_owned()
+            };
+            return Err(LoadError::Open(message));
+        }
+        Ok(Library { handle })
+    }
+
+    #[cfg(not(unix))]
+    fn open(_path: &Path) -> Result<Library, LoadError> {
+        Err(LoadError::Open(
+            "plugins are only supported on Unix systems".to_string(),
+        ))
+    }
+
+    #[cfg(unix)]
+    fn symbol(&self, name: &CStr) -> *mut c_void {
+        unsafe { dl::dlsym(self.handle, name.as_ptr()) }
+    }
+
+    #[cfg(not(unix))]
+    fn symbol(&self, _name: &CStr) -> *mut c_void {
+        std::ptr::null_mut()
+    }
+}
+
+impl Drop for Library {
+    fn drop(&mut self) {
+        #[cfg(unix)]
+        unsafe {
+            dl::dlclose(self.handle);
+        }
+    }
+}
+
+//------------------------------------------------------------Loading----------------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LoadError {
+    Open(String),
+    NotAPlugin,
+    AbiVersion(u32),
+    Capabilities(u32),
+    Invalid(String),
+    NameTaken(String),
+}
+
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LoadError::Open(message) => write!(f, "cannot be opened: {}", message),
+            LoadError::NotAPlugin => write!(
+                f,
+                "not a plugin, it does not export {}",
+                ENTRY_SYMBOL.to_string_lossy()
+            ),
+            LoadError::AbiVersion(version) => write!(
+                f,
+                "built for plugin ABI {}, this tool speaks ABI {}",
+                version, ABI_VERSION
+            ),
+            LoadError::Capabilities(bits) => write!(
+                f,
+                "needs capabilities this tool does not have (bits {:#x})",
+                bits
+            ),
+            LoadError::Invalid(message) => write!(f, "invalid declaration: {}", message),
+            LoadError::NameTaken(name) => write!(f, "a command named '{}' already exists", name),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PluginInfo {
+    pub path: PathBuf,
+    pub name: String,
+    pub version: String,
+    pub capabilities: u32,
+    pub commands: Vec<String>,
+}
+
+impl PluginInfo {
+    pub fn capability_names(&self) -> Vec<&'static str> {
+        [(COMMANDS, "commands"), (READS_INPUT, "reads-input")]
+            .into_iter()
+            .filter(|(bit, _)| self.capabilities & bit != 0)
+            .map(|(_, name)| name)
+            .collect()
+    }
+}
+
+pub struct Plugin {
+    pub info: PluginInfo,
+    commands: Vec<PluginCommand>,
+}
+
+pub fn load(path: &Path) -> Result<Plugin, LoadError> {
+    let library = Rc::new(Library::open(path)?);
+    let entry = library.symbol(ENTRY_SYMBOL);
+    if entry.is_null() {
+        return Err(LoadError::NotAPlugin);
+    }
+    // The symbol has the name of the entry point of the ABI, so it is taken to have its type.
+    let entry = unsafe { std::mem::transmute::<*mut c_void, EntryFn>(entry) };
+    unsafe { read_declaration(entry(), path, Some(library)) }
+}
+
+unsafe fn text(pointer: *const c_char, what: &str) -> Result<String, LoadError> {
+    if pointer.is_null() {
+        return Err(LoadError::Invalid(format!("{} is missing", what)));
+    }
+    CStr::from_ptr(pointer)
+        .to_str()
+        .map(str::to_string)
+        .map_err(|_| LoadError::Invalid(format!("{} is not UTF-8", what)))
+}
+
+// Checks the declaration and copies what the host keeps of it. library is None for declarations that are part of the program, in the tests.
+unsafe fn read_declaration(
+    declaration: *const PluginDeclaration,
+    path: &Path,
+    library: Option<Rc<Library>>,
+) -> Result<Plugin, LoadError> {
+    if declaration.is_null() {
+        return Err(LoadError::Invalid("the declaration is missing".to_string()));
+    }
+    // Only the first field is read before the version is known to match.
+    let abi_version = std::ptr::addr_of!((*declaration).abi_version).read();
+    if abi_version != ABI_VERSION {
+        return Err(LoadError::AbiVersion(abi_version));
+    }
+    let declaration = &*declaration;
+    let unknown = declaration.capabilities & !KNOWN_CAPABILITIES;
+    if unknown != 0 {
+        return Err(LoadError::Capabilities(unknown));
+    }
+    if declaration.capabilities & COMMANDS == 0
+        || declaration.commands.is_null()
+        || declaration.command_count == 0
+    {
+        return Err(LoadError::Invalid(
+            "the plugin offers no commands".to_string(),
+        ));
+    }
+
+    let name = text(declaration.name, "the plugin name")?;
+    let declarations: &[CommandDeclaration] =
+        std::slice::from_raw_parts(declaration.commands, declaration.command_count);
+    let mut commands = Vec::new();
+    for command in declarations {
+        commands.push(PluginCommand {
+            name: text(command.name, "a command name")?,
+            summary: text(command.summary, "a command summary")?,
+            usage: text(command.usage, "a command usage")?,
+            run: command.run,
+            reads_input: declaration.capabilities & READS_INPUT != 0,
+            plugin: name.clone(),
+            _library: library.clone(),
+        });
+    }
+    Ok(Plugin {
+        info: PluginInfo {
+            path: path.to_path_buf(),
+            name,
+            version: text(declaration.version, "the plugin version")?,
+            capabilities: declaration.capabilities,
+            commands: commands
+                .iter()
+                .map(|command| command.name.clone())
+                .collect(),
+        },
+        commands,
+    })
+}
+
+#[derive(Debug)]
+pub struct Rejection {
+    pub path: PathBuf,
+    pub error: LoadError,
+}
+
+impl fmt::Display for Rejection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "skipped plugin {}: {}", self.path.display(), self.error)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct PluginReport {
+    pub dir: PathBuf,
+    pub loaded: Vec<PluginInfo>,
+    pub rejected: Vec<Rejection>,
+}
+
+// Registers the plugin only if none of its command names is taken, so a plugin is either there completely or not at all.
+pub fn register(registry: &mut Registry, plugin: Plugin) -> Result<PluginInfo, LoadError> {
+    if let Some(taken) = plugin
+        .commands
+        .iter()
+        .enumerate()
+        .find(|(index, command)| {
+            registry.get(&command.name).is_some()
+                || command.name == "help"
+                || plugin.commands[..*index]
+                    .iter()
+                    .any(|earlier| earlier.name == command.name)
+        })
+        .map(|(_, command)| command.name.clone())
+    {
+        return Err(LoadError::NameTaken(taken));
+    }
+    for command in plugin.commands {
+        registry
+            .register(Box::new(command))
+            .expect("the names were checked");
+    }
+    Ok(plugin.info)
+}
+
+// A directory that does not exist has no plugins, that is not an error.
+pub fn load_into(registry: &mut Registry, dir: &Path) -> PluginReport {
+    let mut report = PluginReport {
+        dir: dir.to_path_buf(),
+        ..PluginReport::default()
+    };
+    let mut paths: Vec<PathBuf> = match fs::read_dir(dir) {
+        Ok(entries) => entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| {
+                path.extension()
+                    .is_some_and(|extension| extension == std::env::consts::DLL_EXTENSION)
+            })
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+    paths.sort();
+    for path in paths {
+        match load(&path).and_then(|plugin| register(registry, plugin)) {
+            Ok(info) => report.loaded.push(info),
+            Err(error) => report.rejected.push(Rejection { path, error }),
+        }
+    }
+    report
+}
+
+//------------------------------------------------------------Plugin Commands----------------------------------------------------------
+
+struct PluginCommand {
+    name: String,
+    summary: String,
+    usage: String,
+    run: RunFn,
+    reads_input: bool,
+    plugin: String,
+    // Keeps the code of run loaded.
+    _library: Option<Rc<Library>>,
+}
+
+// What the callbacks of a Call reach through its context. input is None when the plugin does not have the READS_INPUT capability.
+struct Sink<'a> {
+    input: Option<&'a mut dyn BufRead>,
+    out: &'a mut dyn Write,
+    error: Option<io::Error>,
+    message: String,
+}
+
+extern "C" fn read_input(context: *mut c_void, data: *mut u8, capacity: usize) -> isize {
+    let sink = unsafe { &mut *(context as *mut Sink) };
+    let input = match sink.input.as_mut() {
+        Some(input) => input,
+        None => return 0,
+    };
+    let buffer = unsafe { std::slice::from_raw_parts_mut(data, capacity) };
+    loop {
+        match input.read(buffer) {
+            Ok(count) => return count as isize,
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => {
+                sink.error = Some(err);
+                return -1;
+            }
+        }
+    }
+}
+
+extern "C" fn write_output(context: *mut c_void, data: *const u8, len: usize) -> i32 {
+    let sink = unsafe { &mut *(context as *mut Sink) };
+    let data = unsafe { std::slice::from_raw_parts(data, len) };
+    match sink.out.write_all(data) {
+        Ok(()) => STATUS_OK,
+        Err(err) => {
+            sink.error = Some(err);
+            plugin_api::STATUS_FAILED
+        }
+    }
+}
+
+extern "C" fn record_failure(context: *mut c_void, data: *const u8, len: usize) {
+    let sink = unsafe { &mut *(context as *mut Sink) };
+    let data = unsafe { std::slice::from_raw_parts(data, len) };
+    sink.message.push_str(&String::from_utf8_lossy(data));
+}
+
+impl Command for PluginCommand {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn summary(&self) -> &str {
+        &self.summary
+    }
+
+    fn usage(&self) -> &str {
+        &self.usage
+    }
+
+    fn run(
+        &self,
+        args: &[String],
+        input: &mut dyn BufRead,
+        out: &mut dyn Write,
+    ) -> Result<(), CommandError> {
+        let args: Vec<CString> = args
+            .iter()
+            .map(|arg| CString::new(arg.as_str()))
+            .collect::<Result<_, _>>()
+            .map_err(|_| CommandError::Usage("an argument contains a 0 byte".to_string()))?;
+        let argv: Vec<*const c_char> = args.iter().map(|arg| arg.as_ptr()).collect();
+        let mut sink = Sink {
+            input: if self.reads_input { Some(input) } else { None },
+            out,
+            error: None,
+            message: String::new(),
+        };
+        let call = Call {
+            argc: argv.len(),
+            argv: argv.as_ptr(),
+            context: &mut sink as *mut Sink as *mut c_void,
+            read: read_input,
+            write: write_output,
+            fail: record_failure,
+        };
+        let status = (self.run)(&call);
+
+        if let Some(err) = sink.error {
+            return Err(CommandError::Io(err));
+        }
+        let message = sink.message;
+        match status {
+            STATUS_OK => Ok(()),
+            STATUS_USAGE => Err(CommandError::Usage(message)),
+            STATUS_PANICKED => Err(CommandError::Failed(format!(
+                "{} (plugin {})",
+                message, self.plugin
+            ))),
+            _ if message.is_empty() => Err(CommandError::Failed(format!(
+                "{} failed with status {}",
+                self.name, status
+            ))),
+            _ => Err(CommandError::Failed(message)),
+        }
+    }
+}
+
+// Lists what load_into() found.
+pub struct Plugins {
+    report: PluginReport,
+}
+
+impl Plugins {
+    pub fn new(report: PluginReport) -> Plugins {
+        Plugins { report }
+    }
+}
+
+impl Command for Plugins {
+    fn name(&self) -> &str {
+        "plugins"
+    }
+
+    fn summary(&self) -> &str {
+        "Lists the loaded plugins and their capabilities, and the ones that were skipped"
+    }
+
+    fn usage(&self) -> &str {
+        ""
+    }
+
+    fn run(
+        &self,
+        args: &[String],
+        _input: &mut dyn BufRead,
+        out: &mut dyn Write,
+    ) -> Result<(), CommandError> {
+        if !args.is_empty() {
+            return Err(CommandError::Usage(
+                "plugins takes no arguments".to_string(),
+            ));
+        }
+        let report = &self.report;
+        if report.loaded.is_empty() && report.rejected.is_empty() {
+            writeln!(out, "No plugins in {}.", report.dir.display())?;
+            return Ok(());
+        }
+        writeln!(out, "Plugins in {}:", report.dir.display())?;
+        for info in &report.loaded {
+            let file = info.path.file_name().unwrap_or_default().to_string_lossy();
+            writeln!(
+                out,
+                "  {} {} ({}), capabilities: {}",
+                info.name,
+                info.version,
+                file,
+                info.capability_names().join(", ")
+            )?;
+            writeln!(out, "    commands: {}", info.commands.join(", "))?;
+        }
+        for rejection in &report.rejected {
+            let file = rejection
+                .path
+                .file_name()
+                .unwrap_or_default()
+                .to_string_lossy();
+            writeln!(out, "  skipped {}: {}", file, rejection.error)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::run_line;
+    use plugin_api::{run_guarded, Failure};
+
+    // Counts its words, or the lines of the input when it has none.
+    extern "C" fn count(call: &Call) -> i32 {
+        run_guarded(call, |invocation| {
+            let args = invocation.args();
+            if args.iter().any(|arg| arg == "--bad") {
+                return Err(Failure::Usage("count takes words".to_string()));
+            }
+            if !args.is_empty() {
+                return invocation.write(&format!("{} words\n", args.len()));
+            }
+            let lines = invocation.read_input()?.lines().count();
+            invocation.write(&format!("{} lines\n", lines))
+        })
+    }
+
+    // Input that must not be read.
+    struct Untouchable;
+
+    impl io::Read for Untouchable {
+        fn read(&mut self, _buffer: &mut [u8]) -> io::Result<usize> {
+            Err(io::Error::other("the input was read"))
+        }
+    }
+
+    impl BufRead for Untouchable {
+        fn fill_buf(&mut self) -> io::Result<&[u8]> {
+            Err(io::Error::other("the input was read"))
+        }
+
+        fn consume(&mut self, _amount: usize) {}
+    }
+
+    static COMMAND: [CommandDeclaration; 1] = [CommandDeclaration {
+        name: c"count".as_ptr(),
+        summary: c"Counts".as_ptr(),
+        usage: c"[WORDS...]".as_ptr(),
+        run: count,
+    }];
+
+    fn declaration(abi_version: u32, capabilities: u32) -> PluginDeclaration {
+        PluginDeclaration {
+            abi_version,
+            capabilities,
+            name: c"counter".as_ptr(),
+            version: c"1.2.3".as_ptr(),
+            commands: COMMAND.as_ptr(),
+            command_count: COMMAND.len(),
+        }
+    }
+
+    fn read(declaration: &PluginDeclaration) -> Result<Plugin, LoadError> {
+        unsafe { read_declaration(declaration, Path::new("counter.so"), None) }
+    }
+
+    #[test]
+    fn declarations_are_checked() {
+        assert_eq!(
+            read(&declaration(ABI_VERSION + 1, COMMANDS)).err(),
+            Some(LoadError::AbiVersion(ABI_VERSION + 1))
+        );
+        assert_eq!(
+            read(&declaration(ABI_VERSION, COMMANDS | 1 << 7)).err(),
+            Some(LoadError::Capabilities(1 << 7))
+        );
+        assert!(matches!(
+            read(&declaration(ABI_VERSION, READS_INPUT)),
+            Err(LoadError::Invalid(_))
+        ));
+        let mut unnamed = declaration(ABI_VERSION, COMMANDS);
+        unnamed.name = std::ptr::null();
+        assert_eq!(
+            read(&unnamed).err(),
+            Some(LoadError::Invalid("the plugin name is missing".to_string()))
+        );
+
+        let plugin = read(&declaration(ABI_VERSION, COMMANDS | READS_INPUT)).unwrap();
+        assert_eq!(plugin.info.version, "1.2.3");
+        assert_eq!(plugin.info.commands, ["count"]);
+        assert_eq!(plugin.info.capability_names(), ["commands", "reads-input"]);
+    }
+
+    #[test]
+    fn commands_run_through_the_abi() {
+        let mut registry = Registry::new();
+        let plugin = read(&declaration(ABI_VERSION, COMMANDS | READS_INPUT)).unwrap();
+        register(&mut registry, plugin).unwrap();
+
+        let count = registry.get("count").unwrap();
+        assert_eq!(run_line(count, "", "x\ny\n").unwrap(), "2 lines\n");
+
+        // Input the command does not ask for is never read, stdin would block on it.
+        let args: Vec<String> = ["count", "a", "b"].map(String::from).to_vec();
+        let mut out = Vec::new();
+        registry
+            .dispatch("tool", &args, &mut Untouchable, &mut out)
+            .unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "2 words\n");
+        let err = registry
+            .dispatch(
+                "tool",
+                &["count".to_string()],
+                &mut Untouchable,
+                &mut Vec::new(),
+            )
+            .unwrap_err();
+        assert!(matches!(err, CommandError::Io(_)));
+
+        let err = run_line(count, "--bad", "").unwrap_err();
+        assert!(matches!(err, CommandError::Usage(message) if message == "count takes words"));
+    }
+
+    #[test]
+    fn input_is_only_read_with_the_capability() {
+        let mut registry = Registry::new();
+        register(
+            &mut registry,
+            read(&declaration(ABI_VERSION, COMMANDS)).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            run_line(registry.get("count").unwrap(), "", "x\n").unwrap(),
+            "0 lines\n"
+        );
+    }
+
+    #[test]
+    fn taken_names_reject_the_whole_plugin() {
+        let mut registry = Registry::new();
+        register(
+            &mut registry,
+            read(&declaration(ABI_VERSION, COMMANDS)).unwrap(),
+        )
+        .unwrap();
+        let again = read(&declaration(ABI_VERSION, COMMANDS)).unwrap();
+        assert_eq!(
+            register(&mut registry, again).err(),
+            Some(LoadError::NameTaken("count".to_string()))
+        );
+        assert_eq!(registry.len(), 1);
+    }
+
+    #[test]
+    fn files_that_are_not_plugins_are_rejected() {
+        let dir = std::env::temp_dir().join(format!("managin_plugins_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let junk = dir.join(format!("junk.{}", std::env::consts::DLL_EXTENSION));
+        fs::write(&junk, "not a library").unwrap();
+        fs::write(dir.join("notes.txt"), "not even a candidate").unwrap();
+
+        let mut registry = Registry::new();
+        let report = load_into(&mut registry, &dir);
+        fs::remove_dir_all(&dir).unwrap();
+        assert!(report.loaded.is_empty());
+        assert_eq!(report.rejected.len(), 1);
+        assert_eq!(report.rejected[0].path, junk);
+        assert!(matches!(report.rejected[0].error, LoadError::Open(_)));
+        assert!(load_into(&mut registry, &dir).rejected.is_empty());
+    }
+}
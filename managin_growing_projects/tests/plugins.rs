@@ -0,0 +1,93 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 06:21:21
+/// @Description: Loading the Sample Plugin
+/*
+- Builds sample_plugin as a real shared library and loads it the way main.rs does, next to a file that only looks like a library. Its commands are run
+  through the managin_growing_projects binary, with MANAGIN_PLUGINS pointing at the directory.
+- The plugin is built into its own target directory, the cargo running the tests holds the lock of the normal one.
+*/
+use managin_growing_projects::{builtin, load_into, LoadError};
+use std::env::consts::{DLL_EXTENSION, DLL_PREFIX, DLL_SUFFIX};
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+fn plugin_dir() -> PathBuf {
+    let target = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("plugin-build");
+    let status = Command::new(std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()))
+        .args(["build", "--offline", "-p", "sample_plugin", "--target-dir"])
+        .arg(&target)
+        .status()
+        .expect("cargo runs");
+    assert!(status.success(), "sample_plugin builds");
+
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("plugins");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    let library = format!("{}sample_plugin{}", DLL_PREFIX, DLL_SUFFIX);
+    fs::copy(target.join("debug").join(&library), dir.join(&library)).unwrap();
+    fs::write(
+        dir.join(format!("broken.{}", DLL_EXTENSION)),
+        "not a library",
+    )
+    .unwrap();
+    dir
+}
+
+// Runs the tool the way a user does, with the plugins of dir. Returns the exit code and what it printed.
+fn tool(dir: &Path, line: &str, input: &str) -> (i32, String) {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_managin_growing_projects"))
+        .args(line.split_whitespace())
+        .env("MANAGIN_PLUGINS", dir)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("the tool starts");
+    // A command that does not read its input may be gone before it is written.
+    let _ = child.stdin.take().unwrap().write_all(input.as_bytes());
+    let output = child.wait_with_output().unwrap();
+    (
+        output.status.code().unwrap(),
+        String::from_utf8(output.stdout).unwrap(),
+    )
+}
+
+#[test]
+fn the_sample_plugin_loads_and_runs() {
+    let dir = plugin_dir();
+    let mut registry = builtin();
+    let report = load_into(&mut registry, &dir);
+
+    assert_eq!(report.loaded.len(), 1);
+    let info = &report.loaded[0];
+    assert_eq!(
+        (info.name.as_str(), info.version.as_str()),
+        ("text tools", "0.1.0")
+    );
+    assert_eq!(info.commands, ["shout", "reverse"]);
+    assert_eq!(info.capability_names(), ["commands", "reads-input"]);
+    assert_eq!(report.rejected.len(), 1);
+    assert!(matches!(report.rejected[0].error, LoadError::Open(_)));
+
+    assert_eq!(
+        tool(&dir, "shout hello there", ""),
+        (0, "HELLO THERE\n".to_string())
+    );
+    assert_eq!(tool(&dir, "shout", "quiet\n"), (0, "QUIET\n".to_string()));
+    assert_eq!(
+        tool(&dir, "reverse", "a\nb\nc\n"),
+        (0, "c\nb\na\n".to_string())
+    );
+    assert_eq!(tool(&dir, "reverse x", "").0, 2);
+    assert!(tool(&dir, "help", "").1.contains("shout"));
+
+    // Loading the same directory again finds the names taken.
+    let again = load_into(&mut registry, &dir);
+    assert!(again.loaded.is_empty());
+    assert!(again
+        .rejected
+        .iter()
+        .any(|rejection| rejection.error == LoadError::NameTaken("shout".to_string())));
+}
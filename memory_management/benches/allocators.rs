@@ -0,0 +1,125 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 06:04:54
+/// @Description: Benchmark of the Allocators
+/*
+- Run with: cargo bench --bench allocators [-- NODES]. NODES defaults to 10^6.
+- Every allocator builds NODES small nodes, the way a parser builds the nodes of a syntax tree, reads them all back and frees them: one Box per node, one Vec
+  of nodes, the typed Arena, the Bump allocator and the object Pool.
+- Each measurement is the best of a few runs. The benchmark runs with TrackingAllocator, so next to the time it shows how many allocations the run made.
+*/
+use fundamentals_core::{Align, Table};
+use memory_management::{Arena, Bump, Pool, Scope, TrackingAllocator};
+use std::env;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+#[global_allocator]
+static ALLOCATOR: TrackingAllocator = TrackingAllocator;
+
+const RUNS: u32 = 5;
+
+type Benchmark = (&'static str, fn(u64) -> u64);
+
+#[derive(Clone, Copy)]
+struct Node {
+    value: u64,
+    weight: u64,
+}
+
+fn node(i: u64) -> Node {
+    Node {
+        value: i,
+        weight: i % 7,
+    }
+}
+
+fn sum<'a, I: Iterator<Item = &'a Node>>(nodes: I) -> u64 {
+    nodes.map(|node| node.value * node.weight).sum()
+}
+
+fn boxed(n: u64) -> u64 {
+    let nodes: Vec<Box<Node>> = (0..n).map(|i| Box::new(node(i))).collect();
+    sum(nodes.iter().map(|node| &**node))
+}
+
+fn vec(n: u64) -> u64 {
+    let mut nodes = Vec::new();
+    for i in 0..n {
+        nodes.push(node(i));
+    }
+    sum(nodes.iter())
+}
+
+fn arena(n: u64) -> u64 {
+    let arena = Arena::new();
+    sum((0..n).map(|i| &*arena.alloc(node(i))))
+}
+
+fn bump(n: u64) -> u64 {
+    let bump = Bump::new();
+    sum((0..n).map(|i| &*bump.alloc(node(i))))
+}
+
+fn pool(n: u64) -> u64 {
+    let mut pool = Pool::with_capacity(n as usize);
+    let handles: Vec<_> = (0..n)
+        .map(|i| {
+            pool.insert(node(i))
+                .ok()
+                .expect("the pool has room for every node")
+        })
+        .collect();
+    let total = sum(handles.iter().map(|&handle| pool.get(handle).unwrap()));
+    for handle in handles {
+        pool.remove(handle);
+    }
+    total
+}
+
+fn best_of(runs: u32, n: u64, f: fn(u64) -> u64) -> (Duration, u64, u64) {
+    let mut best = Duration::MAX;
+    let mut result = 0;
+    let mut allocations = 0;
+    for _ in 0..runs {
+        let scope = Scope::new("run");
+        let start = Instant::now();
+        result = black_box(f(black_box(n)));
+        best = best.min(start.elapsed());
+        allocations = scope.finish().allocations;
+    }
+    (best, result, allocations)
+}
+
+fn main() {
+    // cargo bench passes --bench to the binary, anything numeric is the number of nodes.
+    let n = env::args()
+        .skip(1)
+        .find_map(|arg| arg.parse::<u64>().ok())
+        .unwrap_or(1_000_000);
+    let benchmarks: [Benchmark; 5] = [
+        ("box", boxed),
+        ("vec", vec),
+        ("arena", arena),
+        ("bump", bump),
+        ("pool", pool),
+    ];
+
+    println!("{} nodes", n);
+    let table = Table::new()
+        .column("", 8, Align::Right)
+        .column("time", 14, Align::Right)
+        .column("allocations", 14, Align::Right);
+    println!("{}", table.header());
+    let mut expected = None;
+    for (name, f) in benchmarks {
+        let (elapsed, result, allocations) = best_of(RUNS, n, f);
+        println!(
+            "{}",
+            table.row(&[&name, &format!("{:.3?}", elapsed), &allocations])
+        );
+        match expected {
+            None => expected = Some(result),
+            Some(expected) => assert_eq!(result, expected, "{} disagrees", name),
+        }
+    }
+}
@@ -0,0 +1,160 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 06:04:54
+/// @Description: Typed Arena
+/*
+- An Arena<T> hands out &mut T for values moved into it and frees them all at once when it is dropped. Allocating is a push onto a Vec, there is no
+  allocation per value and no bookkeeping to free them one by one.
+- The values live in chunks. A chunk is a Vec that is never pushed past its capacity, so it never moves its values and the references to them stay valid. A
+  full chunk is kept and a new one with twice the capacity is started, so the number of chunks stays logarithmic in the number of values.
+- The references borrow the arena, so they cannot outlive it. Values can refer to each other as long as they live in the same arena (&'a T inside T).
+- Dropping the arena drops all values in it, in the order they were allocated.
+*/
+use std::cell::RefCell;
+
+const FIRST_CHUNK: usize = 64;
+
+pub struct Arena<T> {
+    chunks: RefCell<Chunks<T>>,
+}
+
+// Fields drop in the order they are declared, full comes first so the values drop in the order they were allocated. A Drop impl on the arena
+// could not do this, it would stop values from borrowing each other.
+struct Chunks<T> {
+    full: Vec<Vec<T>>,
+    current: Vec<T>,
+}
+
+impl<T> Arena<T> {
+    pub fn new() -> Arena<T> {
+        Arena::with_capacity(FIRST_CHUNK)
+    }
+
+    pub fn with_capacity(capacity: usize) -> Arena<T> {
+        Arena {
+            chunks: RefCell::new(Chunks {
+                current: Vec::with_capacity(capacity.max(1)),
+                full: Vec::new(),
+            }),
+        }
+    }
+
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc(&self, value: T) -> &mut T {
+        let mut chunks = self.chunks.borrow_mut();
+        if chunks.current.len() == chunks.current.capacity() {
+            let capacity = chunks.current.capacity() * 2;
+            let full = std::mem::replace(&mut chunks.current, Vec::with_capacity(capacity));
+            chunks.full.push(full);
+        }
+        let index = chunks.current.len();
+        chunks.current.push(value);
+        // The push did not reallocate, there was room for it, and the chunk is never pushed to past its capacity or shrunk while the arena lives. Every
+        // value is handed out once, so the &mut does not alias.
+        unsafe { &mut *chunks.current.as_mut_ptr().add(index) }
+    }
+
+    pub fn len(&self) -> usize {
+        let chunks = self.chunks.borrow();
+        chunks.current.len() + chunks.full.iter().map(Vec::len).sum::<usize>()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // Takes the values back out, in the order they were allocated. Needs the arena by value, so no reference into it is left.
+    pub fn into_vec(self) -> Vec<T> {
+        let chunks = self.chunks.into_inner();
+        let mut values: Vec<T> = chunks.full.into_iter().flatten().collect();
+        values.extend(chunks.current);
+        values
+    }
+}
+
+impl<T> Default for Arena<T> {
+    fn default() -> Arena<T> {
+        Arena::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    struct Node<'a> {
+        value: u32,
+        next: Option<&'a Node<'a>>,
+    }
+
+    #[test]
+    fn values_keep_their_place_across_chunks() {
+        let arena = Arena::with_capacity(2);
+        let first = arena.alloc(Node {
+            value: 0,
+            next: None,
+        });
+        let mut last: &Node = first;
+        for value in 1..100 {
+            last = arena.alloc(Node {
+                value,
+                next: Some(last),
+            });
+        }
+        let mut sum = 0;
+        let mut node = Some(last);
+        while let Some(current) = node {
+            sum += current.value;
+            node = current.next;
+        }
+        assert_eq!(sum, 99 * 100 / 2);
+        assert_eq!(first.value, 0);
+        assert_eq!(arena.len(), 100);
+    }
+
+    #[test]
+    fn dropping_the_arena_drops_every_value() {
+        struct Counted<'a>(&'a Cell<u32>);
+        impl Drop for Counted<'_> {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        let drops = Cell::new(0);
+        let arena = Arena::with_capacity(3);
+        for _ in 0..10 {
+            arena.alloc(Counted(&drops));
+        }
+        assert_eq!(drops.get(), 0);
+        drop(arena);
+        assert_eq!(drops.get(), 10);
+    }
+
+    #[test]
+    fn values_drop_in_allocation_order() {
+        struct Logged<'a>(u32, &'a RefCell<Vec<u32>>);
+        impl Drop for Logged<'_> {
+            fn drop(&mut self) {
+                self.1.borrow_mut().push(self.0);
+            }
+        }
+
+        let log = RefCell::new(Vec::new());
+        let arena = Arena::with_capacity(2);
+        for value in 0..9 {
+            arena.alloc(Logged(value, &log));
+        }
+        drop(arena);
+        assert_eq!(*log.borrow(), (0..9).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn into_vec_keeps_the_order() {
+        let arena = Arena::with_capacity(1);
+        for value in 0..20 {
+            *arena.alloc(value) += 1;
+        }
+        assert_eq!(arena.into_vec(), (1..21).collect::<Vec<_>>());
+    }
+}
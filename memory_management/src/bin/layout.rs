@@ -0,0 +1,15 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 06:06:20
+/// @Description: Layout Inspector
+/*
+- cargo run --bin layout prints the layout of String, Vec, Box, Rc, structs with padding and enums with and without niches as ASCII diagrams.
+- In the stack row every character is a byte: a letter for each inline field, P for a pointer and '.' for padding. Below it the heap row shows the bytes the
+  pointer leads to, '#' for used and '-' for allocated but unused.
+*/
+use memory_management::layout::examples;
+
+fn main() {
+    for layout in examples() {
+        println!("{}", layout);
+    }
+}
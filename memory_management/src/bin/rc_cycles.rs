@@ -0,0 +1,73 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 06:10:48
+/// @Description: Drop Order and Reference Cycles
+/*
+- cargo run --bin rc_cycles prints every step of the hand-rolled smart pointers as it happens: a MyBox dropped at the end of its scope, a tree whose
+  children point back to their parent with MyWeak, and two nodes that hold each other with MyRc.
+- The tree is freed completely when it goes out of scope. The two nodes never are, and the LeakCheck made first in main reports them as a cycle when it is
+  dropped last at the end of main.
+*/
+use memory_management::{set_echo, LeakCheck, MyBox, MyRc, MyWeak, Trace};
+use std::cell::RefCell;
+
+struct Node {
+    name: &'static str,
+    parent: RefCell<Option<MyWeak<Node>>>,
+    children: RefCell<Vec<MyRc<Node>>>,
+}
+
+impl Node {
+    fn new(name: &'static str) -> MyRc<Node> {
+        MyRc::traced(Node {
+            name,
+            parent: RefCell::new(None),
+            children: RefCell::new(Vec::new()),
+        })
+    }
+
+    fn adopt(parent: &MyRc<Node>, child: MyRc<Node>) {
+        *child.parent.borrow_mut() = Some(MyRc::downgrade(parent));
+        parent.children.borrow_mut().push(child);
+    }
+}
+
+impl Trace for Node {
+    fn children(&self) -> Vec<u64> {
+        self.children.borrow().iter().map(MyRc::id).collect()
+    }
+}
+
+fn main() {
+    let _leaks = LeakCheck::new();
+    set_echo(true);
+
+    println!("A box is dropped at the end of its scope:");
+    {
+        let boxed = MyBox::new(String::from("Heap_Data"));
+        println!("  it holds {}", *boxed);
+    }
+
+    println!("A parent owns its children, the children only look back at it:");
+    {
+        let root = Node::new("root");
+        Node::adopt(&root, Node::new("leaf"));
+        let leaf = MyRc::clone(&root.children.borrow()[0]);
+        let parent = leaf.parent.borrow().as_ref().and_then(MyWeak::upgrade);
+        println!(
+            "  the parent of {} is {}",
+            leaf.name,
+            parent.map_or("nobody", |parent| parent.name)
+        );
+    }
+
+    println!("Two nodes that own each other are never dropped:");
+    {
+        let a = Node::new("a");
+        let b = Node::new("b");
+        a.children.borrow_mut().push(MyRc::clone(&b));
+        b.children.borrow_mut().push(MyRc::clone(&a));
+    }
+
+    set_echo(false);
+    println!("At the end of main:");
+}
@@ -0,0 +1,232 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 06:04:54
+/// @Description: Bump Allocator
+/*
+- A Bump hands out memory for values of any type from big chunks. Allocating rounds the offset in the current chunk up to the alignment of the value and
+  moves it past the value, that is all. A chunk that is too full gets a successor twice its size (or big enough for the value).
+- Values in a Bump are never dropped, only their memory is given back. That is right for plain data like the nodes of a parser; anything that owns memory
+  elsewhere, like a String, leaks what it owns. Slices and strings are copied in with alloc_slice_copy and alloc_str.
+- The memory comes back all at once:
+    -> reset() keeps the biggest chunk for the next round and frees the rest. It needs &mut self, so no reference into the bump can be left.
+    -> scope() runs a closure with the bump and frees what the closure allocated when it returns. The result of the closure cannot borrow from the bump.
+    -> dropping the bump frees every chunk.
+- The chunks are raw memory from std::alloc, the bump never makes references to a whole chunk, so handing out &mut to parts of it does not alias.
+*/
+use std::alloc::{self, Layout};
+use std::cell::{Cell, RefCell};
+use std::ptr::{self, NonNull};
+
+const FIRST_CHUNK: usize = 4096;
+const CHUNK_ALIGN: usize = 16;
+
+struct Chunk {
+    start: NonNull<u8>,
+    layout: Layout,
+}
+
+impl Chunk {
+    fn new(size: usize) -> Chunk {
+        let layout = Layout::from_size_align(size, CHUNK_ALIGN).expect("chunk size overflows");
+        // The size is never 0, every chunk is at least as big as the first one.
+        let start = unsafe { alloc::alloc(layout) };
+        let start = NonNull::new(start).unwrap_or_else(|| alloc::handle_alloc_error(layout));
+        Chunk { start, layout }
+    }
+
+    fn size(&self) -> usize {
+        self.layout.size()
+    }
+}
+
+impl Drop for Chunk {
+    fn drop(&mut self) {
+        unsafe { alloc::dealloc(self.start.as_ptr(), self.layout) }
+    }
+}
+
+pub struct Bump {
+    chunks: RefCell<Vec<Chunk>>,
+    // Bytes used in the last chunk.
+    offset: Cell<usize>,
+}
+
+// Where a scope started: the number of chunks and the offset in the last of them.
+struct Checkpoint {
+    chunks: usize,
+    offset: usize,
+}
+
+impl Bump {
+    pub fn new() -> Bump {
+        Bump::with_capacity(FIRST_CHUNK)
+    }
+
+    pub fn with_capacity(capacity: usize) -> Bump {
+        Bump {
+            chunks: RefCell::new(vec![Chunk::new(capacity.max(CHUNK_ALIGN))]),
+            offset: Cell::new(0),
+        }
+    }
+
+    fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
+        let mut chunks = self.chunks.borrow_mut();
+        let chunk = chunks.last().expect("a bump always has a chunk");
+        let address = chunk.start.as_ptr() as usize + self.offset.get();
+        let padding = address.next_multiple_of(layout.align()) - address;
+        if self.offset.get() + padding + layout.size() <= chunk.size() {
+            let offset = self.offset.get() + padding;
+            self.offset.set(offset + layout.size());
+            return unsafe { NonNull::new_unchecked(chunk.start.as_ptr().add(offset)) };
+        }
+
+        let needed = layout.size() + layout.align();
+        let size = (chunk.size() * 2).max(needed.next_power_of_two());
+        let chunk = Chunk::new(size);
+        let address = chunk.start.as_ptr() as usize;
+        let offset = address.next_multiple_of(layout.align()) - address;
+        self.offset.set(offset + layout.size());
+        let pointer = unsafe { NonNull::new_unchecked(chunk.start.as_ptr().add(offset)) };
+        chunks.push(chunk);
+        pointer
+    }
+
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc<T>(&self, value: T) -> &mut T {
+        let pointer = self.alloc_layout(Layout::new::<T>()).cast::<T>();
+        unsafe {
+            pointer.as_ptr().write(value);
+            &mut *pointer.as_ptr()
+        }
+    }
+
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_slice_copy<T: Copy>(&self, values: &[T]) -> &mut [T] {
+        let layout = Layout::for_value(values);
+        let pointer = self.alloc_layout(layout).cast::<T>();
+        unsafe {
+            ptr::copy_nonoverlapping(values.as_ptr(), pointer.as_ptr(), values.len());
+            std::slice::from_raw_parts_mut(pointer.as_ptr(), values.len())
+        }
+    }
+
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_str(&self, text: &str) -> &mut str {
+        let bytes = self.alloc_slice_copy(text.as_bytes());
+        // A copy of the bytes of a str is valid UTF-8.
+        unsafe { std::str::from_utf8_unchecked_mut(bytes) }
+    }
+
+    // Bytes of all chunks, used or not.
+    pub fn capacity(&self) -> usize {
+        self.chunks.borrow().iter().map(Chunk::size).sum()
+    }
+
+    // Bytes handed out so far, with the padding for alignment and the unused ends of full chunks.
+    pub fn used(&self) -> usize {
+        let chunks = self.chunks.borrow();
+        let full: usize = chunks[..chunks.len() - 1].iter().map(Chunk::size).sum();
+        full + self.offset.get()
+    }
+
+    pub fn reset(&mut self) {
+        let chunks = self.chunks.get_mut();
+        let biggest = (0..chunks.len())
+            .max_by_key(|&i| chunks[i].size())
+            .expect("a bump always has a chunk");
+        let kept = chunks.swap_remove(biggest);
+        chunks.clear();
+        chunks.push(kept);
+        self.offset.set(0);
+    }
+
+    pub fn scope<R, F: FnOnce(&Bump) -> R>(&mut self, f: F) -> R {
+        let checkpoint = Checkpoint {
+            chunks: self.chunks.get_mut().len(),
+            offset: self.offset.get(),
+        };
+        let result = f(self);
+        self.chunks.get_mut().truncate(checkpoint.chunks);
+        self.offset.set(checkpoint.offset);
+        result
+    }
+}
+
+impl Default for Bump {
+    fn default() -> Bump {
+        Bump::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn values_are_aligned_and_do_not_overlap() {
+        let bump = Bump::with_capacity(64);
+        let byte = bump.alloc(1u8);
+        let wide = bump.alloc(2u128);
+        let pair = bump.alloc((3u16, 4u64));
+        assert_eq!(
+            wide as *const u128 as usize % std::mem::align_of::<u128>(),
+            0
+        );
+        assert_eq!(
+            pair as *const (u16, u64) as usize % std::mem::align_of::<(u16, u64)>(),
+            0
+        );
+        *byte += 1;
+        *wide += 1;
+        pair.0 += 1;
+        assert_eq!((*byte, *wide, *pair), (2, 3, (4, 4)));
+    }
+
+    #[test]
+    fn many_values_grow_new_chunks() {
+        let bump = Bump::with_capacity(64);
+        let values: Vec<&mut u64> = (0..1000).map(|i| bump.alloc(i)).collect();
+        assert!(values
+            .iter()
+            .enumerate()
+            .all(|(i, value)| **value == i as u64));
+        assert!(bump.capacity() >= 8000);
+        assert!(bump.used() >= 8000);
+        let big = bump.alloc_slice_copy(&[7u8; 100_000]);
+        assert_eq!(big.len(), 100_000);
+        assert_eq!(*values[999], 999);
+    }
+
+    #[test]
+    fn strings_and_slices() {
+        let bump = Bump::new();
+        let greeting = bump.alloc_str("Heap_Data");
+        greeting.make_ascii_uppercase();
+        assert_eq!(greeting, "HEAP_DATA");
+        assert_eq!(bump.alloc_slice_copy(&[1, 2, 3]), &[1, 2, 3]);
+        assert_eq!(bump.alloc_slice_copy::<u32>(&[]), &[] as &[u32]);
+    }
+
+    #[test]
+    fn reset_keeps_the_biggest_chunk() {
+        let mut bump = Bump::with_capacity(64);
+        for i in 0..1000u64 {
+            bump.alloc(i);
+        }
+        let biggest = bump.chunks.borrow().iter().map(Chunk::size).max().unwrap();
+        bump.reset();
+        assert_eq!(bump.used(), 0);
+        assert_eq!(bump.capacity(), biggest);
+    }
+
+    #[test]
+    fn scope_frees_what_it_allocated() {
+        let mut bump = Bump::with_capacity(64);
+        let outer = *bump.alloc(5u64);
+        let before = bump.used();
+        let sum = bump.scope(|bump| (0..1000u64).map(|i| *bump.alloc(i)).sum::<u64>());
+        assert_eq!(sum, 999 * 1000 / 2);
+        assert_eq!(bump.used(), before);
+        assert_eq!(bump.chunks.borrow().len(), 1);
+        assert_eq!(outer, 5);
+    }
+}
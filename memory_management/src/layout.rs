@@ -0,0 +1,600 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 06:06:20
+/// @Description: Stack and Heap Layout Inspector
+/*
+- TypeLayout describes where the bytes of a value are: its size and alignment, what every byte of the inline part (the part on the stack, or wherever the
+  value itself is) is used for, and what the value points to on the heap. Its Display is an ASCII diagram.
+- For our own structs the offsets come from std::mem::offset_of!, the gaps between fields and at the end are padding. Rust may reorder the fields of a
+  struct to fill gaps, #[repr(C)] keeps them in the order they are written.
+- The field order of String, Vec, Box and Rc is not guaranteed by the language, so it is not assumed. The inspector reads the words of a real value and
+  recognises them: the word equal to the address of the data is the pointer, the others are matched against the length and the capacity.
+- An enum needs room for a tag to tell its variants apart, unless one of its fields has a niche: a bit pattern the field can never have, like 0 for a
+  reference, Box or NonZero, or anything but 0 and 1 for bool. The tag is then stored in the niche and the enum is no bigger than the field.
+*/
+use std::fmt;
+use std::mem::{align_of, size_of, size_of_val};
+use std::rc::Rc;
+
+const WORD: usize = size_of::<usize>();
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Storage {
+    // The bytes of the field are the data.
+    Inline,
+    // The field holds the address of data somewhere else.
+    Pointer,
+    Padding,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Part {
+    pub name: String,
+    pub offset: usize,
+    pub size: usize,
+    pub storage: Storage,
+    // What was read from the value, like the length or the address.
+    pub value: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Heap {
+    pub description: String,
+    // Bytes used of the bytes allocated.
+    pub used: usize,
+    pub allocated: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeLayout {
+    pub name: String,
+    pub size: usize,
+    pub align: usize,
+    pub parts: Vec<Part>,
+    pub heap: Option<Heap>,
+    pub notes: Vec<String>,
+}
+
+impl TypeLayout {
+    pub fn of<T>(name: &str) -> TypeLayout {
+        TypeLayout {
+            name: name.to_string(),
+            size: size_of::<T>(),
+            align: align_of::<T>(),
+            parts: Vec::new(),
+            heap: None,
+            notes: Vec::new(),
+        }
+    }
+
+    // Adds the fields, given as (name, offset, size, storage) in any order, and fills the gaps between them with padding.
+    pub fn with_fields(mut self, fields: &[(&str, usize, usize, Storage)]) -> TypeLayout {
+        let mut fields = fields.to_vec();
+        fields.sort_by_key(|&(_, offset, ..)| offset);
+        let mut end = 0;
+        for (name, offset, size, storage) in fields {
+            if offset > end {
+                self.parts.push(padding(end, offset - end));
+            }
+            self.parts.push(Part {
+                name: name.to_string(),
+                offset,
+                size,
+                storage,
+                value: None,
+            });
+            end = end.max(offset + size);
+        }
+        if self.size > end {
+            self.parts.push(padding(end, self.size - end));
+        }
+        self
+    }
+
+    pub fn note(mut self, note: &str) -> TypeLayout {
+        self.notes.push(note.to_string());
+        self
+    }
+
+    pub fn padding_bytes(&self) -> usize {
+        self.parts
+            .iter()
+            .filter(|part| part.storage == Storage::Padding)
+            .map(|part| part.size)
+            .sum()
+    }
+
+    pub fn is_inline(&self) -> bool {
+        self.parts
+            .iter()
+            .all(|part| part.storage != Storage::Pointer)
+    }
+}
+
+fn padding(offset: usize, size: usize) -> Part {
+    Part {
+        name: "padding".to_string(),
+        offset,
+        size,
+        storage: Storage::Padding,
+        value: None,
+    }
+}
+
+//------------------------------------------------------------Pointer Types----------------------------------------------------------
+
+// The words of a value of three words or fewer. Reading them as integers is fine, every byte of these types is initialised and they have no padding.
+fn words<T>(value: &T) -> Vec<usize> {
+    assert!(size_of::<T>().is_multiple_of(WORD) && size_of::<T>() <= 4 * WORD);
+    let pointer = value as *const T as *const usize;
+    (0..size_of::<T>() / WORD)
+        .map(|i| unsafe { pointer.add(i).read_unaligned() })
+        .collect()
+}
+
+// Names the words of a String or Vec by their values. Length and capacity can only be told apart when they differ.
+fn buffer_parts(words: &[usize], data: usize, len: usize, capacity: usize) -> Vec<Part> {
+    let mut pointer_seen = false;
+    let mut parts: Vec<Part> = Vec::new();
+    for (i, &word) in words.iter().enumerate() {
+        let (name, storage, value) = if word == data && !pointer_seen {
+            pointer_seen = true;
+            ("pointer", Storage::Pointer, format!("{:#x}", word))
+        } else if len == capacity {
+            ("length/capacity", Storage::Inline, word.to_string())
+        } else if word == len {
+            ("length", Storage::Inline, word.to_string())
+        } else {
+            ("capacity", Storage::Inline, word.to_string())
+        };
+        parts.push(Part {
+            name: name.to_string(),
+            offset: i * WORD,
+            size: WORD,
+            storage,
+            value: Some(value),
+        });
+    }
+    parts
+}
+
+pub fn inspect_vec<T>(name: &str, vector: &Vec<T>) -> TypeLayout {
+    let mut layout = TypeLayout::of::<Vec<T>>(name);
+    layout.parts = buffer_parts(
+        &words(vector),
+        vector.as_ptr() as usize,
+        vector.len(),
+        vector.capacity(),
+    );
+    let allocated = vector.capacity() * size_of::<T>();
+    layout.heap = (allocated > 0).then(|| Heap {
+        description: format!("{} elements of {} bytes", vector.len(), size_of::<T>()),
+        used: vector.len() * size_of::<T>(),
+        allocated,
+    });
+    if allocated == 0 {
+        layout.notes.push(
+            "An empty Vec allocates nothing, its pointer is a dangling but aligned address."
+                .to_string(),
+        );
+    }
+    layout
+}
+
+pub fn inspect_string(name: &str, string: &String) -> TypeLayout {
+    let mut layout = TypeLayout::of::<String>(name);
+    layout.parts = buffer_parts(
+        &words(string),
+        string.as_ptr() as usize,
+        string.len(),
+        string.capacity(),
+    );
+    layout.heap = Some(Heap {
+        description: format!("UTF-8 bytes {:?}", string),
+        used: string.len(),
+        allocated: string.capacity(),
+    });
+    layout
+        .notes
+        .push("A String is a Vec<u8> that holds valid UTF-8. A string literal (&str) is only a pointer and a length, its bytes are in the binary.".to_string());
+    layout
+}
+
+// Takes the Box itself, not what is in it, it is the Box that is inspected.
+#[allow(clippy::borrowed_box)]
+pub fn inspect_box<T: ?Sized>(name: &str, boxed: &Box<T>) -> TypeLayout {
+    let mut layout = TypeLayout::of::<Box<T>>(name);
+    let words = words(boxed);
+    let data = &**boxed as *const T as *const u8 as usize;
+    for (i, &word) in words.iter().enumerate() {
+        let (name, storage, value) = if word == data {
+            ("pointer", Storage::Pointer, format!("{:#x}", word))
+        } else {
+            // The second word of a fat pointer: the length of a slice or the vtable of a trait object.
+            ("metadata", Storage::Inline, format!("{:#x}", word))
+        };
+        layout.parts.push(Part {
+            name: name.to_string(),
+            offset: i * WORD,
+            size: WORD,
+            storage,
+            value: Some(value),
+        });
+    }
+    let size = size_of_val(&**boxed);
+    layout.heap = Some(Heap {
+        description: format!("the boxed value, {} bytes", size),
+        used: size,
+        allocated: size,
+    });
+    if words.len() > 1 {
+        layout.notes.push("The type behind the Box has no size known at compile time, so the Box is a fat pointer.".to_string());
+    }
+    layout
+}
+
+pub fn inspect_rc<T>(name: &str, rc: &Rc<T>) -> TypeLayout {
+    let mut layout = TypeLayout::of::<Rc<T>>(name);
+    let word = words(rc)[0];
+    layout.parts.push(Part {
+        name: "pointer".to_string(),
+        offset: 0,
+        size: WORD,
+        storage: Storage::Pointer,
+        value: Some(format!("{:#x}", word)),
+    });
+    // The allocation starts with the two counts, the value comes after them.
+    let allocated = 2 * WORD + size_of::<T>().next_multiple_of(WORD.max(align_of::<T>()));
+    layout.heap = Some(Heap {
+        description: format!(
+            "strong count {}, weak count {}, then the value of {} bytes at +{}",
+            Rc::strong_count(rc),
+            Rc::weak_count(rc),
+            size_of::<T>(),
+            Rc::as_ptr(rc) as usize - word
+        ),
+        used: 2 * WORD + size_of::<T>(),
+        allocated,
+    });
+    layout.notes.push(
+        "Clones of an Rc copy the pointer and count up the strong count, the value is shared."
+            .to_string(),
+    );
+    layout
+}
+
+// Compares Option<T> with T: the same size means the tag went into a niche of T. The storage is that of T, Pointer for a Box or a reference.
+pub fn inspect_niche<T>(name: &str, storage: Storage) -> TypeLayout {
+    let option = format!("Option<{}>", name);
+    let layout = TypeLayout::of::<Option<T>>(&option);
+    let (inner, outer) = (size_of::<T>(), size_of::<Option<T>>());
+    let layout = if inner == outer {
+        layout
+            .with_fields(&[("value or None", 0, inner, storage)])
+            .note(&format!(
+                "{} has a niche, None is stored in it, so {} costs no extra bytes.",
+                name, option
+            ))
+    } else {
+        // Where the compiler puts the tag is not guaranteed, the diagram only shows that it needs room.
+        layout
+            .with_fields(&[
+                ("tag", 0, 1, Storage::Inline),
+                ("value", outer - inner, inner, storage),
+            ])
+            .note(&format!(
+                "{} has no niche, the tag takes {} more bytes with the padding to keep the value aligned.",
+                name,
+                outer - inner
+            ))
+    };
+    layout
+}
+
+//------------------------------------------------------------Diagram----------------------------------------------------------
+
+// The character of every part in the diagram: a letter for each inline part in turn, P for pointers and '.' for padding.
+fn symbols(parts: &[Part]) -> Vec<char> {
+    let mut letters = (b'a'..=b'z').cycle();
+    parts
+        .iter()
+        .map(|part| match part.storage {
+            Storage::Padding => '.',
+            Storage::Pointer => 'P',
+            Storage::Inline => letters.next().unwrap() as char,
+        })
+        .collect()
+}
+
+impl fmt::Display for TypeLayout {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "{}: size {} bytes, align {}, {}",
+            self.name,
+            self.size,
+            self.align,
+            match (self.is_inline(), &self.heap) {
+                (true, _) => "all inline",
+                (false, Some(_)) => "points to the heap",
+                (false, None) => "holds a pointer",
+            }
+        )?;
+
+        // One character per byte, a space between the parts.
+        let symbols = symbols(&self.parts);
+        let mut bytes = String::new();
+        let mut offsets = String::new();
+        for (part, &symbol) in self.parts.iter().zip(&symbols) {
+            let cell: String = std::iter::repeat_n(symbol, part.size).collect();
+            offsets.push_str(&format!("{:<width$}", part.offset, width = part.size + 1));
+            bytes.push_str(&cell);
+            bytes.push(' ');
+        }
+        if !self.parts.is_empty() {
+            writeln!(f, "  offset {}", offsets.trim_end())?;
+            writeln!(f, "  stack [{}]", bytes.trim_end())?;
+        }
+        for (part, symbol) in self.parts.iter().zip(symbols) {
+            write!(
+                f,
+                "    {} {:>3}..{:<3} {}",
+                symbol,
+                part.offset,
+                part.offset + part.size,
+                part.name
+            )?;
+            match &part.value {
+                Some(value) => writeln!(f, " = {}", value)?,
+                None => writeln!(f)?,
+            }
+        }
+
+        if let Some(heap) = &self.heap {
+            writeln!(f, "          |")?;
+            writeln!(f, "          v")?;
+            let used: String = "#".repeat(heap.used.min(64));
+            let free: String = "-".repeat(
+                heap.allocated
+                    .saturating_sub(heap.used)
+                    .min(64 - used.len()),
+            );
+            writeln!(f, "  heap  [{}{}]", used, free)?;
+            writeln!(
+                f,
+                "    {} of {} bytes used: {}",
+                heap.used, heap.allocated, heap.description
+            )?;
+        }
+        for note in &self.notes {
+            writeln!(f, "  {}", note)?;
+        }
+        Ok(())
+    }
+}
+
+//------------------------------------------------------------Examples----------------------------------------------------------
+
+#[allow(dead_code)]
+pub struct Padded {
+    pub flag: u8,
+    pub count: u32,
+    pub small: u16,
+}
+
+#[allow(dead_code)]
+#[repr(C)]
+pub struct PaddedC {
+    pub flag: u8,
+    pub count: u32,
+    pub small: u16,
+}
+
+#[allow(dead_code)]
+pub enum Shape {
+    Circle { radius: f64 },
+    Square(u32),
+    Empty,
+}
+
+pub fn padded() -> TypeLayout {
+    TypeLayout::of::<Padded>("struct Padded { flag: u8, count: u32, small: u16 }")
+        .with_fields(&[
+            (
+                "flag",
+                std::mem::offset_of!(Padded, flag),
+                1,
+                Storage::Inline,
+            ),
+            (
+                "count",
+                std::mem::offset_of!(Padded, count),
+                4,
+                Storage::Inline,
+            ),
+            (
+                "small",
+                std::mem::offset_of!(Padded, small),
+                2,
+                Storage::Inline,
+            ),
+        ])
+        .note("Rust reordered the fields to waste as little as possible on padding.")
+}
+
+pub fn padded_c() -> TypeLayout {
+    TypeLayout::of::<PaddedC>("#[repr(C)] struct PaddedC { flag: u8, count: u32, small: u16 }")
+        .with_fields(&[
+            ("flag", std::mem::offset_of!(PaddedC, flag), 1, Storage::Inline),
+            ("count", std::mem::offset_of!(PaddedC, count), 4, Storage::Inline),
+            ("small", std::mem::offset_of!(PaddedC, small), 2, Storage::Inline),
+        ])
+        .note("repr(C) keeps the order of the fields, count has to wait for an offset that is a multiple of 4.")
+}
+
+fn field_offset<T, F>(value: &T, field: &F) -> usize {
+    field as *const F as usize - value as *const T as usize
+}
+
+// offset_of! cannot reach into the variants of an enum, so the offsets come from references to the fields of real values. The tag goes in the first
+// byte no field uses, one byte is enough for three variants.
+pub fn shape() -> TypeLayout {
+    let circle = Shape::Circle { radius: 1.0 };
+    let Shape::Circle { radius } = &circle else {
+        unreachable!()
+    };
+    let square = Shape::Square(1);
+    let Shape::Square(side) = &square else {
+        unreachable!()
+    };
+    let fields = [
+        ("Circle.radius", field_offset(&circle, radius), 8),
+        ("Square.0", field_offset(&square, side), 4),
+    ];
+    let tag = (0..size_of::<Shape>())
+        .find(|byte| {
+            fields
+                .iter()
+                .all(|&(_, offset, size)| !(offset..offset + size).contains(byte))
+        })
+        .expect("f64 and u32 have no niche, the tag has bytes of its own");
+    TypeLayout::of::<Shape>("enum Shape { Circle { radius: f64 }, Square(u32), Empty }")
+        .with_fields(&[
+            ("tag", tag, 1, Storage::Inline),
+            (fields[0].0, fields[0].1, fields[0].2, Storage::Inline),
+            (fields[1].0, fields[1].1, fields[1].2, Storage::Inline),
+        ])
+        .note("An enum is as big as its biggest variant plus the tag, rounded up to the alignment. f64 has no niche, so the tag needs its own bytes.")
+        .note("The fields of different variants may share bytes, only one variant is there at a time. Empty has no fields, only the tag.")
+}
+
+// Everything the layout tool shows, in order.
+pub fn examples() -> Vec<TypeLayout> {
+    let mut string = String::with_capacity(16);
+    string.push_str("Heap_Data");
+    let mut vector: Vec<u16> = Vec::with_capacity(8);
+    vector.extend([1, 2, 3]);
+    let rc = Rc::new(42u64);
+    let shared = Rc::clone(&rc);
+
+    let layouts = vec![
+        TypeLayout::of::<u64>("u64").with_fields(&[("value", 0, 8, Storage::Inline)]),
+        inspect_string("String", &string),
+        inspect_vec("Vec<u16>", &vector),
+        inspect_vec("Vec<u16> (empty)", &Vec::<u16>::new()),
+        inspect_box("Box<u64>", &Box::new(7u64)),
+        inspect_box("Box<[u8]>", &vec![1u8, 2, 3, 4, 5].into_boxed_slice()),
+        inspect_rc("Rc<u64>", &shared),
+        padded(),
+        padded_c(),
+        shape(),
+        inspect_niche::<Box<u64>>("Box<u64>", Storage::Pointer),
+        inspect_niche::<&u8>("&u8", Storage::Pointer),
+        inspect_niche::<bool>("bool", Storage::Inline),
+        inspect_niche::<std::num::NonZeroU32>("NonZeroU32", Storage::Inline),
+        inspect_niche::<u32>("u32", Storage::Inline),
+        inspect_niche::<f64>("f64", Storage::Inline),
+    ];
+    drop(rc);
+    layouts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn string_words_are_recognised() {
+        let mut string = String::with_capacity(16);
+        string.push_str("abc");
+        let layout = inspect_string("String", &string);
+        assert_eq!(layout.size, 3 * WORD);
+        let names: Vec<&str> = layout.parts.iter().map(|part| part.name.as_str()).collect();
+        for name in ["pointer", "length", "capacity"] {
+            assert_eq!(
+                names.iter().filter(|&&n| n == name).count(),
+                1,
+                "{:?}",
+                names
+            );
+        }
+        let length = layout
+            .parts
+            .iter()
+            .find(|part| part.name == "length")
+            .unwrap();
+        assert_eq!(length.value.as_deref(), Some("3"));
+        assert_eq!(layout.heap.as_ref().unwrap().allocated, 16);
+        assert!(!layout.is_inline());
+    }
+
+    #[test]
+    fn padding_between_and_after_fields() {
+        let layout = padded_c();
+        assert_eq!(layout.size, 12);
+        assert_eq!(layout.padding_bytes(), 5);
+        assert_eq!(padded().size, 8);
+        assert_eq!(padded().padding_bytes(), 1);
+    }
+
+    #[test]
+    fn niches() {
+        let boxed = inspect_niche::<Box<u8>>("Box<u8>", Storage::Pointer);
+        assert_eq!(boxed.padding_bytes(), 0);
+        assert!(!boxed.is_inline());
+        assert!(!boxed.to_string().contains("all inline"));
+        assert_eq!(inspect_niche::<bool>("bool", Storage::Inline).size, 1);
+        let number = inspect_niche::<u32>("u32", Storage::Inline);
+        assert_eq!(number.size, 8);
+        assert_eq!(number.padding_bytes(), 3);
+        assert!(number.is_inline());
+    }
+
+    #[test]
+    fn empty_vec_has_nothing_on_the_heap() {
+        let text = inspect_vec("Vec<u16>", &Vec::<u16>::new()).to_string();
+        assert!(!text.contains("points to the heap"), "{}", text);
+        assert!(!text.contains("heap  ["), "{}", text);
+        assert!(inspect_vec("Vec<u16>", &vec![1u16])
+            .to_string()
+            .contains("points to the heap"));
+    }
+
+    #[test]
+    fn shape_shows_the_tag_and_the_fields() {
+        let layout = shape();
+        let names: Vec<&str> = layout
+            .parts
+            .iter()
+            .filter(|part| part.storage != Storage::Padding)
+            .map(|part| part.name.as_str())
+            .collect();
+        assert_eq!(names.len(), 3, "{:?}", names);
+        assert!(names.contains(&"tag"));
+        let bytes: usize = layout.parts.iter().map(|part| part.size).sum();
+        assert_eq!(bytes, layout.size);
+        assert!(layout.to_string().contains("  stack ["));
+    }
+
+    #[test]
+    fn fat_box_has_metadata() {
+        let boxed: Box<[u8]> = vec![1, 2, 3].into_boxed_slice();
+        let layout = inspect_box("Box<[u8]>", &boxed);
+        assert_eq!(layout.parts.len(), 2);
+        let metadata = layout
+            .parts
+            .iter()
+            .find(|part| part.name == "metadata")
+            .unwrap();
+        assert_eq!(metadata.value.as_deref(), Some("0x3"));
+    }
+
+    #[test]
+    fn diagram() {
+        let text = padded_c().to_string();
+        assert!(text.contains("  stack [a ... bbbb cc ..]"), "{}", text);
+        assert!(text.contains("  offset 0 1   4    8  10"), "{}", text);
+        assert!(inspect_rc("Rc<u64>", &Rc::new(1u64))
+            .to_string()
+            .contains("strong count 1, weak count 0"));
+    }
+}
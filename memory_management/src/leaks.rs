@@ -0,0 +1,294 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 06:10:48
+/// @Description: Leak and Reference Cycle Detector
+/*
+- Every MyRc value is in a registry of the thread from MyRc::new until its value is dropped. Whatever is still in it when the program ends has leaked.
+- Two MyRc values that hold each other never reach a strong count of 0: a reference cycle. To tell a cycle from a value that is simply still owned, the
+  detector needs to know which MyRc values a value holds. The Trace trait says so, and MyRc::traced() registers the value with it.
+- leak_report() finds the cycles like a cycle collector does, by trial deletion:
+    -> For every live value, count the strong references that come from other live values. If that is less than its strong count, something outside the
+       MyRc values holds it: a local, a static or a mem::forget. Those values are roots.
+    -> Everything reachable from a root is still in use. What is left is only held by other leaked values, so it is kept alive by a cycle.
+    -> Values made with MyRc::new() do not say what they hold. The detector counts them as roots, so it never calls something a cycle by mistake.
+- LeakCheck is a guard for main: create it first, it is dropped last, and it prints the report of what is still alive to stderr.
+*/
+use std::cell::{Cell, RefCell};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+// The ids of the MyRc values a value holds strong references to, one entry per reference. MyWeak references do not count.
+pub trait Trace {
+    fn children(&self) -> Vec<u64>;
+}
+
+type Children = Box<dyn Fn() -> Vec<u64>>;
+
+struct Entry {
+    type_name: String,
+    // Points into the MyRc allocation, which lives at least as long as the entry.
+    strong: *const Cell<usize>,
+    children: Option<Children>,
+}
+
+thread_local! {
+    static REGISTRY: RefCell<BTreeMap<u64, Entry>> = const { RefCell::new(BTreeMap::new()) };
+}
+
+pub(crate) fn register(
+    id: u64,
+    type_name: String,
+    strong: *const Cell<usize>,
+    children: Option<Children>,
+) {
+    let entry = Entry {
+        type_name,
+        strong,
+        children,
+    };
+    // During the teardown of a thread its registry can be gone already, the value is then not tracked.
+    let _ = REGISTRY.try_with(|registry| registry.borrow_mut().insert(id, entry));
+}
+
+pub(crate) fn unregister(id: u64) {
+    // The entry is dropped outside of the borrow, dropping its closure must not touch the registry while it is borrowed.
+    let entry = REGISTRY
+        .try_with(|registry| registry.borrow_mut().remove(&id))
+        .ok()
+        .flatten();
+    drop(entry);
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Leak {
+    pub id: u64,
+    pub type_name: String,
+    pub strong: usize,
+    // None for a value made with MyRc::new(), it does not say what it holds.
+    pub children: Option<Vec<u64>>,
+    pub in_cycle: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct LeakReport {
+    pub leaks: Vec<Leak>,
+}
+
+impl LeakReport {
+    pub fn is_empty(&self) -> bool {
+        self.leaks.is_empty()
+    }
+
+    pub fn cycles(&self) -> impl Iterator<Item = &Leak> {
+        self.leaks.iter().filter(|leak| leak.in_cycle)
+    }
+}
+
+pub fn live_count() -> usize {
+    REGISTRY.with(|registry| registry.borrow().len())
+}
+
+pub fn leak_report() -> LeakReport {
+    // The closures borrow the values they look at, so the registry stays borrowed only for reading.
+    let mut leaks: Vec<Leak> = REGISTRY.with(|registry| {
+        registry
+            .borrow()
+            .iter()
+            .map(|(&id, entry)| Leak {
+                id,
+                type_name: entry.type_name.clone(),
+                strong: unsafe { (*entry.strong).get() },
+                children: entry.children.as_ref().map(|children| children()),
+                in_cycle: false,
+            })
+            .collect()
+    });
+
+    let mut internal: BTreeMap<u64, usize> = BTreeMap::new();
+    for children in leaks.iter().filter_map(|leak| leak.children.as_ref()) {
+        for &child in children {
+            *internal.entry(child).or_insert(0) += 1;
+        }
+    }
+    let mut reachable: BTreeSet<u64> = BTreeSet::new();
+    let mut pending: Vec<u64> = leaks
+        .iter()
+        .filter(|leak| {
+            leak.children.is_none() || leak.strong > internal.get(&leak.id).copied().unwrap_or(0)
+        })
+        .map(|leak| leak.id)
+        .collect();
+    let by_id: BTreeMap<u64, &Leak> = leaks.iter().map(|leak| (leak.id, leak)).collect();
+    while let Some(id) = pending.pop() {
+        if !reachable.insert(id) {
+            continue;
+        }
+        if let Some(children) = by_id.get(&id).and_then(|leak| leak.children.as_ref()) {
+            pending.extend(children.iter().filter(|child| by_id.contains_key(child)));
+        }
+    }
+    for leak in leaks.iter_mut() {
+        leak.in_cycle = !reachable.contains(&leak.id);
+    }
+    LeakReport { leaks }
+}
+
+impl fmt::Display for LeakReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.leaks.is_empty() {
+            return writeln!(f, "No MyRc value leaked.");
+        }
+        let cycles = self.cycles().count();
+        writeln!(
+            f,
+            "{} MyRc values were never dropped, {} of them in reference cycles:",
+            self.leaks.len(),
+            cycles
+        )?;
+        for leak in &self.leaks {
+            write!(
+                f,
+                "  #{} {} (strong {})",
+                leak.id, leak.type_name, leak.strong
+            )?;
+            if let Some(children) = leak
+                .children
+                .as_ref()
+                .filter(|children| !children.is_empty())
+            {
+                let children: Vec<String> =
+                    children.iter().map(|child| format!("#{}", child)).collect();
+                write!(f, " -> {}", children.join(", "))?;
+            }
+            let reason = if leak.in_cycle {
+                "kept alive by a reference cycle"
+            } else {
+                "still owned from outside"
+            };
+            writeln!(f, ", {}", reason)?;
+        }
+        Ok(())
+    }
+}
+
+// Prints the leak report of its thread when it is dropped.
+pub struct LeakCheck {
+    // The registry is per thread, the guard must be dropped on the thread that made it.
+    _not_send: std::marker::PhantomData<*const ()>,
+}
+
+impl LeakCheck {
+    pub fn new() -> LeakCheck {
+        LeakCheck {
+            _not_send: std::marker::PhantomData,
+        }
+    }
+}
+
+impl Default for LeakCheck {
+    fn default() -> LeakCheck {
+        LeakCheck::new()
+    }
+}
+
+impl Drop for LeakCheck {
+    fn drop(&mut self) {
+        eprint!("{}", leak_report());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pointers::{MyRc, MyWeak};
+
+    struct Node {
+        name: &'static str,
+        next: RefCell<Option<MyRc<Node>>>,
+        parent: RefCell<Option<MyWeak<Node>>>,
+    }
+
+    impl Node {
+        fn new(name: &'static str) -> MyRc<Node> {
+            MyRc::traced(Node {
+                name,
+                next: RefCell::new(None),
+                parent: RefCell::new(None),
+            })
+        }
+    }
+
+    impl Trace for Node {
+        fn children(&self) -> Vec<u64> {
+            self.next.borrow().iter().map(MyRc::id).collect()
+        }
+    }
+
+    #[test]
+    fn dropped_values_leave_the_registry() {
+        let a = Node::new("a");
+        let b = Node::new("b");
+        *a.next.borrow_mut() = Some(MyRc::clone(&b));
+        *b.parent.borrow_mut() = Some(MyRc::downgrade(&a));
+        assert_eq!(live_count(), 2);
+        drop(b);
+        drop(a);
+        assert_eq!(live_count(), 0);
+        assert!(leak_report().is_empty());
+    }
+
+    #[test]
+    fn values_still_in_use_are_not_cycles() {
+        let a = Node::new("a");
+        let b = Node::new("b");
+        *a.next.borrow_mut() = Some(MyRc::clone(&b));
+        let report = leak_report();
+        assert_eq!(report.leaks.len(), 2);
+        assert_eq!(report.cycles().count(), 0);
+        assert_eq!(report.leaks[0].children, Some(vec![b.id()]));
+        assert_eq!(report.leaks[1].strong, 2);
+    }
+
+    #[test]
+    fn finds_a_cycle_and_what_hangs_off_it() {
+        let forgotten = Node::new("forgotten");
+        {
+            let a = Node::new("a");
+            let b = Node::new("b");
+            let tail = Node::new("tail");
+            *a.next.borrow_mut() = Some(MyRc::clone(&b));
+            *b.next.borrow_mut() = Some(MyRc::clone(&a));
+            *forgotten.next.borrow_mut() = Some(tail);
+            assert_eq!(a.next.borrow().as_ref().map(|next| next.name), Some("b"));
+        }
+        std::mem::forget(forgotten);
+        let report = leak_report();
+        let in_cycle: Vec<(u64, bool)> = report
+            .leaks
+            .iter()
+            .map(|leak| (leak.id, leak.in_cycle))
+            .collect();
+        // forgotten is #1, a and b form the cycle, tail is held by forgotten.
+        assert_eq!(in_cycle, [(1, false), (2, true), (3, true), (4, false)]);
+        assert_eq!(
+            report.to_string(),
+            "4 MyRc values were never dropped, 2 of them in reference cycles:\n  \
+             #1 Node (strong 1) -> #4, still owned from outside\n  \
+             #2 Node (strong 1) -> #3, kept alive by a reference cycle\n  \
+             #3 Node (strong 1) -> #2, kept alive by a reference cycle\n  \
+             #4 Node (strong 1), still owned from outside\n"
+        );
+    }
+
+    #[test]
+    fn untraced_values_are_never_called_cycles() {
+        let a = MyRc::new(RefCell::new(None::<MyRc<u32>>));
+        let counter = MyRc::new(7u32);
+        *a.borrow_mut() = Some(MyRc::clone(&counter));
+        std::mem::forget(a);
+        drop(counter);
+        let report = leak_report();
+        assert_eq!(report.leaks.len(), 2);
+        assert_eq!(report.cycles().count(), 0);
+        assert_eq!(report.leaks[1].type_name, "u32");
+    }
+}
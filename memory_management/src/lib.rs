@@ -0,0 +1,29 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 06:03:17
+/// @Description: Memory Management Library
+/*
+- The tracking module has a global allocator that counts what the program allocates and frees, and scopes that report it for a piece of code.
+- arena, bump and pool are allocators for many small objects: a typed Arena<T>, a Bump allocator for values of any type with reset and scopes, and a
+  fixed size Pool<T> with a free list. benches/allocators.rs compares them with Box and Vec.
+- layout inspects where the bytes of a value are, inline or behind a pointer, and draws it. src/bin/layout.rs prints it for the common types.
+- pointers has hand-rolled MyBox, MyRc and MyWeak that trace every step of their life, so the drop order can be watched and tested. leaks keeps track of
+  the MyRc values still alive and finds the reference cycles among them. src/bin/rc_cycles.rs shows both.
+- Other crates can use it in their tests to check when something hits the heap, after installing TrackingAllocator in the test binary.
+*/
+pub mod arena;
+pub mod bump;
+pub mod layout;
+pub mod leaks;
+pub mod pointers;
+pub mod pool;
+pub mod tracking;
+
+pub use arena::Arena;
+pub use bump::Bump;
+pub use layout::{Storage, TypeLayout};
+pub use leaks::{leak_report, Leak, LeakCheck, LeakReport, Trace};
+pub use pointers::{set_echo, set_recording, take_trace, MyBox, MyRc, MyWeak};
+pub use pool::{Handle, Pool};
+pub use tracking::{
+    is_installed, measure, process_stats, thread_stats, Report, Scope, Stats, TrackingAllocator,
+};
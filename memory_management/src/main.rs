@@ -7,12 +7,89 @@
   that has a known and fixed size.
 - Heap is used to store dynamically created data whose size is not known at the compile time.
 - A string literal is constant which can be changed and remains in the stack. A string can be created dynamically in the heap using the String module.
+- The program runs with TrackingAllocator as its global allocator, so every scope below reports what really went to the heap. The reports are printed at
+  the end as a table of fundamentals_core:
+    -> copying an integer stays on the stack, no allocation.
+    -> String::from allocates the 9 bytes of "Heap_Data" and the end of the scope frees them.
+    -> growing a String past its capacity reallocates, moving it to another variable does not allocate at all, cloning it allocates a copy.
 */
+use fundamentals_core::{Align, Table};
+use memory_management::{measure, process_stats, Report, Scope, TrackingAllocator};
+
+#[global_allocator]
+static ALLOCATOR: TrackingAllocator = TrackingAllocator;
+
+fn print_reports(reports: &[Report]) {
+    let table = Table::new()
+        .column("scope", 24, Align::Left)
+        .column("allocations", 0, Align::Right)
+        .column("frees", 0, Align::Right)
+        .column("reallocations", 0, Align::Right)
+        .column("allocated", 0, Align::Right)
+        .column("freed", 0, Align::Right)
+        .column("in use", 0, Align::Right)
+        .column("peak", 0, Align::Right);
+    println!("{}", table.header());
+    for report in reports {
+        println!(
+            "{}",
+            table.row(&[
+                &report.name,
+                &report.allocations,
+                &report.deallocations,
+                &report.reallocations,
+                &report.bytes_allocated,
+                &report.bytes_freed,
+                &report.bytes_in_use,
+                &report.peak_bytes,
+            ])
+        );
+    }
+}
+
 fn main() {
     println!("Hello, world!");
-    let z = 2;
-    let y = z;
+
+    // The reports are printed after the scopes, printing allocates too.
+    let mut reports = Vec::new();
+    let ((z, y), integers) = measure("copied integer", || {
+        let z = 2;
+        let y = z;
+        (z, y)
+    });
+    println!("z = {}, y = {}", z, y);
+    reports.push(integers);
+
+    let scope = Scope::new("String::from");
     let string = String::from("Heap_Data");
-    println!("y = {}", y);
+    let allocated = scope.report();
     println!("{}", string);
+    drop(string);
+    let freed = scope.finish();
+    reports.push(allocated);
+    reports.push(Report {
+        name: "String::from, dropped",
+        ..freed
+    });
+
+    let (_, growing) = measure("push_str", || {
+        let mut string = String::from("Heap_Data");
+        string.push_str(", and some more heap data");
+    });
+    reports.push(growing);
+
+    let string = String::from("Heap_Data");
+    let (moved, moving) = measure("move", move || string);
+    println!("{} moved without a copy", moved);
+    reports.push(moving);
+
+    let (_, cloning) = measure("clone", || {
+        let string = String::from("Heap_Data");
+        let copy = string.clone();
+        (string, copy)
+    });
+    reports.push(cloning);
+
+    print_reports(&reports);
+    println!("whole program: {}", process_stats());
 }
@@ -0,0 +1,536 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 06:10:48
+/// @Description: Hand-Rolled Smart Pointers
+/*
+- MyBox<T> owns one value on the heap: new() allocates with std::alloc and moves the value in, Drop drops the value and frees the memory. That is all a Box
+  does, the compiler only adds the syntax.
+- MyRc<T> shares one value between several owners. The value lives next to two counts:
+    -> strong: the MyRc handles. Cloning a handle counts up, dropping one counts down, the last one drops the value.
+    -> weak: the MyWeak handles, plus one for all the strong ones together. The memory is freed when it reaches 0, so a MyWeak can always look at the
+       counts, even after the value is gone. upgrade() gives a new MyRc only while the value is alive.
+- Every step is written to a trace: creating, cloning, dropping a handle, dropping the value and freeing the memory, numbered by the allocation it belongs to.
+  The trace is only kept once set_recording(true) asks for it, take_trace() then hands the trace of the current thread to a test. set_echo(true) prints
+  every step to stderr as it happens. With neither, nothing is kept, so a long running program does not pile up steps nobody reads.
+- MyRc and MyWeak are not Send, the counts are plain Cells like in std::rc::Rc. The trace and the registry of the leaks module are per thread for the same
+  reason.
+- A MyRc made with MyRc::traced() also tells the leaks module which other MyRc values it holds, so a leak can be told apart from a reference cycle.
+*/
+use crate::leaks::{self, Trace};
+use std::alloc::{self, Layout};
+use std::any;
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem::{self, ManuallyDrop};
+use std::ops::{Deref, DerefMut};
+use std::ptr::{self, NonNull};
+
+thread_local! {
+    static TRACE: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+    static RECORDING: Cell<bool> = const { Cell::new(false) };
+    static ECHO: Cell<bool> = const { Cell::new(false) };
+    static NEXT_ID: Cell<u64> = const { Cell::new(1) };
+}
+
+pub fn take_trace() -> Vec<String> {
+    TRACE.with(|trace| mem::take(&mut *trace.borrow_mut()))
+}
+
+pub fn set_recording(record: bool) {
+    RECORDING.with(|cell| cell.set(record));
+}
+
+pub fn set_echo(echo: bool) {
+    ECHO.with(|cell| cell.set(echo));
+}
+
+// Values can be dropped while the thread locals of their thread are torn down, then the step is not traced.
+fn trace(event: String) {
+    if ECHO.try_with(Cell::get).unwrap_or(false) {
+        eprintln!("{}", event);
+    }
+    if RECORDING.try_with(Cell::get).unwrap_or(false) {
+        let _ = TRACE.try_with(|trace| trace.borrow_mut().push(event));
+    }
+}
+
+fn next_id() -> u64 {
+    NEXT_ID.with(|next| {
+        let id = next.get();
+        next.set(id + 1);
+        id
+    })
+}
+
+// The type name without module paths: alloc::string::String becomes String.
+pub(crate) fn short_type_name<T: ?Sized>() -> String {
+    let mut short = String::new();
+    let mut segment_start = 0;
+    let full = any::type_name::<T>();
+    let mut chars = full.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == ':' && chars.peek() == Some(&':') {
+            chars.next();
+            short.truncate(segment_start);
+        } else {
+            short.push(c);
+            if !(c.is_alphanumeric() || c == '_') {
+                segment_start = short.len();
+            }
+        }
+    }
+    short
+}
+
+//------------------------------------------------------------MyBox----------------------------------------------------------
+
+pub struct MyBox<T> {
+    pointer: NonNull<T>,
+    id: u64,
+    // Tells the compiler a MyBox owns a T, for the drop check.
+    _owns: PhantomData<T>,
+}
+
+impl<T> MyBox<T> {
+    pub fn new(value: T) -> MyBox<T> {
+        let layout = Layout::new::<T>();
+        // Allocating 0 bytes is not allowed, a value without bytes needs no memory and any aligned address will do.
+        let pointer = if layout.size() == 0 {
+            NonNull::dangling()
+        } else {
+            let raw = unsafe { alloc::alloc(layout) } as *mut T;
+            NonNull::new(raw).unwrap_or_else(|| alloc::handle_alloc_error(layout))
+        };
+        unsafe { pointer.as_ptr().write(value) };
+        let id = next_id();
+        trace(format!(
+            "MyBox#{}<{}> new, {} bytes on the heap",
+            id,
+            short_type_name::<T>(),
+            layout.size()
+        ));
+        MyBox {
+            pointer,
+            id,
+            _owns: PhantomData,
+        }
+    }
+
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    // Moves the value back out and frees the memory.
+    pub fn into_inner(self) -> T {
+        let this = ManuallyDrop::new(self);
+        let value = unsafe { this.pointer.as_ptr().read() };
+        unsafe { free(this.pointer) };
+        trace(format!(
+            "MyBox#{}<{}> moved out and freed",
+            this.id,
+            short_type_name::<T>()
+        ));
+        value
+    }
+}
+
+unsafe fn free<T>(pointer: NonNull<T>) {
+    let layout = Layout::new::<T>();
+    if layout.size() != 0 {
+        alloc::dealloc(pointer.as_ptr() as *mut u8, layout);
+    }
+}
+
+impl<T> Deref for MyBox<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { self.pointer.as_ref() }
+    }
+}
+
+impl<T> DerefMut for MyBox<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { self.pointer.as_mut() }
+    }
+}
+
+impl<T> Drop for MyBox<T> {
+    fn drop(&mut self) {
+        trace(format!(
+            "MyBox#{}<{}> drop",
+            self.id,
+            short_type_name::<T>()
+        ));
+        unsafe {
+            ptr::drop_in_place(self.pointer.as_ptr());
+            free(self.pointer);
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for MyBox<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("MyBox").field(&**self).finish()
+    }
+}
+
+//------------------------------------------------------------MyRc and MyWeak----------------------------------------------------------
+
+pub(crate) struct RcInner<T> {
+    pub(crate) strong: Cell<usize>,
+    weak: Cell<usize>,
+    id: u64,
+    // Dropped by hand when the strong count reaches 0, the memory stays as long as there are weak handles.
+    value: ManuallyDrop<T>,
+}
+
+pub struct MyRc<T> {
+    inner: NonNull<RcInner<T>>,
+    _owns: PhantomData<RcInner<T>>,
+}
+
+pub struct MyWeak<T> {
+    inner: NonNull<RcInner<T>>,
+}
+
+impl<T> MyRc<T> {
+    pub fn new(value: T) -> MyRc<T> {
+        let rc = MyRc::allocate(value);
+        leaks::register(rc.id(), short_type_name::<T>(), rc.strong_cell(), None);
+        rc
+    }
+
+    fn allocate(value: T) -> MyRc<T> {
+        let id = next_id();
+        let inner = Box::new(RcInner {
+            strong: Cell::new(1),
+            weak: Cell::new(1),
+            id,
+            value: ManuallyDrop::new(value),
+        });
+        trace(format!(
+            "MyRc#{}<{}> new, strong 1",
+            id,
+            short_type_name::<T>()
+        ));
+        MyRc {
+            inner: NonNull::from(Box::leak(inner)),
+            _owns: PhantomData,
+        }
+    }
+
+    fn inner(&self) -> &RcInner<T> {
+        unsafe { self.inner.as_ref() }
+    }
+
+    fn strong_cell(&self) -> *const Cell<usize> {
+        &self.inner().strong
+    }
+
+    pub fn id(&self) -> u64 {
+        self.inner().id
+    }
+
+    pub fn strong_count(&self) -> usize {
+        self.inner().strong.get()
+    }
+
+    pub fn weak_count(&self) -> usize {
+        self.inner().weak.get() - 1
+    }
+
+    pub fn ptr_eq(a: &MyRc<T>, b: &MyRc<T>) -> bool {
+        a.inner == b.inner
+    }
+
+    pub fn downgrade(this: &MyRc<T>) -> MyWeak<T> {
+        let weak = &this.inner().weak;
+        weak.set(weak.get() + 1);
+        trace(format!(
+            "MyRc#{}<{}> downgrade, weak {}",
+            this.id(),
+            short_type_name::<T>(),
+            weak.get() - 1
+        ));
+        MyWeak { inner: this.inner }
+    }
+}
+
+impl<T: Trace + 'static> MyRc<T> {
+    // Like new(), but the leaks module can ask the value for the MyRc values it holds.
+    pub fn traced(value: T) -> MyRc<T> {
+        let rc = MyRc::allocate(value);
+        let value: *const T = &*rc.inner().value;
+        // The registry only calls it while the value is alive, it unregisters the value before dropping it.
+        let children = Box::new(move || unsafe { (*value).children() });
+        leaks::register(
+            rc.id(),
+            short_type_name::<T>(),
+            rc.strong_cell(),
+            Some(children),
+        );
+        rc
+    }
+}
+
+impl<T> Clone for MyRc<T> {
+    fn clone(&self) -> MyRc<T> {
+        let strong = &self.inner().strong;
+        strong.set(strong.get() + 1);
+        trace(format!(
+            "MyRc#{}<{}> clone, strong {}",
+            self.id(),
+            short_type_name::<T>(),
+            strong.get()
+        ));
+        MyRc {
+            inner: self.inner,
+            _owns: PhantomData,
+        }
+    }
+}
+
+impl<T> Deref for MyRc<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.inner().value
+    }
+}
+
+// Drops the value when the last strong handle goes and frees the memory when the last handle of any kind goes.
+impl<T> Drop for MyRc<T> {
+    fn drop(&mut self) {
+        let (id, name) = (self.id(), short_type_name::<T>());
+        let strong = &self.inner().strong;
+        strong.set(strong.get() - 1);
+        trace(format!(
+            "MyRc#{}<{}> drop handle, strong {}",
+            id,
+            name,
+            strong.get()
+        ));
+        if strong.get() > 0 {
+            return;
+        }
+        leaks::unregister(id);
+        trace(format!("MyRc#{}<{}> value dropped", id, name));
+        unsafe { ManuallyDrop::drop(&mut (*self.inner.as_ptr()).value) };
+        release_weak(self.inner, id, &name);
+    }
+}
+
+// Gives up one weak count, the last one frees the memory.
+fn release_weak<T>(inner: NonNull<RcInner<T>>, id: u64, name: &str) {
+    let weak = unsafe { &inner.as_ref().weak };
+    weak.set(weak.get() - 1);
+    if weak.get() == 0 {
+        trace(format!("MyRc#{}<{}> freed", id, name));
+        // The value was dropped already, it is in a ManuallyDrop, so this only frees the memory.
+        drop(unsafe { Box::from_raw(inner.as_ptr()) });
+    }
+}
+
+impl<T> MyWeak<T> {
+    pub fn upgrade(&self) -> Option<MyRc<T>> {
+        let inner = unsafe { self.inner.as_ref() };
+        let name = short_type_name::<T>();
+        if inner.strong.get() == 0 {
+            trace(format!(
+                "MyRc#{}<{}> upgrade failed, the value is gone",
+                inner.id, name
+            ));
+            return None;
+        }
+        inner.strong.set(inner.strong.get() + 1);
+        trace(format!(
+            "MyRc#{}<{}> upgrade, strong {}",
+            inner.id,
+            name,
+            inner.strong.get()
+        ));
+        Some(MyRc {
+            inner: self.inner,
+            _owns: PhantomData,
+        })
+    }
+
+    pub fn strong_count(&self) -> usize {
+        unsafe { self.inner.as_ref() }.strong.get()
+    }
+}
+
+impl<T> Clone for MyWeak<T> {
+    fn clone(&self) -> MyWeak<T> {
+        let weak = unsafe { &self.inner.as_ref().weak };
+        weak.set(weak.get() + 1);
+        MyWeak { inner: self.inner }
+    }
+}
+
+impl<T> Drop for MyWeak<T> {
+    fn drop(&mut self) {
+        let id = unsafe { self.inner.as_ref() }.id;
+        let name = short_type_name::<T>();
+        trace(format!("MyWeak#{}<{}> drop", id, name));
+        release_weak(self.inner, id, &name);
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for MyRc<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("MyRc").field(&**self).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Noisy(&'static str);
+
+    impl Drop for Noisy {
+        fn drop(&mut self) {
+            trace(format!("Noisy {} dropped", self.0));
+        }
+    }
+
+    fn events() -> Vec<String> {
+        // The ids count up per thread, every test runs on a thread of its own.
+        take_trace()
+    }
+
+    #[test]
+    fn nothing_is_kept_unless_asked_for() {
+        drop(MyBox::new(1u8));
+        assert!(events().is_empty());
+        set_recording(true);
+        drop(MyBox::new(2u8));
+        set_recording(false);
+        drop(MyBox::new(3u8));
+        assert_eq!(
+            events(),
+            ["MyBox#2<u8> new, 1 bytes on the heap", "MyBox#2<u8> drop"]
+        );
+    }
+
+    #[test]
+    fn values_dropped_during_thread_teardown_do_not_panic() {
+        struct Late(RefCell<Option<MyRc<u8>>>);
+        thread_local! {
+            static LATE: Late = const { Late(RefCell::new(None)) };
+        }
+
+        std::thread::spawn(|| {
+            set_recording(true);
+            LATE.with(|late| *late.0.borrow_mut() = Some(MyRc::new(1)));
+        })
+        .join()
+        .unwrap();
+    }
+
+    #[test]
+    fn short_type_names() {
+        assert_eq!(short_type_name::<String>(), "String");
+        assert_eq!(
+            short_type_name::<Vec<Option<String>>>(),
+            "Vec<Option<String>>"
+        );
+        assert_eq!(
+            short_type_name::<(u8, std::cell::RefCell<u32>)>(),
+            "(u8, RefCell<u32>)"
+        );
+    }
+
+    #[test]
+    fn box_drops_in_reverse_order_of_declaration() {
+        set_recording(true);
+        {
+            let first = MyBox::new(Noisy("first"));
+            let _second = MyBox::new(Noisy("second"));
+            assert_eq!(first.0, "first");
+        }
+        assert_eq!(
+            events(),
+            [
+                "MyBox#1<Noisy> new, 16 bytes on the heap",
+                "MyBox#2<Noisy> new, 16 bytes on the heap",
+                "MyBox#2<Noisy> drop",
+                "Noisy second dropped",
+                "MyBox#1<Noisy> drop",
+                "Noisy first dropped",
+            ]
+        );
+    }
+
+    #[test]
+    fn box_moves_out_and_handles_zero_sized_values() {
+        set_recording(true);
+        let mut boxed = MyBox::new(String::from("Heap"));
+        boxed.push_str("_Data");
+        assert_eq!(boxed.into_inner(), "Heap_Data");
+        drop(MyBox::new(()));
+        assert_eq!(
+            events(),
+            [
+                "MyBox#1<String> new, 24 bytes on the heap",
+                "MyBox#1<String> moved out and freed",
+                "MyBox#2<()> new, 0 bytes on the heap",
+                "MyBox#2<()> drop",
+            ]
+        );
+    }
+
+    #[test]
+    fn last_strong_handle_drops_the_value() {
+        set_recording(true);
+        let a = MyRc::new(Noisy("shared"));
+        let b = MyRc::clone(&a);
+        assert_eq!(a.strong_count(), 2);
+        assert!(MyRc::ptr_eq(&a, &b));
+        drop(a);
+        assert_eq!(b.strong_count(), 1);
+        drop(b);
+        assert_eq!(
+            events(),
+            [
+                "MyRc#1<Noisy> new, strong 1",
+                "MyRc#1<Noisy> clone, strong 2",
+                "MyRc#1<Noisy> drop handle, strong 1",
+                "MyRc#1<Noisy> drop handle, strong 0",
+                "MyRc#1<Noisy> value dropped",
+                "Noisy shared dropped",
+                "MyRc#1<Noisy> freed",
+            ]
+        );
+    }
+
+    #[test]
+    fn weak_handles_keep_the_memory_but_not_the_value() {
+        set_recording(true);
+        let strong = MyRc::new(Noisy("value"));
+        let weak = MyRc::downgrade(&strong);
+        assert_eq!(strong.weak_count(), 1);
+        assert_eq!(weak.upgrade().map(|rc| rc.strong_count()), Some(2));
+        drop(strong);
+        assert!(weak.upgrade().is_none());
+        assert_eq!(weak.strong_count(), 0);
+        drop(weak);
+        assert_eq!(
+            events(),
+            [
+                "MyRc#1<Noisy> new, strong 1",
+                "MyRc#1<Noisy> downgrade, weak 1",
+                "MyRc#1<Noisy> upgrade, strong 2",
+                "MyRc#1<Noisy> drop handle, strong 1",
+                "MyRc#1<Noisy> drop handle, strong 0",
+                "MyRc#1<Noisy> value dropped",
+                "Noisy value dropped",
+                "MyRc#1<Noisy> upgrade failed, the value is gone",
+                "MyWeak#1<Noisy> drop",
+                "MyRc#1<Noisy> freed",
+            ]
+        );
+    }
+}
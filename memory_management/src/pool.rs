@@ -0,0 +1,186 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 06:04:54
+/// @Description: Fixed Size Object Pool
+/*
+- A Pool<T> has a fixed number of slots, all allocated when it is created. Inserting a value takes a free slot, removing it gives the slot back, neither
+  allocates. Inserting into a full pool hands the value back.
+- The free slots form a linked list through the slots themselves: a free slot holds the index of the next free one. Inserting takes the head of the list,
+  removing pushes the slot onto it, both in constant time.
+- Values are reached through a Handle. A handle carries the generation of its slot, which goes up every time the slot is freed, so a handle to a removed
+  value does not reach the value that took its slot later.
+*/
+use std::mem;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Handle {
+    index: usize,
+    generation: u64,
+}
+
+enum Entry<T> {
+    Occupied(T),
+    Free { next: Option<usize> },
+}
+
+struct Slot<T> {
+    generation: u64,
+    entry: Entry<T>,
+}
+
+pub struct Pool<T> {
+    slots: Vec<Slot<T>>,
+    free: Option<usize>,
+    len: usize,
+}
+
+impl<T> Pool<T> {
+    pub fn with_capacity(capacity: usize) -> Pool<T> {
+        let slots = (0..capacity)
+            .map(|index| Slot {
+                generation: 0,
+                entry: Entry::Free {
+                    next: (index + 1 < capacity).then_some(index + 1),
+                },
+            })
+            .collect();
+        Pool {
+            slots,
+            free: (capacity > 0).then_some(0),
+            len: 0,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.free.is_none()
+    }
+
+    pub fn insert(&mut self, value: T) -> Result<Handle, T> {
+        let index = match self.free {
+            Some(index) => index,
+            None => return Err(value),
+        };
+        let slot = &mut self.slots[index];
+        self.free = match mem::replace(&mut slot.entry, Entry::Occupied(value)) {
+            Entry::Free { next } => next,
+            Entry::Occupied(_) => unreachable!("the free list only has free slots"),
+        };
+        self.len += 1;
+        Ok(Handle {
+            index,
+            generation: slot.generation,
+        })
+    }
+
+    fn slot(&self, handle: Handle) -> Option<&Slot<T>> {
+        self.slots
+            .get(handle.index)
+            .filter(|slot| slot.generation == handle.generation)
+    }
+
+    pub fn get(&self, handle: Handle) -> Option<&T> {
+        match self.slot(handle).map(|slot| &slot.entry) {
+            Some(Entry::Occupied(value)) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, handle: Handle) -> Option<&mut T> {
+        match self.slots.get_mut(handle.index) {
+            Some(Slot {
+                generation,
+                entry: Entry::Occupied(value),
+            }) if *generation == handle.generation => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn remove(&mut self, handle: Handle) -> Option<T> {
+        self.get(handle)?;
+        let slot = &mut self.slots[handle.index];
+        slot.generation += 1;
+        let entry = mem::replace(&mut slot.entry, Entry::Free { next: self.free });
+        self.free = Some(handle.index);
+        self.len -= 1;
+        match entry {
+            Entry::Occupied(value) => Some(value),
+            Entry::Free { .. } => unreachable!("checked by get"),
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Handle, &T)> {
+        self.slots
+            .iter()
+            .enumerate()
+            .filter_map(|(index, slot)| match &slot.entry {
+                Entry::Occupied(value) => Some((
+                    Handle {
+                        index,
+                        generation: slot.generation,
+                    },
+                    value,
+                )),
+                Entry::Free { .. } => None,
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fills_up_and_hands_back_the_value() {
+        let mut pool = Pool::with_capacity(2);
+        let a = pool.insert("a").unwrap();
+        let b = pool.insert("b").unwrap();
+        assert!(pool.is_full());
+        assert_eq!(pool.insert("c"), Err("c"));
+        assert_eq!((pool.get(a), pool.get(b)), (Some(&"a"), Some(&"b")));
+        assert_eq!(pool.len(), 2);
+    }
+
+    #[test]
+    fn freed_slots_are_reused_last_in_first_out() {
+        let mut pool = Pool::with_capacity(3);
+        let handles: Vec<Handle> = (0..3).map(|i| pool.insert(i).unwrap()).collect();
+        assert_eq!(pool.remove(handles[0]), Some(0));
+        assert_eq!(pool.remove(handles[2]), Some(2));
+        let reused = pool.insert(10).unwrap();
+        assert_eq!(reused.index, handles[2].index);
+        let reused = pool.insert(11).unwrap();
+        assert_eq!(reused.index, handles[0].index);
+        assert!(pool.is_full());
+    }
+
+    #[test]
+    fn stale_handles_miss() {
+        let mut pool = Pool::with_capacity(1);
+        let old = pool.insert(1).unwrap();
+        pool.remove(old);
+        let new = pool.insert(2).unwrap();
+        assert_eq!(pool.get(old), None);
+        assert_eq!(pool.get_mut(old), None);
+        assert_eq!(pool.remove(old), None);
+        *pool.get_mut(new).unwrap() += 1;
+        assert_eq!(pool.iter().collect::<Vec<_>>(), [(new, &3)]);
+    }
+
+    #[test]
+    fn empty_pool() {
+        let mut pool = Pool::with_capacity(0);
+        assert!(pool.is_full() && pool.is_empty());
+        assert_eq!(pool.insert(()), Err(()));
+    }
+}
@@ -0,0 +1,350 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 06:03:17
+/// @Description: Tracking Global Allocator
+/*
+- TrackingAllocator hands every request on to the System allocator and counts it on the way: allocations, deallocations, reallocations, the bytes allocated
+  and freed, the bytes in use and the peak of the bytes in use.
+- It has to be installed by the final binary (or test binary) with #[global_allocator], a library cannot choose the allocator for the programs that use it:
+      #[global_allocator]
+      static ALLOCATOR: TrackingAllocator = TrackingAllocator;
+  is_installed() tells whether that happened, without it all the counters stay at zero.
+- The counters are kept twice: for the whole process and for the current thread. The test harness runs tests in parallel threads, so tests should look at
+  thread_stats() and Scope, which only see their own thread. Memory freed by another thread than the one that allocated it makes bytes_in_use of a thread
+  go negative, so the thread counters are signed.
+- The allocator must not allocate itself. The process counters are atomics and the thread counters are const initialised thread locals without Drop, which
+  need no allocation to set up.
+- A Scope takes the thread counters at its start, its report is the difference to that. The peak of a scope is measured from the bytes in use at its start,
+  scopes can be nested.
+- Scopes and reports do not allocate themselves, their names are &'static str, so a report only shows what the measured code did.
+*/
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering};
+
+pub struct TrackingAllocator;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub allocations: u64,
+    pub deallocations: u64,
+    pub reallocations: u64,
+    pub bytes_allocated: u64,
+    pub bytes_freed: u64,
+    pub bytes_in_use: i64,
+    pub peak_bytes: i64,
+}
+
+struct Counters {
+    allocations: AtomicU64,
+    deallocations: AtomicU64,
+    reallocations: AtomicU64,
+    bytes_allocated: AtomicU64,
+    bytes_freed: AtomicU64,
+    bytes_in_use: AtomicI64,
+    peak_bytes: AtomicI64,
+}
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+static PROCESS: Counters = Counters {
+    allocations: AtomicU64::new(0),
+    deallocations: AtomicU64::new(0),
+    reallocations: AtomicU64::new(0),
+    bytes_allocated: AtomicU64::new(0),
+    bytes_freed: AtomicU64::new(0),
+    bytes_in_use: AtomicI64::new(0),
+    peak_bytes: AtomicI64::new(0),
+};
+
+thread_local! {
+    static THREAD: Cell<Stats> = const {
+        Cell::new(Stats {
+            allocations: 0,
+            deallocations: 0,
+            reallocations: 0,
+            bytes_allocated: 0,
+            bytes_freed: 0,
+            bytes_in_use: 0,
+            peak_bytes: 0,
+        })
+    };
+}
+
+// One event of the allocator: how many allocations, deallocations and reallocations it was and the bytes that came and went.
+#[derive(Clone, Copy, Default)]
+struct Event {
+    allocations: u64,
+    deallocations: u64,
+    reallocations: u64,
+    allocated: u64,
+    freed: u64,
+}
+
+fn count(event: Event) {
+    INSTALLED.store(true, Ordering::Relaxed);
+    let change = event.allocated as i64 - event.freed as i64;
+
+    PROCESS
+        .allocations
+        .fetch_add(event.allocations, Ordering::Relaxed);
+    PROCESS
+        .deallocations
+        .fetch_add(event.deallocations, Ordering::Relaxed);
+    PROCESS
+        .reallocations
+        .fetch_add(event.reallocations, Ordering::Relaxed);
+    PROCESS
+        .bytes_allocated
+        .fetch_add(event.allocated, Ordering::Relaxed);
+    PROCESS
+        .bytes_freed
+        .fetch_add(event.freed, Ordering::Relaxed);
+    let in_use = PROCESS.bytes_in_use.fetch_add(change, Ordering::Relaxed) + change;
+    PROCESS.peak_bytes.fetch_max(in_use, Ordering::Relaxed);
+
+    // During the teardown of a thread its thread locals can be gone already, then only the process counts.
+    let _ = THREAD.try_with(|thread| {
+        let mut stats = thread.get();
+        stats.allocations += event.allocations;
+        stats.deallocations += event.deallocations;
+        stats.reallocations += event.reallocations;
+        stats.bytes_allocated += event.allocated;
+        stats.bytes_freed += event.freed;
+        stats.bytes_in_use += change;
+        stats.peak_bytes = stats.peak_bytes.max(stats.bytes_in_use);
+        thread.set(stats);
+    });
+}
+
+unsafe impl GlobalAlloc for TrackingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let pointer = System.alloc(layout);
+        if !pointer.is_null() {
+            count(Event {
+                allocations: 1,
+                allocated: layout.size() as u64,
+                ..Event::default()
+            });
+        }
+        pointer
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let pointer = System.alloc_zeroed(layout);
+        if !pointer.is_null() {
+            count(Event {
+                allocations: 1,
+                allocated: layout.size() as u64,
+                ..Event::default()
+            });
+        }
+        pointer
+    }
+
+    unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+        System.dealloc(pointer, layout);
+        count(Event {
+            deallocations: 1,
+            freed: layout.size() as u64,
+            ..Event::default()
+        });
+    }
+
+    // A reallocation frees the old block and allocates the new one as far as the bytes go, but counts as neither.
+    unsafe fn realloc(&self, pointer: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_pointer = System.realloc(pointer, layout, new_size);
+        if !new_pointer.is_null() {
+            count(Event {
+                reallocations: 1,
+                allocated: new_size as u64,
+                freed: layout.size() as u64,
+                ..Event::default()
+            });
+        }
+        new_pointer
+    }
+}
+
+pub fn is_installed() -> bool {
+    INSTALLED.load(Ordering::Relaxed)
+}
+
+pub fn process_stats() -> Stats {
+    Stats {
+        allocations: PROCESS.allocations.load(Ordering::Relaxed),
+        deallocations: PROCESS.deallocations.load(Ordering::Relaxed),
+        reallocations: PROCESS.reallocations.load(Ordering::Relaxed),
+        bytes_allocated: PROCESS.bytes_allocated.load(Ordering::Relaxed),
+        bytes_freed: PROCESS.bytes_freed.load(Ordering::Relaxed),
+        bytes_in_use: PROCESS.bytes_in_use.load(Ordering::Relaxed),
+        peak_bytes: PROCESS.peak_bytes.load(Ordering::Relaxed),
+    }
+}
+
+pub fn thread_stats() -> Stats {
+    THREAD.with(|thread| thread.get())
+}
+
+fn set_thread_peak(peak_bytes: i64) {
+    THREAD.with(|thread| {
+        let mut stats = thread.get();
+        stats.peak_bytes = peak_bytes;
+        thread.set(stats);
+    });
+}
+
+pub struct Scope {
+    name: &'static str,
+    start: Stats,
+    outer_peak: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub name: &'static str,
+    pub allocations: u64,
+    pub deallocations: u64,
+    pub reallocations: u64,
+    pub bytes_allocated: u64,
+    pub bytes_freed: u64,
+    // Bytes allocated in the scope and not freed yet, negative if the scope freed more than it allocated.
+    pub bytes_in_use: i64,
+    // The most bytes in use at any time in the scope, on top of the bytes in use at its start.
+    pub peak_bytes: i64,
+}
+
+impl Scope {
+    // The peak of the thread is set back to the bytes in use now, so the peak of the scope can be read at its end. The peak from before is put back then.
+    pub fn new(name: &'static str) -> Scope {
+        let start = thread_stats();
+        set_thread_peak(start.bytes_in_use);
+        Scope {
+            name,
+            start,
+            outer_peak: start.peak_bytes,
+        }
+    }
+
+    pub fn report(&self) -> Report {
+        let now = thread_stats();
+        Report {
+            name: self.name,
+            allocations: now.allocations - self.start.allocations,
+            deallocations: now.deallocations - self.start.deallocations,
+            reallocations: now.reallocations - self.start.reallocations,
+            bytes_allocated: now.bytes_allocated - self.start.bytes_allocated,
+            bytes_freed: now.bytes_freed - self.start.bytes_freed,
+            bytes_in_use: now.bytes_in_use - self.start.bytes_in_use,
+            peak_bytes: now.peak_bytes - self.start.bytes_in_use,
+        }
+    }
+
+    pub fn finish(self) -> Report {
+        self.report()
+    }
+}
+
+impl Drop for Scope {
+    fn drop(&mut self) {
+        let peak = thread_stats().peak_bytes;
+        set_thread_peak(peak.max(self.outer_peak));
+    }
+}
+
+// Runs f in a scope of its own and reports what it allocated. Whatever f returns is dropped after the report is taken.
+pub fn measure<T, F: FnOnce() -> T>(name: &'static str, f: F) -> (T, Report) {
+    let scope = Scope::new(name);
+    let value = f();
+    (value, scope.finish())
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} allocations, {} deallocations, {} reallocations, {} bytes allocated, {} bytes freed, {} bytes in use, peak {} bytes",
+            self.allocations,
+            self.deallocations,
+            self.reallocations,
+            self.bytes_allocated,
+            self.bytes_freed,
+            self.bytes_in_use,
+            self.peak_bytes
+        )
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} allocations, {} deallocations, {} reallocations, {} bytes allocated, {} bytes freed, {} bytes still in use, peak {} bytes",
+            self.name,
+            self.allocations,
+            self.deallocations,
+            self.reallocations,
+            self.bytes_allocated,
+            self.bytes_freed,
+            self.bytes_in_use,
+            self.peak_bytes
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[global_allocator]
+    static ALLOCATOR: TrackingAllocator = TrackingAllocator;
+
+    #[test]
+    fn stack_values_do_not_allocate() {
+        let (_, report) = measure("integers", || {
+            let z = 2;
+            let y = z;
+            std::hint::black_box(y)
+        });
+        assert!(is_installed());
+        assert_eq!(report.allocations, 0);
+        assert_eq!(report.peak_bytes, 0);
+    }
+
+    #[test]
+    fn string_allocates_and_frees() {
+        let scope = Scope::new("string");
+        let string = String::from("Heap_Data");
+        let allocated = scope.report();
+        assert_eq!(allocated.allocations, 1);
+        assert_eq!(allocated.bytes_in_use, 9);
+        drop(string);
+        let report = scope.finish();
+        assert_eq!(report.deallocations, 1);
+        assert_eq!(report.bytes_freed, 9);
+        assert_eq!(report.bytes_in_use, 0);
+        assert_eq!(report.peak_bytes, 9);
+    }
+
+    #[test]
+    fn growing_reallocates() {
+        let (vector, report) = measure("vector", || {
+            let mut vector: Vec<u64> = vec![1];
+            vector.push(2);
+            vector
+        });
+        assert_eq!(report.allocations, 1);
+        assert!(report.reallocations >= 1);
+        assert_eq!(report.bytes_in_use, (vector.capacity() * 8) as i64);
+    }
+
+    #[test]
+    fn nested_scopes_keep_the_outer_peak() {
+        let outer = Scope::new("outer");
+        drop(vec![0u8; 1000]);
+        let (_, inner) = measure("inner", || drop(vec![0u8; 10]));
+        assert_eq!(inner.peak_bytes, 10);
+        let outer = outer.finish();
+        assert_eq!(outer.peak_bytes, 1000);
+        assert_eq!(outer.allocations, 2);
+    }
+}
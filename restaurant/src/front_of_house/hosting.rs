@@ -0,0 +1,431 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 06:22:55
+/// @Description: Hosting, the Waitlist and the Tables
+/*
+- A Host keeps the tables of the restaurant and the waitlist of the parties that are waiting for one. It is the API of the front desk:
+    -> add_table() adds a table with the number of seats it has.
+    -> add_to_waitlist() puts a party at the end of the waitlist and quotes how long it will wait.
+    -> seat_parties() seats every waiting party that a free table fits, and clear_table() frees the table of a party that left.
+    -> quote() tells a waiting party where it is and how long it still has to wait, leave_waitlist() takes it off the list.
+- Times are Durations since the restaurant opened, the front desk passes the current one. The host has no clock of its own, so the same calls give the
+  same answers, in a test too.
+- Seating policy:
+    -> the waitlist is served in order of arrival, but a party that no free table fits does not hold up a smaller one behind it.
+       A party is only passed for a table it does not fit, so the smaller parties behind it never keep it waiting.
+    -> a party gets the smallest free table it fits, so the big tables stay free for the big parties. Between tables of the same size the one added
+       first wins.
+- A quote plays the waitlist forward: every taken table is expected to be free again turn_time after its party was seated, or now when it is late,
+  and each time one frees up the parties ahead are seated by the same policy as seat_parties, smaller ones skipping ahead too. It is an estimate,
+  parties stay longer or shorter than turn_time.
+- A party that is bigger than every table is turned away when it arrives, it would wait forever.
+*/
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PartyId(u64);
+
+impl fmt::Display for PartyId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "party #{}", self.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TableId(usize);
+
+impl fmt::Display for TableId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "table {}", self.0 + 1)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Party {
+    pub id: PartyId,
+    pub name: String,
+    pub size: u32,
+    pub arrived: Duration,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Occupant {
+    pub party: PartyId,
+    pub seated: Duration,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Table {
+    pub id: TableId,
+    pub capacity: u32,
+    pub occupant: Option<Occupant>,
+}
+
+impl Table {
+    pub fn is_free(&self) -> bool {
+        self.occupant.is_none()
+    }
+}
+
+// position is 1 for the party that is next.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Quote {
+    pub position: usize,
+    pub wait: Duration,
+}
+
+impl fmt::Display for Quote {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let minutes = self.wait.as_secs().div_ceil(60);
+        if minutes == 0 {
+            write!(f, "number {} in line, no wait", self.position)
+        } else {
+            write!(f, "number {} in line, about {} min", self.position, minutes)
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Seating {
+    pub party: Party,
+    pub table: TableId,
+    pub waited: Duration,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HostingError {
+    EmptyParty,
+    TooLarge { size: u32, largest: u32 },
+    UnknownParty(PartyId),
+    UnknownTable(TableId),
+    TableFree(TableId),
+}
+
+impl fmt::Display for HostingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HostingError::EmptyParty => write!(f, "a party has at least one guest"),
+            HostingError::TooLarge { size, largest } => write!(
+                f,
+                "no table seats {} guests, the largest seats {}",
+                size, largest
+            ),
+            HostingError::UnknownParty(party) => write!(f, "{} is not on the waitlist", party),
+            HostingError::UnknownTable(table) => write!(f, "there is no {}", table),
+            HostingError::TableFree(table) => write!(f, "nobody sits at {}", table),
+        }
+    }
+}
+
+impl Error for HostingError {}
+
+#[derive(Debug)]
+pub struct Host {
+    tables: Vec<Table>,
+    waitlist: VecDeque<Party>,
+    turn_time: Duration,
+    next_party: u64,
+}
+
+impl Host {
+    // turn_time is how long a party is expected to keep its table, the quotes are based on it.
+    pub fn new(turn_time: Duration) -> Host {
+        Host {
+            tables: Vec::new(),
+            waitlist: VecDeque::new(),
+            turn_time,
+            next_party: 1,
+        }
+    }
+
+    pub fn add_table(&mut self, capacity: u32) -> TableId {
+        let id = TableId(self.tables.len());
+        self.tables.push(Table {
+            id,
+            capacity,
+            occupant: None,
+        });
+        id
+    }
+
+    pub fn tables(&self) -> &[Table] {
+        &self.tables
+    }
+
+    pub fn waitlist(&self) -> impl Iterator<Item = &Party> {
+        self.waitlist.iter()
+    }
+
+    pub fn add_to_waitlist(
+        &mut self,
+        name: &str,
+        size: u32,
+        now: Duration,
+    ) -> Result<(PartyId, Quote), HostingError> {
+        if size == 0 {
+            return Err(HostingError::EmptyParty);
+        }
+        let largest = self
+            .tables
+            .iter()
+            .map(|table| table.capacity)
+            .max()
+            .unwrap_or(0);
+        if size > largest {
+            return Err(HostingError::TooLarge { size, largest });
+        }
+        let id = PartyId(self.next_party);
+        self.next_party += 1;
+        self.waitlist.push_back(Party {
+            id,
+            name: name.to_string(),
+            size,
+            arrived: now,
+        });
+        Ok((id, self.estimate(self.waitlist.len() - 1, now)))
+    }
+
+    pub fn quote(&self, party: PartyId, now: Duration) -> Result<Quote, HostingError> {
+        Ok(self.estimate(self.position(party)?, now))
+    }
+
+    pub fn leave_waitlist(&mut self, party: PartyId) -> Result<Party, HostingError> {
+        let index = self.position(party)?;
+        Ok(self
+            .waitlist
+            .remove(index)
+            .expect("the index was just found"))
+    }
+
+    // Returns the parties that were seated, in the order they were waiting.
+    pub fn seat_parties(&mut self, now: Duration) -> Vec<Seating> {
+        let mut seated = Vec::new();
+        let mut index = 0;
+        while index < self.waitlist.len() {
+            match self.seat_at_table(index, now) {
+                Some(seating) => seated.push(seating),
+                None => index += 1,
+            }
+        }
+        seated
+    }
+
+    // Frees the table and returns the party that sat there.
+    pub fn clear_table(&mut self, table: TableId) -> Result<PartyId, HostingError> {
+        let table = self
+            .tables
+            .get_mut(table.0)
+            .ok_or(HostingError::UnknownTable(table))?;
+        table
+            .occupant
+            .take()
+            .map(|occupant| occupant.party)
+            .ok_or(HostingError::TableFree(table.id))
+    }
+
+    fn position(&self, party: PartyId) -> Result<usize, HostingError> {
+        self.waitlist
+            .iter()
+            .position(|waiting| waiting.id == party)
+            .ok_or(HostingError::UnknownParty(party))
+    }
+
+    // Seats the party at index at the smallest free table it fits, if there is one.
+    fn seat_at_table(&mut self, index: usize, now: Duration) -> Option<Seating> {
+        let size = self.waitlist[index].size;
+        let table = self
+            .tables
+            .iter_mut()
+            .filter(|table| table.is_free() && table.capacity >= size)
+            .min_by_key(|table| table.capacity)?;
+        let party = self.waitlist.remove(index)?;
+        table.occupant = Some(Occupant {
+            party: party.id,
+            seated: now,
+        });
+        Some(Seating {
+            waited: now.saturating_sub(party.arrived),
+            table: table.id,
+            party,
+        })
+    }
+
+    // Replays seat_parties on a copy of the host each time a table is expected to free up, so the quote follows the same skip-ahead as the
+    // seating. The parties behind the one quoted are left out, they only take tables it does not fit.
+    fn estimate(&self, index: usize, now: Duration) -> Quote {
+        let party = self.waitlist[index].id;
+        let mut future = Host {
+            tables: self.tables.clone(),
+            waitlist: self.waitlist.iter().take(index + 1).cloned().collect(),
+            turn_time: self.turn_time,
+            next_party: self.next_party,
+        };
+        let mut time = now;
+        loop {
+            for table in &mut future.tables {
+                if table
+                    .occupant
+                    .is_some_and(|occupant| occupant.seated + self.turn_time <= time)
+                {
+                    table.occupant = None;
+                }
+            }
+            let seated = future.seat_parties(time);
+            if seated.iter().any(|seating| seating.party.id == party) {
+                return Quote {
+                    position: index + 1,
+                    wait: time - now,
+                };
+            }
+            time = future
+                .tables
+                .iter()
+                .filter_map(|table| table.occupant)
+                .map(|occupant| occupant.seated + self.turn_time)
+                .min()
+                .expect("a party that fits a table is seated once every table is free");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn minutes(minutes: u64) -> Duration {
+        Duration::from_secs(minutes * 60)
+    }
+
+    fn host(capacities: &[u32]) -> Host {
+        let mut host = Host::new(minutes(45));
+        for &capacity in capacities {
+            host.add_table(capacity);
+        }
+        host
+    }
+
+    #[test]
+    fn parties_get_the_smallest_table_they_fit() {
+        let mut host = host(&[6, 2, 4]);
+        host.add_to_waitlist("Ada", 3, minutes(0)).unwrap();
+        host.add_to_waitlist("Bo", 2, minutes(1)).unwrap();
+        let seated = host.seat_parties(minutes(5));
+        let tables: Vec<(&str, TableId)> = seated
+            .iter()
+            .map(|seating| (seating.party.name.as_str(), seating.table))
+            .collect();
+        assert_eq!(tables, [("Ada", TableId(2)), ("Bo", TableId(1))]);
+        assert_eq!(seated[1].waited, minutes(4));
+        assert!(host.tables()[0].is_free());
+        assert_eq!(host.waitlist().count(), 0);
+    }
+
+    #[test]
+    fn a_big_party_does_not_hold_up_the_line() {
+        let mut host = host(&[2, 8]);
+        let (big, _) = host.add_to_waitlist("Crew", 8, minutes(0)).unwrap();
+        host.add_to_waitlist("Pair", 2, minutes(0)).unwrap();
+        host.add_to_waitlist("Late crew", 7, minutes(0)).unwrap();
+        host.seat_parties(minutes(0));
+        let waiting: Vec<&str> = host.waitlist().map(|party| party.name.as_str()).collect();
+        assert_eq!(waiting, ["Late crew"]);
+
+        let table = host
+            .tables()
+            .iter()
+            .find(|table| table.occupant.map(|occupant| occupant.party) == Some(big))
+            .unwrap()
+            .id;
+        assert_eq!(host.clear_table(table), Ok(big));
+        assert_eq!(host.clear_table(table), Err(HostingError::TableFree(table)));
+        assert_eq!(host.seat_parties(minutes(50))[0].waited, minutes(50));
+    }
+
+    #[test]
+    fn quotes_play_the_waitlist_forward() {
+        let mut host = host(&[4, 4]);
+        host.add_to_waitlist("A", 4, minutes(0)).unwrap();
+        host.add_to_waitlist("B", 4, minutes(0)).unwrap();
+        host.seat_parties(minutes(0));
+
+        let (c, quote) = host.add_to_waitlist("C", 2, minutes(10)).unwrap();
+        assert_eq!(
+            quote,
+            Quote {
+                position: 1,
+                wait: minutes(35)
+            }
+        );
+        let (_, quote) = host.add_to_waitlist("D", 3, minutes(10)).unwrap();
+        assert_eq!(
+            quote,
+            Quote {
+                position: 2,
+                wait: minutes(35)
+            }
+        );
+        let (_, quote) = host.add_to_waitlist("E", 1, minutes(10)).unwrap();
+        assert_eq!(
+            quote,
+            Quote {
+                position: 3,
+                wait: minutes(80)
+            }
+        );
+        assert_eq!(quote.to_string(), "number 3 in line, about 80 min");
+
+        // Late tables are expected to free up any moment.
+        assert_eq!(host.quote(c, minutes(60)).unwrap().wait, Duration::ZERO);
+        host.leave_waitlist(c).unwrap();
+        assert_eq!(
+            host.quote(c, minutes(60)),
+            Err(HostingError::UnknownParty(c))
+        );
+    }
+
+    #[test]
+    fn quotes_follow_the_skip_ahead() {
+        let mut host = host(&[2, 8]);
+        host.add_to_waitlist("Crew", 8, minutes(0)).unwrap();
+        let (big, _) = host.add_to_waitlist("Big", 6, minutes(0)).unwrap();
+        host.add_to_waitlist("Pair", 2, minutes(0)).unwrap();
+        host.seat_parties(minutes(0));
+
+        let (late, quote) = host.add_to_waitlist("Late pair", 2, minutes(10)).unwrap();
+        assert_eq!(quote.wait, minutes(35));
+        assert_eq!(host.quote(big, minutes(10)).unwrap().wait, minutes(35));
+
+        for table in [TableId(0), TableId(1)] {
+            host.clear_table(table).unwrap();
+        }
+        let waited: Vec<(PartyId, Duration)> = host
+            .seat_parties(minutes(45))
+            .iter()
+            .map(|seating| (seating.party.id, seating.waited))
+            .collect();
+        assert_eq!(waited, [(big, minutes(45)), (late, minutes(35))]);
+    }
+
+    #[test]
+    fn parties_that_cannot_be_seated_are_turned_away() {
+        let mut host = host(&[2, 4]);
+        assert_eq!(
+            host.add_to_waitlist("Nobody", 0, minutes(0)),
+            Err(HostingError::EmptyParty)
+        );
+        assert_eq!(
+            host.add_to_waitlist("Team", 5, minutes(0)),
+            Err(HostingError::TooLarge {
+                size: 5,
+                largest: 4
+            })
+        );
+        assert_eq!(
+            host.clear_table(TableId(9)),
+            Err(HostingError::UnknownTable(TableId(9)))
+        );
+        assert_eq!(host.waitlist().count(), 0);
+    }
+}
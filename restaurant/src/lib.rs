@@ -20,7 +20,10 @@
 - Relative paths can be constructed that begin in the parent module, rather than the current module or the crate root, by using super at the start of the path. This
   is like starting a filesystem path with the .. syntax. Using super allows us to reference an item that we know is in the parent module, which can make rearranging
   the module tree easier when the module is closely related to the parent but the parent might be moved elsewhere in the module tree someday.
+- hosting is no longer empty, it is the waitlist and seating engine of the front desk: Host, reached from outside as restaurant::hosting::Host through the
+  pub use below. front_of_house itself stays private.
 */
+use std::time::Duration;
 pub fn add(left: u64, right: u64) -> u64 {
     left + right
 }
@@ -28,11 +31,7 @@ pub fn add(left: u64, right: u64) -> u64 {
 mod module_different_file; //Rust finds this module in another files whose name matches the module name.
 
 mod front_of_house {
-    pub mod hosting {
-        pub fn add_to_waitlist() {}
-
-        fn seat_at_table() {}
-    }
+    pub mod hosting; // Declared in an inline module, the compiler looks for src/front_of_house/hosting.rs.
 
     mod serving {
         fn take_order() {}
@@ -52,12 +51,14 @@ mod back_of_house_super {
 
     fn cook_order() {}
 }
-pub fn eat_at_restaurant_path() {
+// Both paths reach the same Host, the front desk that is built here is handed to the caller.
+pub fn eat_at_restaurant_path() -> hosting::Host {
     // Absolute path
-    crate::front_of_house::hosting::add_to_waitlist();
+    let mut host = crate::front_of_house::hosting::Host::new(Duration::from_secs(45 * 60));
 
     // Relative path
-    front_of_house::hosting::add_to_waitlist();
+    front_of_house::hosting::Host::add_table(&mut host, 4);
+    host
 }
 
 mod back_of_house {
@@ -104,9 +105,11 @@ pub fn eat_at_restaurant_enum() {
 
 pub use crate::front_of_house::hosting; // Brings the module into scope
 pub fn eat_at_restaurant_use_keyword() {
-    hosting::add_to_waitlist();
-    hosting::add_to_waitlist();
-    hosting::add_to_waitlist();
+    let mut host = hosting::Host::new(Duration::from_secs(45 * 60));
+    host.add_table(4);
+    host.add_to_waitlist("Ferris", 2, Duration::ZERO).unwrap();
+    host.add_to_waitlist("Corro", 4, Duration::ZERO).unwrap();
+    host.seat_parties(Duration::ZERO);
 }
 
 // use std::fmt;
@@ -133,7 +136,7 @@ fn main() {
     map.insert(1, 2);
 }
 use rand::Rng;
-use rand::{CryptoRng, ErrorKind::Transient}; // Nested Paths
+use rand::{rngs::ThreadRng, CryptoRng}; // Nested Paths
 fn rand_num() {
     let secret_number = rand::thread_rng().gen_range(1..=100);
 }
@@ -9,4 +9,4 @@ For a module named hosting that is a submodule of front_of_house, the compiler w
     -> src/front_of_house/hosting.rs
     -> src/front_of_house/hosting/mod.rs (older style, still supported path)
 */
-pub use nested_module;
+pub mod nested_module;
@@ -35,6 +35,7 @@
 - Associated functions that aren’t methods are often used for constructors that will return a new instance of the struct. These are often called new, but new isn’t a
   special name and isn’t built into the language. To call this associated function, the :: syntax is used with the struct name.
 */
+use fundamentals_core::{Align, Table};
 
 struct User {
     active: bool,
@@ -127,6 +128,11 @@ fn main() {
         area_tuple(rect1)
     );
 
+    let rect1 = Rectangle {
+        width: 30,
+        height: 50,
+    };
+
     println!(
         "The area of the rectangle is {} square pixels.",
         area_struct(&rect1)
@@ -162,6 +168,26 @@ fn main() {
     println!("Can rect1 hold rect2? {}", rect1.can_hold(&rect2));
     println!("Can rect1 hold rect3? {}", rect1.can_hold(&rect3));
 
+    let table = Table::new()
+        .column("rectangle", 9, Align::Left)
+        .column("width", 5, Align::Right)
+        .column("height", 6, Align::Right)
+        .column("area", 5, Align::Right)
+        .column("fits in rect1", 13, Align::Right);
+    println!("{}", table.header());
+    for (name, rect) in [("rect1", &rect1), ("rect2", &rect2), ("rect3", &rect3)] {
+        println!(
+            "{}",
+            table.row(&[
+                &name,
+                &rect.width,
+                &rect.height,
+                &rect.rect_area(),
+                &rect1.can_hold(rect)
+            ])
+        );
+    }
+
     let _sq = Rectangle::square(3);
 }
 
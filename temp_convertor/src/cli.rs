@@ -0,0 +1,531 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 05:38:00
+/// @Description: Command Line Interface
+/*
+- temp_convertor 132F --to C                                  -> converts a single value.
+- temp_convertor --table --from C --to F --start -40 --end 100 --step 10
+                                                              -> prints a conversion table over a range.
+- temp_convertor --batch [FILE] --to K                        -> converts one value per line from FILE, or stdin when FILE is left out.
+- temp_convertor --csv [FILE] --column temp_f --from F --to C [--append temp_c]
+                                                              -> converts one column of a CSV file, see the dataset module.
+- temp_convertor --jsonl [FILE] --field temp --from F --to C  -> same for JSON lines, --field and --column are the same option.
+- An option the chosen mode does not use is a usage error, e.g. --from with a single value, whose scale is part of the value.
+- A table needs finite --start, --end and --step, a --step above zero and at most 10 000 rows, so a typo cannot make it run forever.
+- In batch and dataset modes a bad line does not stop the run. It is reported on stderr with its line number and the exit code is non-zero once the run is done.
+- The argument parsing and the modes live in the library, not in main.rs, so they can be driven from tests with in-memory readers and writers.
+*/
+use crate::dataset::{ColumnConversion, DatasetError, Format};
+use crate::{ParseTemperatureError, Scale, Temperature};
+use fundamentals_core::{data_lines, Align, Args, Table, UsageError};
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead, Write};
+use std::path::PathBuf;
+
+pub const USAGE: &str = "\
+Usage:
+    temp_convertor <VALUE><SCALE> --to <SCALE> [--precision N]
+    temp_convertor --table --from <SCALE> --to <SCALE> --start <N> --end <N> --step <N> [--precision N]
+    temp_convertor --batch [FILE] --to <SCALE> [--precision N]
+    temp_convertor --csv [FILE] --column <NAME> --from <SCALE> --to <SCALE> [--append <NAME>] [--precision N]
+    temp_convertor --jsonl [FILE] --field <NAME> --from <SCALE> --to <SCALE> [--append <NAME>] [--precision N]
+
+Scales: C, F, K, R (Rankine), Re (Réaumur), De (Delisle)";
+
+pub const MAX_TABLE_ROWS: u64 = 10_000;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Mode {
+    Single(Temperature),
+    Table {
+        from: Scale,
+        start: f64,
+        end: f64,
+        step: f64,
+    },
+    Batch(Option<PathBuf>),
+    Dataset {
+        format: Format,
+        input: Option<PathBuf>,
+        conversion: ColumnConversion,
+    },
+    Help,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Options {
+    pub mode: Mode,
+    pub to: Scale,
+    pub precision: usize,
+}
+
+#[derive(Debug)]
+pub enum CliError {
+    Usage(String),
+    Parse(ParseTemperatureError),
+    Dataset(DatasetError),
+    Io(io::Error),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CliError::Usage(message) => write!(f, "{}\n\n{}", message, USAGE),
+            CliError::Parse(err) => write!(f, "{}", err),
+            CliError::Dataset(err) => write!(f, "{}", err),
+            CliError::Io(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl Error for CliError {}
+
+impl From<ParseTemperatureError> for CliError {
+    fn from(err: ParseTemperatureError) -> CliError {
+        CliError::Parse(err)
+    }
+}
+
+impl From<DatasetError> for CliError {
+    fn from(err: DatasetError) -> CliError {
+        CliError::Dataset(err)
+    }
+}
+
+impl From<io::Error> for CliError {
+    fn from(err: io::Error) -> CliError {
+        CliError::Io(err)
+    }
+}
+
+impl From<UsageError> for CliError {
+    fn from(err: UsageError) -> CliError {
+        CliError::Usage(err.0)
+    }
+}
+
+// Takes the arguments without the program name, i.e. std::env::args().skip(1).
+pub fn parse_args<I: IntoIterator<Item = String>>(args: I) -> Result<Options, CliError> {
+    let mut args = Args::new(args);
+    let mut to = None;
+    let mut from = None;
+    let mut start = None;
+    let mut end = None;
+    let mut step = None;
+    let mut precision = 2;
+    let mut table = false;
+    let mut batch = None;
+    let mut dataset = None;
+    let mut column = None;
+    let mut append = None;
+    let mut value = None;
+    let mut given = Vec::new();
+
+    while let Some(arg) = args.next() {
+        if arg.starts_with("--") {
+            given.push(arg.clone());
+        }
+        match arg.as_str() {
+            "-h" | "--help" => {
+                return Ok(Options {
+                    mode: Mode::Help,
+                    to: Scale::Celsius,
+                    precision,
+                })
+            }
+            "--to" => to = Some(args.value("--to")?.parse::<Scale>()?),
+            "--from" => from = Some(args.value("--from")?.parse::<Scale>()?),
+            "--start" => start = Some(args.number("--start")?),
+            "--end" => end = Some(args.number("--end")?),
+            "--step" => step = Some(args.number("--step")?),
+            "--precision" => precision = args.number("--precision")?,
+            "--table" => table = true,
+            // The file is optional, a following flag means read from stdin.
+            "--batch" => batch = Some(args.optional().map(PathBuf::from)),
+            "--csv" => dataset = Some((Format::Csv, args.optional().map(PathBuf::from))),
+            "--jsonl" => dataset = Some((Format::JsonLines, args.optional().map(PathBuf::from))),
+            "--column" | "--field" => column = Some(args.value(&arg)?),
+            "--append" => append = Some(args.value("--append")?),
+            _ if arg.starts_with("--") => {
+                return Err(CliError::Usage(format!("unknown option '{}'", arg)))
+            }
+            _ if value.is_none() => value = Some(arg),
+            _ => return Err(CliError::Usage(format!("unexpected argument '{}'", arg))),
+        }
+    }
+
+    let to = to.ok_or_else(|| CliError::Usage("--to is required".to_string()))?;
+    let chosen = table as usize
+        + batch.is_some() as usize
+        + dataset.is_some() as usize
+        + value.is_some() as usize;
+    if chosen > 1 {
+        return Err(CliError::Usage(
+            "pick one of a value, --table, --batch, --csv or --jsonl".to_string(),
+        ));
+    }
+    let chosen = if table {
+        Some("--table")
+    } else if batch.is_some() {
+        Some("--batch")
+    } else {
+        dataset.as_ref().map(|(format, _)| match format {
+            Format::Csv => "--csv",
+            Format::JsonLines => "--jsonl",
+        })
+    };
+    if chosen.is_some() || value.is_some() {
+        check_options(chosen, &given).map_err(CliError::Usage)?;
+    }
+    let mode = if table {
+        let missing = |flag: &str| CliError::Usage(format!("--table needs {}", flag));
+        let step = step.ok_or_else(|| missing("--step"))?;
+        let start = start.ok_or_else(|| missing("--start"))?;
+        let end = end.ok_or_else(|| missing("--end"))?;
+        row_count(start, end, step).map_err(CliError::Usage)?;
+        Mode::Table {
+            from: from.ok_or_else(|| missing("--from"))?,
+            start,
+            end,
+            step,
+        }
+    } else if let Some(file) = batch {
+        Mode::Batch(file)
+    } else if let Some((format, input)) = dataset {
+        let missing = |flag: &str| CliError::Usage(format!("--csv and --jsonl need {}", flag));
+        Mode::Dataset {
+            format,
+            input,
+            conversion: ColumnConversion {
+                column: column.ok_or_else(|| missing("--column"))?,
+                from: from.ok_or_else(|| missing("--from"))?,
+                to,
+                append,
+                precision,
+            },
+        }
+    } else if let Some(value) = value {
+        Mode::Single(value.parse()?)
+    } else {
+        return Err(CliError::Usage("nothing to convert".to_string()));
+    };
+
+    Ok(Options {
+        mode,
+        to,
+        precision,
+    })
+}
+
+// The options each mode uses, mode is None for a single value. Any other option is a mistake, not something to ignore.
+fn mode_options(mode: Option<&str>) -> &'static [&'static str] {
+    match mode {
+        Some("--table") => &[
+            "--to",
+            "--precision",
+            "--from",
+            "--start",
+            "--end",
+            "--step",
+        ],
+        Some("--csv") | Some("--jsonl") => &[
+            "--to",
+            "--precision",
+            "--from",
+            "--column",
+            "--field",
+            "--append",
+        ],
+        _ => &["--to", "--precision"],
+    }
+}
+
+fn check_options(mode: Option<&str>, given: &[String]) -> Result<(), String> {
+    let options = mode_options(mode);
+    match given
+        .iter()
+        .find(|flag| Some(flag.as_str()) != mode && !options.contains(&flag.as_str()))
+    {
+        Some(flag) => Err(format!(
+            "{} does not go with {}",
+            flag,
+            mode.unwrap_or("a single value")
+        )),
+        None => Ok(()),
+    }
+}
+
+// The number of rows from start to end, end included when a step lands on it. A little slack lets 0.1 steps reach the end.
+pub fn row_count(start: f64, end: f64, step: f64) -> Result<u64, String> {
+    if !(start.is_finite() && end.is_finite() && step.is_finite()) {
+        return Err("--start, --end and --step must be finite numbers".to_string());
+    }
+    if step <= 0.0 {
+        return Err("--step must be greater than zero".to_string());
+    }
+    let steps = ((end - start) / step + 1e-9).floor();
+    if steps < 0.0 {
+        return Ok(0);
+    }
+    if steps >= MAX_TABLE_ROWS as f64 {
+        return Err(format!(
+            "the table would have more than {} rows, use a bigger --step",
+            MAX_TABLE_ROWS
+        ));
+    }
+    Ok(steps as u64 + 1)
+}
+
+pub fn write_table<W: Write>(
+    out: &mut W,
+    from: Scale,
+    to: Scale,
+    (start, end, step): (f64, f64, f64),
+    precision: usize,
+) -> io::Result<()> {
+    let rows = row_count(start, end, step)
+        .map_err(|message| io::Error::new(io::ErrorKind::InvalidInput, message))?;
+    let width = 12 + precision;
+    let table = Table::new()
+        .column(from.symbol(), width, Align::Right)
+        .column(to.symbol(), width, Align::Right);
+    writeln!(out, "{}", table.header())?;
+
+    // Multiply instead of accumulating so the rows do not drift.
+    for row in 0..rows {
+        let value = start + step * row as f64;
+        let converted = match Temperature::new(value, from) {
+            Ok(temp) => format!("{:.*}", precision, temp.value_in(to)),
+            Err(_) => "-".to_string(),
+        };
+        writeln!(
+            out,
+            "{}",
+            table.row(&[&format!("{:.*}", precision, value), &converted])
+        )?;
+    }
+    Ok(())
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct BatchReport {
+    pub converted: usize,
+    pub errors: Vec<(usize, ParseTemperatureError)>,
+}
+
+impl BatchReport {
+    pub fn is_clean(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+// Blank lines and lines starting with # are skipped. Line numbers in the report start at 1.
+pub fn run_batch<R: BufRead, W: Write, E: Write>(
+    input: R,
+    out: &mut W,
+    err: &mut E,
+    to: Scale,
+    precision: usize,
+) -> io::Result<BatchReport> {
+    let mut report = BatchReport::default();
+    for line in data_lines(input) {
+        let (number, line) = line?;
+        match line.parse::<Temperature>() {
+            Ok(temp) => {
+                writeln!(out, "{:.*}", precision, temp.to(to))?;
+                report.converted += 1;
+            }
+            Err(parse_err) => {
+                writeln!(err, "line {}: {}", number, parse_err)?;
+                report.errors.push((number, parse_err));
+            }
+        }
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn parse_single_value() {
+        let options = parse_args(args("132F --to C")).unwrap();
+        assert_eq!(
+            options.mode,
+            Mode::Single(Temperature::fahrenheit(132.0).unwrap())
+        );
+        assert_eq!(options.to, Scale::Celsius);
+        assert_eq!(options.precision, 2);
+    }
+
+    #[test]
+    fn parse_table_and_batch() {
+        let options = parse_args(args(
+            "--table --from C --to F --start -40 --end 100 --step 10",
+        ))
+        .unwrap();
+        assert_eq!(
+            options.mode,
+            Mode::Table {
+                from: Scale::Celsius,
+                start: -40.0,
+                end: 100.0,
+                step: 10.0
+            }
+        );
+        assert_eq!(
+            parse_args(args("--batch --to K")).unwrap().mode,
+            Mode::Batch(None)
+        );
+        assert_eq!(
+            parse_args(args("--batch temps.txt --to K")).unwrap().mode,
+            Mode::Batch(Some(PathBuf::from("temps.txt")))
+        );
+        assert_eq!(
+            parse_args(args(
+                "--csv log.csv --column temp_f --from F --to C --append temp_c"
+            ))
+            .unwrap()
+            .mode,
+            Mode::Dataset {
+                format: Format::Csv,
+                input: Some(PathBuf::from("log.csv")),
+                conversion: ColumnConversion {
+                    column: "temp_f".to_string(),
+                    from: Scale::Fahrenheit,
+                    to: Scale::Celsius,
+                    append: Some("temp_c".to_string()),
+                    precision: 2,
+                },
+            }
+        );
+        assert!(matches!(
+            parse_args(args("--jsonl --field t --to C")),
+            Err(CliError::Usage(_))
+        ));
+    }
+
+    #[test]
+    fn parse_rejects_bad_arguments() {
+        assert!(matches!(parse_args(args("132F")), Err(CliError::Usage(_))));
+        assert!(matches!(
+            parse_args(args("132F --to X")),
+            Err(CliError::Parse(_))
+        ));
+        assert!(matches!(
+            parse_args(args("--table --from C --to F --start 0 --end 10 --step 0")),
+            Err(CliError::Usage(_))
+        ));
+        for range in [
+            "--start 0 --end 10 --step nan",
+            "--start 0 --end inf --step 1",
+            "--start -inf --end 0 --step 1",
+            "--start 1e20 --end 2e20 --step 1",
+        ] {
+            let line = format!("--table --from C --to F {}", range);
+            assert!(
+                matches!(parse_args(args(&line)), Err(CliError::Usage(_))),
+                "{}",
+                line
+            );
+        }
+        assert!(matches!(
+            parse_args(args("132F --batch --to C")),
+            Err(CliError::Usage(_))
+        ));
+    }
+
+    #[test]
+    fn parse_rejects_options_of_other_modes() {
+        for (line, message) in [
+            (
+                "132F --to C --from K",
+                "--from does not go with a single value",
+            ),
+            (
+                "--csv --column t --from F --to C --start 0",
+                "--start does not go with --csv",
+            ),
+            (
+                "--table --from C --to F --start 0 --end 10 --step 1 --append c",
+                "--append does not go with --table",
+            ),
+            (
+                "--table --from C --to F --start 0 --end 10 --step 1 --column t",
+                "--column does not go with --table",
+            ),
+            ("--batch --to K --from C", "--from does not go with --batch"),
+            (
+                "--jsonl --field t --from F --to C --step 1",
+                "--step does not go with --jsonl",
+            ),
+        ] {
+            match parse_args(args(line)) {
+                Err(CliError::Usage(err)) => assert_eq!(err, message),
+                other => panic!("{}: {:?}", line, other),
+            }
+        }
+    }
+
+    #[test]
+    fn table_rows() {
+        let mut out = Vec::new();
+        write_table(
+            &mut out,
+            Scale::Celsius,
+            Scale::Fahrenheit,
+            (-300.0, 100.0, 100.0),
+            1,
+        )
+        .unwrap();
+        let out = String::from_utf8(out).unwrap();
+        let rows: Vec<Vec<&str>> = out
+            .lines()
+            .map(|line| line.split_whitespace().collect())
+            .collect();
+        assert_eq!(rows.len(), 6);
+        assert_eq!(rows[1], vec!["-300.0", "-"]);
+        assert_eq!(rows[2], vec!["-200.0", "-328.0"]);
+        assert_eq!(rows[5], vec!["100.0", "212.0"]);
+
+        assert_eq!(row_count(0.0, 1.0, 0.1), Ok(11));
+        assert_eq!(row_count(5.0, 0.0, 1.0), Ok(0));
+        assert_eq!(row_count(0.0, 9_999.0, 1.0), Ok(MAX_TABLE_ROWS));
+        assert!(row_count(0.0, 10_000.0, 1.0).is_err());
+        // Half a step does not move 1e16, the rows are counted up front so the table still ends.
+        let mut out = Vec::new();
+        write_table(
+            &mut out,
+            Scale::Celsius,
+            Scale::Kelvin,
+            (1e16, 1e16 + 10.0, 0.5),
+            0,
+        )
+        .unwrap();
+        assert_eq!(String::from_utf8(out).unwrap().lines().count(), 22);
+    }
+
+    #[test]
+    fn batch_reports_bad_lines_and_keeps_going() {
+        let input = "132F\n\n# comment\nabc\n0C\n-10K\n";
+        let mut out = Vec::new();
+        let mut err = Vec::new();
+        let report = run_batch(input.as_bytes(), &mut out, &mut err, Scale::Kelvin, 2).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "328.71K\n273.15K\n");
+        assert_eq!(report.converted, 2);
+        assert_eq!(
+            report
+                .errors
+                .iter()
+                .map(|(line, _)| *line)
+                .collect::<Vec<_>>(),
+            vec![4, 6]
+        );
+        assert!(String::from_utf8(err).unwrap().starts_with("line 4: "));
+    }
+}
@@ -0,0 +1,684 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 05:39:29
+/// @Description: Column Conversion for CSV and JSON Lines Datasets
+/*
+- A dataset is read one record at a time and every record is written out before the next one is read, so a file of any size is converted in constant memory.
+- CSV files must start with a header row, the column to convert is looked up by name in it. Quoted fields, doubled quotes and line breaks inside quotes are
+  supported, up to 32 lines per record. A quote still open after that, or at the end of the input, is reported on its own line and reading picks
+  up again at the next line. Only a quote at the start of a field opens one, a quote inside an unquoted field is reported with its row alone.
+- JSON lines files hold one flat object per line and the field is looked up by key. Nested values in other fields are carried over untouched.
+- The converted value either replaces the original one or is appended as a new column (or field) next to the untouched original.
+- A malformed row is not dropped. It is written out unchanged (with an empty appended cell for CSV) and recorded in the report with its line number, so the
+  caller can decide what to do with the bad rows after the run.
+*/
+use crate::{Scale, Temperature};
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead, Write};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Csv,
+    JsonLines,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnConversion {
+    pub column: String,
+    pub from: Scale,
+    pub to: Scale,
+    pub append: Option<String>,
+    pub precision: usize,
+}
+
+impl ColumnConversion {
+    fn convert(&self, raw: &str) -> Result<String, String> {
+        let raw = raw.trim();
+        if raw.is_empty() {
+            return Err(format!("'{}' is empty", self.column));
+        }
+        let value: f64 = raw
+            .parse()
+            .map_err(|_| format!("'{}' is not a number in '{}'", raw, self.column))?;
+        let temp = Temperature::new(value, self.from).map_err(|err| err.to_string())?;
+        Ok(format!("{:.*}", self.precision, temp.value_in(self.to)))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RowError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for RowError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct ConversionReport {
+    pub rows: usize,
+    pub converted: usize,
+    pub errors: Vec<RowError>,
+}
+
+impl ConversionReport {
+    pub fn is_clean(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    fn fail(&mut self, line: usize, message: String) {
+        self.errors.push(RowError { line, message });
+    }
+}
+
+// Errors that stop the whole run. Problems with single rows end up in the ConversionReport instead.
+#[derive(Debug)]
+pub enum DatasetError {
+    Io(io::Error),
+    MissingHeader,
+    MissingColumn(String),
+}
+
+impl fmt::Display for DatasetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DatasetError::Io(err) => write!(f, "{}", err),
+            DatasetError::MissingHeader => write!(f, "the CSV input has no header row"),
+            DatasetError::MissingColumn(column) => {
+                write!(f, "no column named '{}' in the header", column)
+            }
+        }
+    }
+}
+
+impl Error for DatasetError {}
+
+impl From<io::Error> for DatasetError {
+    fn from(err: io::Error) -> DatasetError {
+        DatasetError::Io(err)
+    }
+}
+
+pub fn convert<R: BufRead, W: Write>(
+    format: Format,
+    input: R,
+    out: &mut W,
+    conversion: &ColumnConversion,
+) -> Result<ConversionReport, DatasetError> {
+    match format {
+        Format::Csv => convert_csv(input, out, conversion),
+        Format::JsonLines => convert_json_lines(input, out, conversion),
+    }
+}
+
+//------------------------------------------------------------CSV-------------------------------------------------------------------
+
+// A quoted field may hold at most this many line breaks. A stray quote would otherwise swallow the rest of the file into one record.
+const MAX_RECORD_LINES: usize = 32;
+
+// Reads one record at a time, a record spans several physical lines when a quoted field contains a line break.
+struct CsvRecords<R> {
+    input: R,
+    // Lines read ahead while looking for a closing quote that never came, they are read again one by one.
+    pending: VecDeque<String>,
+}
+
+impl<R: BufRead> CsvRecords<R> {
+    fn new(input: R) -> CsvRecords<R> {
+        CsvRecords {
+            input,
+            pending: VecDeque::new(),
+        }
+    }
+
+    fn read_line(&mut self) -> io::Result<Option<String>> {
+        if let Some(line) = self.pending.pop_front() {
+            return Ok(Some(line));
+        }
+        let mut line = String::new();
+        Ok((self.input.read_line(&mut line)? > 0).then_some(line))
+    }
+
+    // Returns the record without its line ending and the number of lines it took. When a quote is still open after MAX_RECORD_LINES
+    // lines, or at the end of the input, only the first line is returned, the parser reports its open quote and reading resumes at the
+    // line after it.
+    fn next(&mut self) -> io::Result<Option<(String, usize)>> {
+        let mut lines = Vec::new();
+        let mut open = false;
+        while let Some(line) = self.read_line()? {
+            open = ends_inside_quotes(&line, open);
+            lines.push(line);
+            if !open || lines.len() == MAX_RECORD_LINES {
+                break;
+            }
+        }
+        if open {
+            self.pending.extend(lines.drain(1..));
+        }
+        if lines.is_empty() {
+            return Ok(None);
+        }
+        let count = lines.len();
+        let mut record = lines.concat();
+        let trimmed = record.trim_end_matches(['\n', '\r']).len();
+        record.truncate(trimmed);
+        Ok(Some((record, count)))
+    }
+}
+
+// Follows the quoting rules of split_csv_record: a quote only opens at the start of a field and a doubled quote inside one is a literal
+// quote. quoted tells whether the line starts inside a quoted field.
+fn ends_inside_quotes(line: &str, mut quoted: bool) -> bool {
+    let mut chars = line.chars().peekable();
+    let mut field_start = true;
+    while let Some(c) = chars.next() {
+        match (quoted, c) {
+            (true, '"') if chars.peek() == Some(&'"') => {
+                chars.next();
+            }
+            (true, '"') => quoted = false,
+            (true, _) => {}
+            (false, '"') if field_start => {
+                quoted = true;
+                field_start = false;
+            }
+            (false, ',') => field_start = true,
+            (false, _) => field_start = false,
+        }
+    }
+    quoted
+}
+
+fn split_csv_record(record: &str) -> Result<Vec<String>, String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut chars = record.chars().peekable();
+    let mut quoted = false;
+    let mut was_quoted = false;
+
+    while let Some(c) = chars.next() {
+        match (quoted, c) {
+            (true, '"') if chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            (true, '"') => quoted = false,
+            (true, _) => field.push(c),
+            (false, '"') if field.is_empty() && !was_quoted => {
+                quoted = true;
+                was_quoted = true;
+            }
+            (false, '"') => return Err("stray quote inside an unquoted field".to_string()),
+            (false, ',') => {
+                fields.push(std::mem::take(&mut field));
+                was_quoted = false;
+            }
+            (false, _) if was_quoted => return Err("text after a closing quote".to_string()),
+            (false, _) => field.push(c),
+        }
+    }
+    if quoted {
+        return Err("unterminated quoted field".to_string());
+    }
+    fields.push(field);
+    Ok(fields)
+}
+
+fn write_csv_record<W: Write>(out: &mut W, fields: &[String]) -> io::Result<()> {
+    let mut first = true;
+    for field in fields {
+        if !first {
+            out.write_all(b",")?;
+        }
+        first = false;
+        if field.contains([',', '"', '\n', '\r']) {
+            write!(out, "\"{}\"", field.replace('"', "\"\""))?;
+        } else {
+            out.write_all(field.as_bytes())?;
+        }
+    }
+    out.write_all(b"\n")
+}
+
+pub fn convert_csv<R: BufRead, W: Write>(
+    input: R,
+    out: &mut W,
+    conversion: &ColumnConversion,
+) -> Result<ConversionReport, DatasetError> {
+    let mut report = ConversionReport::default();
+    let mut records = CsvRecords::new(input);
+    let (header, mut line) = records.next()?.ok_or(DatasetError::MissingHeader)?;
+    let mut header = split_csv_record(&header).map_err(|_| DatasetError::MissingHeader)?;
+    let column = header
+        .iter()
+        .position(|name| name.trim() == conversion.column)
+        .ok_or_else(|| DatasetError::MissingColumn(conversion.column.clone()))?;
+    if let Some(name) = &conversion.append {
+        header.push(name.clone());
+    }
+    write_csv_record(out, &header)?;
+
+    while let Some((record, lines)) = records.next()? {
+        let start = line + 1;
+        line += lines;
+        if record.is_empty() {
+            continue;
+        }
+        report.rows += 1;
+
+        let mut fields = match split_csv_record(&record) {
+            Ok(fields) => fields,
+            Err(message) => {
+                report.fail(start, message);
+                writeln!(out, "{}", record)?;
+                continue;
+            }
+        };
+        let converted = if fields.len() != header.len() - conversion.append.is_some() as usize {
+            Err(format!(
+                "expected {} fields, found {}",
+                header.len() - conversion.append.is_some() as usize,
+                fields.len()
+            ))
+        } else {
+            conversion.convert(&fields[column])
+        };
+        match converted {
+            Ok(value) => {
+                report.converted += 1;
+                match conversion.append {
+                    Some(_) => fields.push(value),
+                    None => fields[column] = value,
+                }
+            }
+            Err(message) => {
+                report.fail(start, message);
+                if conversion.append.is_some() {
+                    fields.push(String::new());
+                }
+            }
+        }
+        write_csv_record(out, &fields)?;
+    }
+    Ok(report)
+}
+
+//------------------------------------------------------------JSON Lines------------------------------------------------------------
+
+// A member of a JSON object, the key is decoded and the value is kept as the raw text from the input.
+struct Member<'a> {
+    raw_key: &'a str,
+    key: String,
+    value: &'a str,
+}
+
+struct Scanner<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl<'a> Scanner<'a> {
+    fn peek(&self) -> Option<u8> {
+        self.text.as_bytes().get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\r' | b'\n')) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), String> {
+        self.skip_whitespace();
+        if self.peek() != Some(byte) {
+            return Err(format!(
+                "expected '{}' at column {}",
+                byte as char,
+                self.pos + 1
+            ));
+        }
+        self.pos += 1;
+        Ok(())
+    }
+
+    fn string(&mut self) -> Result<&'a str, String> {
+        let start = self.pos;
+        self.expect(b'"')?;
+        loop {
+            match self.peek() {
+                None => return Err("unterminated string".to_string()),
+                Some(b'\\') => self.pos += 2,
+                Some(b'"') => {
+                    self.pos += 1;
+                    return Ok(&self.text[start..self.pos]);
+                }
+                Some(_) => self.pos += 1,
+            }
+        }
+    }
+
+    // Skips one value of any kind and returns its text. Nested objects and arrays are only checked for balance.
+    fn value(&mut self) -> Result<&'a str, String> {
+        self.skip_whitespace();
+        let start = self.pos;
+        match self.peek() {
+            None => return Err("missing value".to_string()),
+            Some(b'"') => {
+                self.string()?;
+            }
+            Some(b'{' | b'[') => {
+                let mut depth = 0;
+                loop {
+                    match self.peek() {
+                        None => return Err("unterminated object or array".to_string()),
+                        Some(b'"') => {
+                            self.string()?;
+                            continue;
+                        }
+                        Some(b'{' | b'[') => depth += 1,
+                        Some(b'}' | b']') => depth -= 1,
+                        Some(_) => {}
+                    }
+                    self.pos += 1;
+                    if depth == 0 {
+                        break;
+                    }
+                }
+            }
+            Some(_) => {
+                while !matches!(
+                    self.peek(),
+                    None | Some(b',' | b'}' | b']' | b' ' | b'\t' | b'\r' | b'\n')
+                ) {
+                    self.pos += 1;
+                }
+            }
+        }
+        if self.pos == start {
+            return Err(format!("missing value at column {}", start + 1));
+        }
+        Ok(&self.text[start..self.pos])
+    }
+}
+
+fn decode_json_string(raw: &str) -> Result<String, String> {
+    let inner = &raw[1..raw.len() - 1];
+    let mut decoded = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            decoded.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('"') => decoded.push('"'),
+            Some('\\') => decoded.push('\\'),
+            Some('/') => decoded.push('/'),
+            Some('b') => decoded.push('\u{8}'),
+            Some('f') => decoded.push('\u{c}'),
+            Some('n') => decoded.push('\n'),
+            Some('r') => decoded.push('\r'),
+            Some('t') => decoded.push('\t'),
+            Some('u') => {
+                let hex: String = chars.by_ref().take(4).collect();
+                let code =
+                    u32::from_str_radix(&hex, 16).map_err(|_| format!("bad escape \\u{}", hex))?;
+                decoded.push(char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER));
+            }
+            other => {
+                return Err(format!(
+                    "bad escape \\{}",
+                    other.map(String::from).unwrap_or_default()
+                ))
+            }
+        }
+    }
+    Ok(decoded)
+}
+
+fn split_json_object(line: &str) -> Result<Vec<Member<'_>>, String> {
+    let mut scanner = Scanner { text: line, pos: 0 };
+    let mut members = Vec::new();
+    scanner.expect(b'{')?;
+    scanner.skip_whitespace();
+    if scanner.peek() == Some(b'}') {
+        scanner.pos += 1;
+    } else {
+        loop {
+            scanner.skip_whitespace();
+            let raw_key = scanner.string()?;
+            let key = decode_json_string(raw_key)?;
+            scanner.expect(b':')?;
+            let value = scanner.value()?;
+            members.push(Member {
+                raw_key,
+                key,
+                value,
+            });
+            scanner.skip_whitespace();
+            match scanner.peek() {
+                Some(b',') => scanner.pos += 1,
+                Some(b'}') => {
+                    scanner.pos += 1;
+                    break;
+                }
+                _ => {
+                    return Err(format!(
+                        "expected ',' or '}}' at column {}",
+                        scanner.pos + 1
+                    ))
+                }
+            }
+        }
+    }
+    scanner.skip_whitespace();
+    if scanner.pos != line.len() {
+        return Err(format!(
+            "unexpected text after the object at column {}",
+            scanner.pos + 1
+        ));
+    }
+    Ok(members)
+}
+
+fn json_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len() + 2);
+    escaped.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn convert_json_object(line: &str, conversion: &ColumnConversion) -> Result<String, String> {
+    let members = split_json_object(line)?;
+    let index = members
+        .iter()
+        .position(|member| member.key == conversion.column)
+        .ok_or_else(|| format!("no field named '{}'", conversion.column))?;
+
+    // Numbers stored as strings, "98.6", are common in sensor exports and are accepted as well.
+    let raw = members[index].value;
+    let number = if raw.starts_with('"') {
+        decode_json_string(raw)?
+    } else {
+        raw.to_string()
+    };
+    let value = conversion.convert(&number)?;
+
+    let mut parts: Vec<String> = members
+        .iter()
+        .enumerate()
+        .map(|(i, member)| {
+            let value = if i == index && conversion.append.is_none() {
+                &value
+            } else {
+                member.value
+            };
+            format!("{}:{}", member.raw_key, value)
+        })
+        .collect();
+    if let Some(name) = &conversion.append {
+        parts.push(format!("{}:{}", json_escape(name), value));
+    }
+    Ok(format!("{{{}}}", parts.join(",")))
+}
+
+pub fn convert_json_lines<R: BufRead, W: Write>(
+    input: R,
+    out: &mut W,
+    conversion: &ColumnConversion,
+) -> Result<ConversionReport, DatasetError> {
+    let mut report = ConversionReport::default();
+    for (index, line) in input.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        report.rows += 1;
+        match convert_json_object(&line, conversion) {
+            Ok(converted) => {
+                report.converted += 1;
+                writeln!(out, "{}", converted)?;
+            }
+            Err(message) => {
+                report.fail(index + 1, message);
+                writeln!(out, "{}", line)?;
+            }
+        }
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn conversion(column: &str, append: Option<&str>) -> ColumnConversion {
+        ColumnConversion {
+            column: column.to_string(),
+            from: Scale::Fahrenheit,
+            to: Scale::Celsius,
+            append: append.map(String::from),
+            precision: 1,
+        }
+    }
+
+    fn run(
+        format: Format,
+        input: &str,
+        conversion: &ColumnConversion,
+    ) -> (String, ConversionReport) {
+        let mut out = Vec::new();
+        let report = convert(format, input.as_bytes(), &mut out, conversion).unwrap();
+        (String::from_utf8(out).unwrap(), report)
+    }
+
+    #[test]
+    fn csv_replaces_column() {
+        let input = "time,temp_f,site\n10:00,212,\"Lab, North\"\n10:05,32,Roof\n";
+        let (out, report) = run(Format::Csv, input, &conversion("temp_f", None));
+        assert_eq!(
+            out,
+            "time,temp_f,site\n10:00,100.0,\"Lab, North\"\n10:05,0.0,Roof\n"
+        );
+        assert_eq!(report.converted, 2);
+        assert!(report.is_clean());
+    }
+
+    #[test]
+    fn csv_appends_column_and_reports_bad_rows() {
+        let input =
+            "time,temp_f,note\n1,212,\"two\nlines\"\n2,n/a,x\n3,-500,y\n4,32\n5,32,\"open\n";
+        let (out, report) = run(Format::Csv, input, &conversion("temp_f", Some("temp_c")));
+        assert_eq!(
+            out,
+            "time,temp_f,note,temp_c\n1,212,\"two\nlines\",100.0\n2,n/a,x,\n3,-500,y,\n4,32,\n5,32,\"open\n"
+        );
+        assert_eq!(report.rows, 5);
+        assert_eq!(report.converted, 1);
+        let lines: Vec<usize> = report.errors.iter().map(|err| err.line).collect();
+        assert_eq!(lines, vec![4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn csv_stray_quote_does_not_swallow_the_file() {
+        let mut input = "time,temp_f,note\n1,212,\"stray\n".to_string();
+        for row in 2..=40 {
+            input.push_str(&format!("{},32,x\n", row));
+        }
+        let (out, report) = run(Format::Csv, &input, &conversion("temp_f", None));
+        assert_eq!(report.rows, 40);
+        assert_eq!(report.converted, 39);
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].line, 2);
+        assert!(out.contains("1,212,\"stray\n2,0.0,x\n"));
+        assert!(out.ends_with("40,0.0,x\n"));
+    }
+
+    #[test]
+    fn csv_stray_quote_near_the_end_does_not_swallow_the_rest() {
+        let input = "id,temp_f\n1,32\n2,50\n3,\"212\n4,98.6\n5,68\n6,100\n";
+        let (out, report) = run(Format::Csv, input, &conversion("temp_f", None));
+        assert_eq!(report.rows, 6);
+        assert_eq!(report.converted, 5);
+        let lines: Vec<usize> = report.errors.iter().map(|err| err.line).collect();
+        assert_eq!(lines, vec![4]);
+        assert!(out.ends_with("3,\"212\n4,37.0\n5,20.0\n6,37.8\n"));
+    }
+
+    #[test]
+    fn csv_quote_inside_an_unquoted_field_stays_on_its_row() {
+        let input = "id,temp_f\n1,12\"\n2,50\n3,68\n";
+        let (out, report) = run(Format::Csv, input, &conversion("temp_f", None));
+        assert_eq!(report.rows, 3);
+        assert_eq!(report.converted, 2);
+        assert_eq!(report.errors[0].line, 2);
+        assert_eq!(out, "id,temp_f\n1,12\"\n2,10.0\n3,20.0\n");
+    }
+
+    #[test]
+    fn csv_missing_column_stops_the_run() {
+        let mut out = Vec::new();
+        let result = convert_csv(
+            "a,b\n1,2\n".as_bytes(),
+            &mut out,
+            &conversion("temp_f", None),
+        );
+        assert!(matches!(result, Err(DatasetError::MissingColumn(_))));
+    }
+
+    #[test]
+    fn json_lines_replace_and_append() {
+        let input = "{\"t\": 212, \"site\": {\"name\": \"a,}\"}}\n{\"t\":\"32\"}\n\n{\"t\": true}\n{\"x\":1}\nnot json\n";
+        let (out, report) = run(Format::JsonLines, input, &conversion("t", None));
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines[0], "{\"t\":100.0,\"site\":{\"name\": \"a,}\"}}");
+        assert_eq!(lines[1], "{\"t\":0.0}");
+        assert_eq!(lines[2], "{\"t\": true}");
+        assert_eq!(
+            report.errors.iter().map(|err| err.line).collect::<Vec<_>>(),
+            vec![4, 5, 6]
+        );
+
+        let (out, _) = run(
+            Format::JsonLines,
+            "{\"t\": 212}\n",
+            &conversion("t", Some("t_c")),
+        );
+        assert_eq!(out, "{\"t\":212,\"t_c\":100.0}\n");
+    }
+}
@@ -0,0 +1,20 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 05:36:49
+/// @Description: Temperature Conversion Library
+/*
+- The library crate exposes a Temperature type that remembers the scale its value was measured in. Conversions between any pair of scales go through Kelvin,
+  which is the only scale whose zero is the absolute zero, so checking for a physically impossible value is a single comparison.
+- All arithmetic is done in f64. Integer arithmetic is what broke the original demo: (5 / 9) is 0 in integer division, so every conversion returned 0.
+- Values below absolute zero are rejected when the Temperature is constructed, so every Temperature that exists is a valid one and the conversions never fail.
+- Supported scales: Celsius, Fahrenheit, Kelvin, Rankine, Réaumur and Delisle. Delisle runs backwards, higher values are colder.
+- The quantity module generalises the idea to other dimensions (length, mass, time, speed, pressure, energy) and to temperature differences.
+*/
+pub mod cli;
+pub mod dataset;
+pub mod quantity;
+mod temperature;
+
+pub use quantity::{Dimension, Quantity, TemperatureDelta, Unit};
+pub use temperature::{
+    ParseTemperatureError, Scale, Temperature, TemperatureError, ABSOLUTE_ZERO_KELVIN,
+};
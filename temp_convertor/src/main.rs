@@ -1,10 +1,76 @@
 /// @Author: Mitul
 /// @Date:   2024-11-15 20:05:04
 /// @Description: Temperature Conversion b/w Fahrenheit and Celcius
-fn main() {
-    let  f_temp: i32 = 132;
-    let  cel_temp: i32;
+use fundamentals_core::open_or_stdin;
+use std::env;
+use std::io::{self, Write};
+use std::process;
+use temp_convertor::cli::{self, CliError, Mode};
+use temp_convertor::dataset;
+
+fn run() -> Result<bool, CliError> {
+    let options = cli::parse_args(env::args().skip(1))?;
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
 
-    cel_temp = (f_temp - 32) * (5 / 9);
-    println!("Celcius for {:?}F is {:?}C", f_temp, cel_temp);
+    match options.mode {
+        Mode::Help => writeln!(out, "{}", cli::USAGE)?,
+        Mode::Single(temp) => writeln!(
+            out,
+            "{} is {:.*}",
+            temp,
+            options.precision,
+            temp.to(options.to)
+        )?,
+        Mode::Table {
+            from,
+            start,
+            end,
+            step,
+        } => cli::write_table(
+            &mut out,
+            from,
+            options.to,
+            (start, end, step),
+            options.precision,
+        )?,
+        Mode::Batch(file) => {
+            let mut err = io::stderr();
+            let input = open_or_stdin(file.as_deref())?;
+            let report = cli::run_batch(input, &mut out, &mut err, options.to, options.precision)?;
+            return Ok(report.is_clean());
+        }
+        Mode::Dataset {
+            format,
+            input,
+            conversion,
+        } => {
+            let input = open_or_stdin(input.as_deref())?;
+            let report = dataset::convert(format, input, &mut out, &conversion)?;
+            out.flush()?;
+            for err in &report.errors {
+                eprintln!("{}", err);
+            }
+            if !report.is_clean() {
+                eprintln!(
+                    "{} of {} rows could not be converted",
+                    report.errors.len(),
+                    report.rows
+                );
+            }
+            return Ok(report.is_clean());
+        }
+    }
+    Ok(true)
+}
+
+fn main() {
+    match run() {
+        Ok(true) => {}
+        Ok(false) => process::exit(1),
+        Err(err) => {
+            eprintln!("error: {}", err);
+            process::exit(2);
+        }
+    }
 }
@@ -0,0 +1,477 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 05:40:55
+/// @Description: Quantities with Physical Dimensions
+/*
+- A Quantity<D> is a value together with its dimension D (Length, Mass, Time, ...). The dimension is a zero sized marker type, so it costs nothing at runtime,
+  but two quantities can only be added or subtracted when they have the same dimension. Adding metres to seconds is a compile time error.
+- Every dimension has its own unit enum. A quantity is stored in the SI unit of its dimension and converted on the way in and out, which makes converting
+  between any two units of the same dimension a multiplication and a division.
+- Multiplying or dividing quantities of different dimensions gives a quantity of the derived dimension where that is meaningful: Length / Time is Speed and
+  Speed * Time is Length.
+- Absolute temperatures are not quantities in this sense. 0°C is not "no temperature", so the scales have an offset and the conversion is not a plain scaling.
+  Temperature (in the temperature module) stays the type for absolute temperatures and TemperatureDelta, a Quantity of the TemperatureInterval dimension, is
+  the type for differences. Converting a delta only scales it: a rise of 10°C is a rise of 18°F, not 50°F.
+- Temperature - Temperature gives a TemperatureDelta, and a Temperature can be shifted by a TemperatureDelta with checked_add, which fails instead of going
+  below absolute zero.
+*/
+use crate::{Scale, Temperature, TemperatureError};
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::Sum;
+use std::marker::PhantomData;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+pub trait Unit: Copy + fmt::Debug {
+    // How many SI units one of this unit is, e.g. 0.3048 for the foot.
+    fn factor(&self) -> f64;
+    fn symbol(&self) -> &'static str;
+}
+
+pub trait Dimension {
+    type Unit: Unit;
+    const SI_UNIT: Self::Unit;
+}
+
+macro_rules! units {
+    ($dimension:ident, $unit:ident, $si:ident, { $($variant:ident => ($symbol:expr, $factor:expr)),+ $(,)? }) => {
+        #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+        pub struct $dimension;
+
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum $unit {
+            $($variant),+
+        }
+
+        impl $unit {
+            pub const ALL: &'static [$unit] = &[$($unit::$variant),+];
+        }
+
+        impl Unit for $unit {
+            fn factor(&self) -> f64 {
+                match self {
+                    $($unit::$variant => $factor),+
+                }
+            }
+
+            fn symbol(&self) -> &'static str {
+                match self {
+                    $($unit::$variant => $symbol),+
+                }
+            }
+        }
+
+        impl Dimension for $dimension {
+            type Unit = $unit;
+            const SI_UNIT: $unit = $unit::$si;
+        }
+    };
+}
+
+units!(Length, LengthUnit, Metre, {
+    Metre => ("m", 1.0),
+    Kilometre => ("km", 1000.0),
+    Centimetre => ("cm", 0.01),
+    Millimetre => ("mm", 0.001),
+    Inch => ("in", 0.0254),
+    Foot => ("ft", 0.3048),
+    Yard => ("yd", 0.9144),
+    Mile => ("mi", 1609.344),
+    NauticalMile => ("nmi", 1852.0),
+});
+
+units!(Mass, MassUnit, Kilogram, {
+    Kilogram => ("kg", 1.0),
+    Gram => ("g", 0.001),
+    Milligram => ("mg", 1e-6),
+    Tonne => ("t", 1000.0),
+    Ounce => ("oz", 0.028349523125),
+    Pound => ("lb", 0.45359237),
+    Stone => ("st", 6.35029318),
+});
+
+units!(Time, TimeUnit, Second, {
+    Second => ("s", 1.0),
+    Millisecond => ("ms", 0.001),
+    Minute => ("min", 60.0),
+    Hour => ("h", 3600.0),
+    Day => ("d", 86400.0),
+});
+
+units!(Speed, SpeedUnit, MetrePerSecond, {
+    MetrePerSecond => ("m/s", 1.0),
+    KilometrePerHour => ("km/h", 1000.0 / 3600.0),
+    MilePerHour => ("mph", 1609.344 / 3600.0),
+    FootPerSecond => ("ft/s", 0.3048),
+    Knot => ("kn", 1852.0 / 3600.0),
+});
+
+units!(Pressure, PressureUnit, Pascal, {
+    Pascal => ("Pa", 1.0),
+    Kilopascal => ("kPa", 1000.0),
+    Bar => ("bar", 100_000.0),
+    Millibar => ("mbar", 100.0),
+    Atmosphere => ("atm", 101_325.0),
+    Psi => ("psi", 6894.757293168361),
+    MillimetreOfMercury => ("mmHg", 133.322387415),
+    InchOfMercury => ("inHg", 3386.388640341),
+});
+
+units!(Energy, EnergyUnit, Joule, {
+    Joule => ("J", 1.0),
+    Kilojoule => ("kJ", 1000.0),
+    Calorie => ("cal", 4.184),
+    Kilocalorie => ("kcal", 4184.0),
+    WattHour => ("Wh", 3600.0),
+    KilowattHour => ("kWh", 3_600_000.0),
+    Btu => ("BTU", 1055.05585262),
+    FootPound => ("ft·lbf", 1.3558179483314004),
+});
+
+// Temperature differences reuse the Scale enum as their unit. Only the size of a degree matters here, the offsets of the scales do not.
+// Delisle counts downwards, so a rise in temperature is a negative number of Delisle degrees.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct TemperatureInterval;
+
+impl Unit for Scale {
+    fn factor(&self) -> f64 {
+        match self {
+            Scale::Celsius | Scale::Kelvin => 1.0,
+            Scale::Fahrenheit | Scale::Rankine => 5.0 / 9.0,
+            Scale::Reaumur => 5.0 / 4.0,
+            Scale::Delisle => -2.0 / 3.0,
+        }
+    }
+
+    fn symbol(&self) -> &'static str {
+        Scale::symbol(self)
+    }
+}
+
+impl Dimension for TemperatureInterval {
+    type Unit = Scale;
+    const SI_UNIT: Scale = Scale::Kelvin;
+}
+
+pub type TemperatureDelta = Quantity<TemperatureInterval>;
+
+// Quantities of different dimensions do not add up, this does not compile:
+/// ```compile_fail
+/// use temp_convertor::quantity::{Length, LengthUnit, Quantity, Time, TimeUnit};
+/// let _ = Quantity::<Length>::new(1.0, LengthUnit::Metre) + Quantity::<Time>::new(1.0, TimeUnit::Second);
+/// ```
+pub struct Quantity<D: Dimension> {
+    si: f64,
+    dimension: PhantomData<D>,
+}
+
+// Written by hand because derive would only implement them when the marker type D implements them too.
+impl<D: Dimension> Clone for Quantity<D> {
+    fn clone(&self) -> Quantity<D> {
+        *self
+    }
+}
+
+impl<D: Dimension> Copy for Quantity<D> {}
+
+impl<D: Dimension> PartialEq for Quantity<D> {
+    fn eq(&self, other: &Quantity<D>) -> bool {
+        self.si == other.si
+    }
+}
+
+impl<D: Dimension> PartialOrd for Quantity<D> {
+    fn partial_cmp(&self, other: &Quantity<D>) -> Option<Ordering> {
+        self.si.partial_cmp(&other.si)
+    }
+}
+
+impl<D: Dimension> fmt::Debug for Quantity<D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Quantity({} {})", self.si, D::SI_UNIT.symbol())
+    }
+}
+
+impl<D: Dimension> Quantity<D> {
+    pub fn new(value: f64, unit: D::Unit) -> Quantity<D> {
+        Quantity::from_si(value * unit.factor())
+    }
+
+    pub fn from_si(si: f64) -> Quantity<D> {
+        Quantity {
+            si,
+            dimension: PhantomData,
+        }
+    }
+
+    pub fn si(&self) -> f64 {
+        self.si
+    }
+
+    pub fn value_in(&self, unit: D::Unit) -> f64 {
+        self.si / unit.factor()
+    }
+
+    pub fn abs(&self) -> Quantity<D> {
+        Quantity::from_si(self.si.abs())
+    }
+
+    // Returns something that prints as the value in the given unit, e.g. `quantity.display_in(LengthUnit::Mile)`.
+    pub fn display_in(&self, unit: D::Unit) -> InUnit<D> {
+        InUnit {
+            quantity: *self,
+            unit,
+        }
+    }
+}
+
+impl<D: Dimension> Default for Quantity<D> {
+    fn default() -> Quantity<D> {
+        Quantity::from_si(0.0)
+    }
+}
+
+pub struct InUnit<D: Dimension> {
+    quantity: Quantity<D>,
+    unit: D::Unit,
+}
+
+impl<D: Dimension> fmt::Display for InUnit<D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = self.quantity.value_in(self.unit);
+        match f.precision() {
+            Some(precision) => write!(f, "{:.*} {}", precision, value, self.unit.symbol()),
+            None => write!(f, "{} {}", value, self.unit.symbol()),
+        }
+    }
+}
+
+impl<D: Dimension> fmt::Display for Quantity<D> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.display_in(D::SI_UNIT), f)
+    }
+}
+
+//------------------------------------------------------------Same Dimension--------------------------------------------------------
+
+impl<D: Dimension> Add for Quantity<D> {
+    type Output = Quantity<D>;
+
+    fn add(self, other: Quantity<D>) -> Quantity<D> {
+        Quantity::from_si(self.si + other.si)
+    }
+}
+
+impl<D: Dimension> Sub for Quantity<D> {
+    type Output = Quantity<D>;
+
+    fn sub(self, other: Quantity<D>) -> Quantity<D> {
+        Quantity::from_si(self.si - other.si)
+    }
+}
+
+impl<D: Dimension> AddAssign for Quantity<D> {
+    fn add_assign(&mut self, other: Quantity<D>) {
+        self.si += other.si;
+    }
+}
+
+impl<D: Dimension> SubAssign for Quantity<D> {
+    fn sub_assign(&mut self, other: Quantity<D>) {
+        self.si -= other.si;
+    }
+}
+
+impl<D: Dimension> Neg for Quantity<D> {
+    type Output = Quantity<D>;
+
+    fn neg(self) -> Quantity<D> {
+        Quantity::from_si(-self.si)
+    }
+}
+
+impl<D: Dimension> Mul<f64> for Quantity<D> {
+    type Output = Quantity<D>;
+
+    fn mul(self, factor: f64) -> Quantity<D> {
+        Quantity::from_si(self.si * factor)
+    }
+}
+
+impl<D: Dimension> Mul<Quantity<D>> for f64 {
+    type Output = Quantity<D>;
+
+    fn mul(self, quantity: Quantity<D>) -> Quantity<D> {
+        quantity * self
+    }
+}
+
+impl<D: Dimension> Div<f64> for Quantity<D> {
+    type Output = Quantity<D>;
+
+    fn div(self, divisor: f64) -> Quantity<D> {
+        Quantity::from_si(self.si / divisor)
+    }
+}
+
+// The ratio of two quantities of the same dimension has no dimension.
+impl<D: Dimension> Div for Quantity<D> {
+    type Output = f64;
+
+    fn div(self, other: Quantity<D>) -> f64 {
+        self.si / other.si
+    }
+}
+
+impl<D: Dimension> Sum for Quantity<D> {
+    fn sum<I: Iterator<Item = Quantity<D>>>(iter: I) -> Quantity<D> {
+        iter.fold(Quantity::default(), |total, quantity| total + quantity)
+    }
+}
+
+//------------------------------------------------------------Derived Dimensions----------------------------------------------------
+
+impl Div<Quantity<Time>> for Quantity<Length> {
+    type Output = Quantity<Speed>;
+
+    fn div(self, time: Quantity<Time>) -> Quantity<Speed> {
+        Quantity::from_si(self.si / time.si)
+    }
+}
+
+impl Div<Quantity<Speed>> for Quantity<Length> {
+    type Output = Quantity<Time>;
+
+    fn div(self, speed: Quantity<Speed>) -> Quantity<Time> {
+        Quantity::from_si(self.si / speed.si)
+    }
+}
+
+impl Mul<Quantity<Time>> for Quantity<Speed> {
+    type Output = Quantity<Length>;
+
+    fn mul(self, time: Quantity<Time>) -> Quantity<Length> {
+        Quantity::from_si(self.si * time.si)
+    }
+}
+
+impl Mul<Quantity<Speed>> for Quantity<Time> {
+    type Output = Quantity<Length>;
+
+    fn mul(self, speed: Quantity<Speed>) -> Quantity<Length> {
+        speed * self
+    }
+}
+
+//------------------------------------------------------------Absolute Temperatures-------------------------------------------------
+
+impl Sub for Temperature {
+    type Output = TemperatureDelta;
+
+    fn sub(self, other: Temperature) -> TemperatureDelta {
+        TemperatureDelta::from_si(self.in_kelvin() - other.in_kelvin())
+    }
+}
+
+impl Temperature {
+    // The result keeps the scale of self.
+    pub fn checked_add(&self, delta: TemperatureDelta) -> Result<Temperature, TemperatureError> {
+        let kelvin = Temperature::kelvin(self.in_kelvin() + delta.si())?;
+        Ok(kelvin.to(self.scale()))
+    }
+
+    pub fn checked_sub(&self, delta: TemperatureDelta) -> Result<Temperature, TemperatureError> {
+        self.checked_add(-delta)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(left: f64, right: f64) {
+        assert!(
+            (left - right).abs() < 1e-9 * right.abs().max(1.0),
+            "{} != {}",
+            left,
+            right
+        );
+    }
+
+    #[test]
+    fn converts_between_si_and_imperial() {
+        let marathon = Quantity::<Length>::new(42.195, LengthUnit::Kilometre);
+        assert_close(marathon.value_in(LengthUnit::Mile), 42195.0 / 1609.344);
+        assert_close(
+            Quantity::<Mass>::new(1.0, MassUnit::Pound).value_in(MassUnit::Ounce),
+            16.0,
+        );
+        assert_close(
+            Quantity::<Pressure>::new(1.0, PressureUnit::Atmosphere).value_in(PressureUnit::Bar),
+            1.01325,
+        );
+        assert_close(
+            Quantity::<Energy>::new(1.0, EnergyUnit::KilowattHour).value_in(EnergyUnit::Kilojoule),
+            3600.0,
+        );
+        assert_close(
+            Quantity::<Time>::new(1.5, TimeUnit::Hour).value_in(TimeUnit::Minute),
+            90.0,
+        );
+        for unit in SpeedUnit::ALL {
+            let speed = Quantity::<Speed>::new(7.0, *unit);
+            assert_close(
+                Quantity::<Speed>::new(speed.value_in(SpeedUnit::Knot), SpeedUnit::Knot)
+                    .value_in(*unit),
+                7.0,
+            );
+        }
+    }
+
+    #[test]
+    fn same_dimension_arithmetic() {
+        let total: Quantity<Length> = [
+            Quantity::new(1.0, LengthUnit::Metre),
+            Quantity::new(50.0, LengthUnit::Centimetre),
+            Quantity::new(1.0, LengthUnit::Foot),
+        ]
+        .into_iter()
+        .sum();
+        assert_close(total.si(), 1.8048);
+        assert_close(
+            total / Quantity::<Length>::new(1.0, LengthUnit::Foot),
+            1.8048 / 0.3048,
+        );
+        assert_close((2.0 * total - total).si(), 1.8048);
+    }
+
+    #[test]
+    fn derived_dimensions() {
+        let distance = Quantity::<Length>::new(100.0, LengthUnit::Kilometre);
+        let time = Quantity::<Time>::new(2.0, TimeUnit::Hour);
+        let speed = distance / time;
+        assert_close(speed.value_in(SpeedUnit::KilometrePerHour), 50.0);
+        assert_close((speed * time).value_in(LengthUnit::Kilometre), 100.0);
+        assert_close((distance / speed).value_in(TimeUnit::Hour), 2.0);
+        assert_eq!(
+            format!("{:.1}", speed.display_in(SpeedUnit::KilometrePerHour)),
+            "50.0 km/h"
+        );
+    }
+
+    #[test]
+    fn temperature_delta_has_no_offset() {
+        let rise = TemperatureDelta::new(10.0, Scale::Celsius);
+        assert_close(rise.value_in(Scale::Fahrenheit), 18.0);
+        assert_close(rise.value_in(Scale::Kelvin), 10.0);
+        assert_close(rise.value_in(Scale::Delisle), -15.0);
+
+        let morning = Temperature::fahrenheit(50.0).unwrap();
+        let noon = Temperature::celsius(25.0).unwrap();
+        assert_close((noon - morning).value_in(Scale::Celsius), 15.0);
+
+        let warmer = morning.checked_add(rise).unwrap();
+        assert_eq!(warmer.scale(), Scale::Fahrenheit);
+        assert_close(warmer.value(), 68.0);
+        assert!(Temperature::kelvin(5.0).unwrap().checked_sub(rise).is_err());
+    }
+}
@@ -0,0 +1,344 @@
+/// @Author: Mitul
+/// @Date:   2026-10-18 05:36:49
+/// @Description: Temperature Scales and Conversions
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+pub const ABSOLUTE_ZERO_KELVIN: f64 = 0.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Scale {
+    Celsius,
+    Fahrenheit,
+    Kelvin,
+    Rankine,
+    Reaumur,
+    Delisle,
+}
+
+impl Scale {
+    pub const ALL: [Scale; 6] = [
+        Scale::Celsius,
+        Scale::Fahrenheit,
+        Scale::Kelvin,
+        Scale::Rankine,
+        Scale::Reaumur,
+        Scale::Delisle,
+    ];
+
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Scale::Celsius => "°C",
+            Scale::Fahrenheit => "°F",
+            Scale::Kelvin => "K",
+            Scale::Rankine => "°R",
+            Scale::Reaumur => "°Ré",
+            Scale::Delisle => "°De",
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Scale::Celsius => "Celsius",
+            Scale::Fahrenheit => "Fahrenheit",
+            Scale::Kelvin => "Kelvin",
+            Scale::Rankine => "Rankine",
+            Scale::Reaumur => "Réaumur",
+            Scale::Delisle => "Delisle",
+        }
+    }
+
+    fn kelvin_of(self, value: f64) -> f64 {
+        match self {
+            Scale::Celsius => value + 273.15,
+            Scale::Fahrenheit => (value + 459.67) * 5.0 / 9.0,
+            Scale::Kelvin => value,
+            Scale::Rankine => value * 5.0 / 9.0,
+            Scale::Reaumur => value * 5.0 / 4.0 + 273.15,
+            Scale::Delisle => 373.15 - value * 2.0 / 3.0,
+        }
+    }
+
+    fn of_kelvin(self, kelvin: f64) -> f64 {
+        match self {
+            Scale::Celsius => kelvin - 273.15,
+            Scale::Fahrenheit => kelvin * 9.0 / 5.0 - 459.67,
+            Scale::Kelvin => kelvin,
+            Scale::Rankine => kelvin * 9.0 / 5.0,
+            Scale::Reaumur => (kelvin - 273.15) * 4.0 / 5.0,
+            Scale::Delisle => (373.15 - kelvin) * 3.0 / 2.0,
+        }
+    }
+
+    pub fn absolute_zero(&self) -> f64 {
+        self.of_kelvin(ABSOLUTE_ZERO_KELVIN)
+    }
+}
+
+impl fmt::Display for Scale {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for Scale {
+    type Err = ParseTemperatureError;
+
+    fn from_str(s: &str) -> Result<Scale, ParseTemperatureError> {
+        let name = s.trim().trim_start_matches('°').to_lowercase();
+        match name.as_str() {
+            "c" | "celsius" => Ok(Scale::Celsius),
+            "f" | "fahrenheit" => Ok(Scale::Fahrenheit),
+            "k" | "kelvin" => Ok(Scale::Kelvin),
+            "r" | "ra" | "rankine" => Ok(Scale::Rankine),
+            "re" | "ré" | "reaumur" | "réaumur" => Ok(Scale::Reaumur),
+            "d" | "de" | "delisle" => Ok(Scale::Delisle),
+            _ => Err(ParseTemperatureError::UnknownScale(s.trim().to_string())),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TemperatureError {
+    BelowAbsoluteZero { value: f64, scale: Scale },
+    NotFinite,
+}
+
+impl fmt::Display for TemperatureError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TemperatureError::BelowAbsoluteZero { value, scale } => write!(
+                f,
+                "{}{} is below absolute zero ({}{})",
+                value,
+                scale.symbol(),
+                scale.absolute_zero(),
+                scale.symbol()
+            ),
+            TemperatureError::NotFinite => write!(f, "temperature must be a finite number"),
+        }
+    }
+}
+
+impl Error for TemperatureError {}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseTemperatureError {
+    MissingScale(String),
+    UnknownScale(String),
+    InvalidNumber(String),
+    OutOfRange(TemperatureError),
+}
+
+impl fmt::Display for ParseTemperatureError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseTemperatureError::MissingScale(input) => {
+                write!(f, "'{}' has no scale, expected something like 132F", input)
+            }
+            ParseTemperatureError::UnknownScale(scale) => write!(f, "unknown scale '{}'", scale),
+            ParseTemperatureError::InvalidNumber(number) => {
+                write!(f, "'{}' is not a number", number)
+            }
+            ParseTemperatureError::OutOfRange(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl Error for ParseTemperatureError {}
+
+impl From<TemperatureError> for ParseTemperatureError {
+    fn from(err: TemperatureError) -> ParseTemperatureError {
+        ParseTemperatureError::OutOfRange(err)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Temperature {
+    value: f64,
+    scale: Scale,
+}
+
+impl Temperature {
+    pub fn new(value: f64, scale: Scale) -> Result<Temperature, TemperatureError> {
+        if !value.is_finite() {
+            return Err(TemperatureError::NotFinite);
+        }
+        if scale.kelvin_of(value) < ABSOLUTE_ZERO_KELVIN {
+            return Err(TemperatureError::BelowAbsoluteZero { value, scale });
+        }
+        Ok(Temperature { value, scale })
+    }
+
+    pub fn celsius(value: f64) -> Result<Temperature, TemperatureError> {
+        Temperature::new(value, Scale::Celsius)
+    }
+
+    pub fn fahrenheit(value: f64) -> Result<Temperature, TemperatureError> {
+        Temperature::new(value, Scale::Fahrenheit)
+    }
+
+    pub fn kelvin(value: f64) -> Result<Temperature, TemperatureError> {
+        Temperature::new(value, Scale::Kelvin)
+    }
+
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    pub fn scale(&self) -> Scale {
+        self.scale
+    }
+
+    pub fn in_kelvin(&self) -> f64 {
+        self.scale.kelvin_of(self.value)
+    }
+
+    // Converting to the scale the value is already in returns it untouched, so a round trip through the same scale is exact.
+    pub fn value_in(&self, scale: Scale) -> f64 {
+        if scale == self.scale {
+            return self.value;
+        }
+        scale.of_kelvin(self.in_kelvin())
+    }
+
+    pub fn to(&self, scale: Scale) -> Temperature {
+        // Rounding can push a value at absolute zero a hair past it, clamp instead of failing.
+        let kelvin = self.in_kelvin().max(ABSOLUTE_ZERO_KELVIN);
+        let value = if scale == self.scale {
+            self.value
+        } else {
+            scale.of_kelvin(kelvin)
+        };
+        Temperature { value, scale }
+    }
+}
+
+impl fmt::Display for Temperature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match f.precision() {
+            Some(precision) => write!(f, "{:.*}{}", precision, self.value, self.scale.symbol()),
+            None => write!(f, "{}{}", self.value, self.scale.symbol()),
+        }
+    }
+}
+
+impl FromStr for Temperature {
+    type Err = ParseTemperatureError;
+
+    // Accepts a number followed by a scale, with or without a space or degree sign: 132F, -40 °C, 300K.
+    fn from_str(s: &str) -> Result<Temperature, ParseTemperatureError> {
+        let input = s.trim();
+        let split = input
+            .char_indices()
+            .rev()
+            .take_while(|(_, c)| c.is_alphabetic() || *c == '°')
+            .last()
+            .map(|(index, _)| index)
+            .ok_or_else(|| ParseTemperatureError::MissingScale(input.to_string()))?;
+        let (number, scale) = input.split_at(split);
+        let number = number.trim();
+        let value: f64 = number.parse().map_err(|_| {
+            let shown = if number.is_empty() { input } else { number };
+            ParseTemperatureError::InvalidNumber(shown.to_string())
+        })?;
+        Ok(Temperature::new(value, scale.parse()?)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(left: f64, right: f64) {
+        assert!((left - right).abs() < 1e-9, "{} != {}", left, right);
+    }
+
+    #[test]
+    fn fahrenheit_to_celsius() {
+        let temp = Temperature::fahrenheit(132.0).unwrap();
+        assert_close(temp.value_in(Scale::Celsius), 500.0 / 9.0);
+    }
+
+    #[test]
+    fn fixed_points() {
+        let boiling = Temperature::celsius(100.0).unwrap();
+        assert_close(boiling.value_in(Scale::Fahrenheit), 212.0);
+        assert_close(boiling.value_in(Scale::Kelvin), 373.15);
+        assert_close(boiling.value_in(Scale::Rankine), 671.67);
+        assert_close(boiling.value_in(Scale::Reaumur), 80.0);
+        assert_close(boiling.value_in(Scale::Delisle), 0.0);
+
+        let freezing = Temperature::new(32.0, Scale::Fahrenheit).unwrap();
+        assert_close(freezing.value_in(Scale::Celsius), 0.0);
+        assert_close(freezing.value_in(Scale::Delisle), 150.0);
+    }
+
+    #[test]
+    fn round_trip_between_every_pair() {
+        for from in Scale::ALL {
+            for to in Scale::ALL {
+                let temp = Temperature::celsius(36.6).unwrap().to(from);
+                let back = temp.to(to).to(from);
+                assert!((back.value() - temp.value()).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn below_absolute_zero_is_rejected() {
+        assert_eq!(
+            Temperature::kelvin(-0.1),
+            Err(TemperatureError::BelowAbsoluteZero {
+                value: -0.1,
+                scale: Scale::Kelvin
+            })
+        );
+        assert!(Temperature::celsius(-273.16).is_err());
+        assert!(Temperature::fahrenheit(-459.68).is_err());
+        assert!(Temperature::new(559.8, Scale::Delisle).is_err());
+        assert!(Temperature::new(559.7, Scale::Delisle).is_ok());
+        assert!(Temperature::celsius(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn absolute_zero_converts_cleanly() {
+        let zero = Temperature::kelvin(0.0).unwrap();
+        for scale in Scale::ALL {
+            assert_close(zero.to(scale).value(), scale.absolute_zero());
+        }
+    }
+
+    #[test]
+    fn parse_value_with_scale() {
+        assert_eq!(
+            "132F".parse::<Temperature>().ok(),
+            Temperature::fahrenheit(132.0).ok()
+        );
+        assert_eq!(
+            " -40 °C ".parse::<Temperature>().ok(),
+            Temperature::celsius(-40.0).ok()
+        );
+        assert_eq!(
+            "1.5e2K".parse::<Temperature>().ok(),
+            Temperature::kelvin(150.0).ok()
+        );
+        assert_eq!(
+            "80Re".parse::<Temperature>().unwrap().scale(),
+            Scale::Reaumur
+        );
+        assert_eq!(
+            "12".parse::<Temperature>(),
+            Err(ParseTemperatureError::MissingScale("12".to_string()))
+        );
+        assert_eq!(
+            "12X".parse::<Temperature>(),
+            Err(ParseTemperatureError::UnknownScale("X".to_string()))
+        );
+        assert!(matches!(
+            "-500C".parse::<Temperature>(),
+            Err(ParseTemperatureError::OutOfRange(_))
+        ));
+    }
+}